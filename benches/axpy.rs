@@ -0,0 +1,23 @@
+//! Timing comparison for scaled addition, run with `cargo bench --bench axpy`.
+mod common;
+
+use matrix::Matrix;
+use matrix::sub::Sub;
+use common::time;
+
+fn main() {
+    let w = Matrix::from_seed(1000, 1000, 1).unwrap();
+    let g = Matrix::from_seed(1000, 1000, 2).unwrap();
+    let lr = 0.01;
+
+    let two_step = w.sub(g.scale(lr)).unwrap();
+    let mut in_place = w.clone();
+    in_place.axpy(-lr, &g).unwrap();
+    assert_eq!(w.scaled_add(-lr, &g).unwrap(), two_step);
+    assert_eq!(in_place, two_step);
+
+    time("1000x1000 scale then sub", 10, || w.sub(g.scale(lr)).unwrap());
+    time("1000x1000 scaled_add", 10, || w.scaled_add(-lr, &g).unwrap());
+    let mut buffer = w.clone();
+    time("1000x1000 axpy (in place)", 10, || buffer.axpy(-lr, &g).unwrap());
+}
@@ -0,0 +1,26 @@
+//! Timing and memory comparison for dense and banded tridiagonal solves, run with `cargo bench --bench banded_solve`.
+mod common;
+
+use matrix::{BandedMatrix, Matrix};
+use common::time;
+
+fn main() {
+    let n = 1000;
+    let mut band = BandedMatrix::zeros(n, 1, 1).unwrap();
+    for i in 0..n {
+        band.set(i, i, 2.0).unwrap();
+        if i > 0 {
+            band.set(i, i - 1, -1.0).unwrap();
+            band.set(i - 1, i, -1.0).unwrap();
+        }
+    }
+    let dense = band.to_dense();
+    let b = Matrix::from_rows((0..n).map(|i| vec![(i as f64 / n as f64).sin()]).collect()).unwrap();
+    assert!(band.solve(&b).unwrap().diff(&dense.solve(&b).unwrap(), 1e-6).is_equal());
+
+    let bytes = |values: usize| values * std::mem::size_of::<f64>();
+    println!("dense storage {:>10} bytes, banded storage {:>8} bytes", bytes(n * n), bytes(n * 3));
+    time("1000x1000 tridiagonal, dense solve", 3, || dense.solve(&b).unwrap());
+    time("1000x1000 tridiagonal, solve_tridiagonal", 3, || dense.solve_tridiagonal(&b).unwrap());
+    time("1000x1000 tridiagonal, banded solve", 20, || band.solve(&b).unwrap());
+}
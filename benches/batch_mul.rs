@@ -0,0 +1,24 @@
+//! Timing comparison for batched multiplication, run with `cargo bench --bench batch_mul`
+//! (add `--features parallel` to split the batch across threads).
+mod common;
+
+use matrix::Matrix;
+use matrix::batch::batch_mul;
+use matrix::mul::Mul;
+use common::time;
+
+fn main() {
+    for (count, n) in [(64, 16), (64, 64), (16, 256)] {
+        let a: Vec<Matrix> = (0..count).map(|i| Matrix::from_seed(n, n, i).unwrap()).collect();
+        let b: Vec<Matrix> = (0..count).map(|i| Matrix::from_seed(n, n, 1000 + i).unwrap()).collect();
+
+        let looped: Vec<Matrix> = a.iter().zip(&b).map(|(x, y)| x.mul(y).unwrap()).collect();
+        assert_eq!(batch_mul(&a, &b).unwrap(), looped);
+
+        let runs = if n > 64 { 3 } else { 20 };
+        time(&format!("{}x {}x{} loop of mul", count, n, n), runs, || {
+            a.iter().zip(&b).map(|(x, y)| x.mul(y).unwrap()).collect::<Vec<_>>()
+        });
+        time(&format!("{}x {}x{} batch_mul", count, n, n), runs, || batch_mul(&a, &b).unwrap());
+    }
+}
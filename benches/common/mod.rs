@@ -0,0 +1,40 @@
+//! Helpers shared by the benchmarks, pulled in with `mod common;`.
+// Every bench uses a different part of this module.
+#![allow(dead_code)]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Runs `f` `runs` times, prints the mean time per run under `name` and returns it.
+pub fn time<T>(name: &str, runs: u32, mut f: impl FnMut() -> T) -> Duration {
+    let start = Instant::now();
+    for _ in 0..runs {
+        black_box(f());
+    }
+    let elapsed = start.elapsed() / runs;
+    println!("{:<40} {:>12?}", name, elapsed);
+    elapsed
+}
+
+/// The system allocator, counting the allocations. A bench opts in with
+/// `#[global_allocator] static ALLOCATOR: CountingAllocator = CountingAllocator;`.
+pub struct CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+/// Number of allocations made through `CountingAllocator` so far.
+pub fn allocations() -> u64 {
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
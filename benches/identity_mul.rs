@@ -0,0 +1,25 @@
+//! Timing comparison for `mul` with identity and zero operands, run with `cargo bench --bench identity_mul`.
+mod common;
+
+use matrix::Matrix;
+use matrix::mul::Mul;
+use common::time;
+
+fn main() {
+    let n = 500;
+    let identity = Matrix::identity(n).unwrap();
+    let zero = Matrix::zeros(n, n).unwrap();
+    let a = Matrix::from_seed(n, n, 1).unwrap();
+    let b = Matrix::from_seed(n, n, 2).unwrap();
+    // `mul_transposed` has no shortcut, it is the cost of the full product.
+    let computed = |x: &Matrix, y: &Matrix| x.mul_transposed(&y.transpose()).unwrap();
+    assert_eq!(identity.mul(&a).unwrap(), computed(&identity, &a));
+    assert_eq!(zero.mul(&a).unwrap(), computed(&zero, &a));
+
+    time("500x500 identity * dense, computed", 3, || computed(&identity, &a));
+    time("500x500 identity.mul(&dense)", 3, || identity.mul(&a).unwrap());
+    time("500x500 dense.mul(&identity)", 3, || a.mul(&identity).unwrap());
+    time("500x500 zero.mul(&dense)", 3, || zero.mul(&a).unwrap());
+    time("500x500 dense * dense, computed", 3, || computed(&a, &b));
+    time("500x500 dense.mul(&dense)", 3, || a.mul(&b).unwrap());
+}
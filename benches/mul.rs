@@ -0,0 +1,36 @@
+//! Timing comparison for the multiplication strategies, run with `cargo bench --bench mul`.
+mod common;
+
+use matrix::Matrix;
+use matrix::mul::Mul;
+use common::time;
+
+fn square(n: usize, seed: usize) -> Matrix {
+    Matrix::from_rows(
+        (0..n)
+            .map(|i| (0..n).map(|j| ((i * 31 + j * 17 + seed) % 97) as f64 / 97.0).collect())
+            .collect(),
+    )
+    .unwrap()
+}
+
+fn naive(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut result = Matrix::builder().rows(a.rows).cols(b.cols).done().unwrap();
+    for i in 0..a.rows {
+        for j in 0..b.cols {
+            for k in 0..a.cols {
+                result.data[i][j] += a.data[i][k] * b.data[k][j];
+            }
+        }
+    }
+    result
+}
+
+fn main() {
+    let (a, b) = (square(300, 1), square(300, 2));
+    let b_t = b.transpose();
+
+    time("300x300 naive (i-j-k, strided column)", 5, || naive(&a, &b));
+    time("300x300 mul_transposed (pre-transposed)", 5, || a.mul_transposed(&b_t).unwrap());
+    time("300x300 mul (transpose + multiply)", 5, || a.mul(&b).unwrap());
+}
@@ -0,0 +1,41 @@
+//! Timing and allocation comparison for `mul` and `mul_into` in a loop, run with `cargo bench --bench mul_into`.
+mod common;
+
+use matrix::Matrix;
+use matrix::mul::Mul;
+use std::hint::black_box;
+use std::time::Instant;
+use common::{allocations, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn measure(name: &str, runs: u32, mut f: impl FnMut()) {
+    let before = allocations();
+    let start = Instant::now();
+    for _ in 0..runs {
+        f();
+    }
+    let elapsed = start.elapsed();
+    let allocations = allocations() - before;
+    println!("{:<40} {:>12?} {:>10} allocations", name, elapsed, allocations);
+}
+
+fn main() {
+    for n in [8, 32] {
+        let a = Matrix::from_seed(n, n, 1).unwrap();
+        let b = Matrix::from_seed(n, n, 2).unwrap();
+        let mut c = Matrix::zeros(n, n).unwrap();
+        a.mul_into(&b, &mut c).unwrap();
+        assert_eq!(c, a.mul(&b).unwrap());
+
+        measure(&format!("{}x{} c = a.mul(&b) x1000", n, n), 1000, || {
+            c = a.mul(&b).unwrap();
+            black_box(&c);
+        });
+        measure(&format!("{}x{} a.mul_into(&b, &mut c) x1000", n, n), 1000, || {
+            a.mul_into(&b, &mut c).unwrap();
+            black_box(&c);
+        });
+    }
+}
@@ -0,0 +1,33 @@
+//! Timing comparison for rank-1 updates, run with `cargo bench --bench rank1_update`.
+mod common;
+
+use matrix::Matrix;
+use matrix::add::Add;
+use matrix::mul::Mul;
+use common::time;
+
+fn main() {
+    let n = 500;
+    let a = Matrix::from_seed(n, n, 1).unwrap();
+    let x: Vec<f64> = Matrix::from_seed(1, n, 2).unwrap().data.remove(0);
+    let outer = |x: &[f64]| {
+        let u = Matrix::from_flat(n, 1, x.to_vec()).unwrap();
+        let v = Matrix::from_flat(1, n, x.to_vec()).unwrap();
+        u.mul(v).unwrap()
+    };
+
+    let allocating = a.add(outer(&x).scale(0.5)).unwrap();
+    let mut general = a.clone();
+    general.rank1_update(0.5, &x, &x).unwrap();
+    let mut symmetric = a.add(a.transpose()).unwrap();
+    let symmetric_expected = symmetric.add(outer(&x).scale(0.5)).unwrap();
+    symmetric.rank1_update_sym(0.5, &x).unwrap();
+    assert!(general.diff(&allocating, 1e-12).is_equal());
+    assert!(symmetric.diff(&symmetric_expected, 1e-12).is_equal());
+
+    time("500x500 add(outer product)", 20, || a.add(outer(&x).scale(0.5)).unwrap());
+    let mut buffer = a.clone();
+    time("500x500 rank1_update", 20, || buffer.rank1_update(0.5, &x, &x).unwrap());
+    let mut buffer = a.add(a.transpose()).unwrap();
+    time("500x500 rank1_update_sym", 20, || buffer.rank1_update_sym(0.5, &x).unwrap());
+}
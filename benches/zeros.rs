@@ -0,0 +1,19 @@
+//! Timing comparison for zero matrix construction, run with `cargo bench --bench zeros`.
+mod common;
+
+use matrix::Matrix;
+use common::time;
+
+fn main() {
+    let (rows, cols) = (10_000, 1_000);
+
+    let cloned = Matrix { rows, cols, data: vec![vec![0.0; cols]; rows] };
+    let built = Matrix::builder().rows(rows).cols(cols).done().unwrap();
+    let zeros = Matrix::zeros(rows, cols).unwrap();
+    assert_eq!(cloned, zeros);
+    assert_eq!(built, zeros);
+
+    time("10000x1000 vec![vec![0.0; cols]; rows]", 5, || vec![vec![0.0f64; cols]; rows]);
+    time("10000x1000 builder", 5, || Matrix::builder().rows(rows).cols(cols).done().unwrap());
+    time("10000x1000 Matrix::zeros", 5, || Matrix::zeros(rows, cols).unwrap());
+}
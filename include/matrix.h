@@ -0,0 +1,58 @@
+/* C interface of the `matrix` crate, built with `--features ffi`. Mirrors src/ffi.rs. */
+#ifndef MATRIX_H
+#define MATRIX_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Opaque handle, created by matrix_new, matrix_add and matrix_mul and released with matrix_free. */
+typedef struct Matrix Matrix;
+
+/* Result code of every function. On failure the out-parameters are left untouched. */
+typedef enum MatrixStatus {
+    MATRIX_OK = 0,
+    /* A required pointer argument was null. */
+    MATRIX_NULL_POINTER = 1,
+    /* len is not rows * cols. */
+    MATRIX_LENGTH_MISMATCH = 2,
+    MATRIX_INVALID_MATRIX_SIZE = 3,
+    MATRIX_DIMENSION_MISMATCH = 4,
+    MATRIX_NOT_SQUARE = 5,
+    MATRIX_INDEX_OUT_OF_BOUNDS = 6,
+    MATRIX_SINGULAR = 7,
+    /* Any other shape error. */
+    MATRIX_SHAPE_ERROR = 8,
+    /* Any other numeric error. */
+    MATRIX_NUMERIC_ERROR = 9,
+    /* Allocation failures, I/O and parse errors. */
+    MATRIX_OTHER_ERROR = 10,
+    /* The call panicked; no output was written. */
+    MATRIX_PANIC = 11,
+} MatrixStatus;
+
+/* Creates a rows x cols matrix from len values in row-major order. */
+MatrixStatus matrix_new(size_t rows, size_t cols, const double *data, size_t len, Matrix **out);
+
+/* Releases a handle. NULL is accepted and ignored. */
+void matrix_free(Matrix *matrix);
+
+MatrixStatus matrix_shape(const Matrix *matrix, size_t *rows, size_t *cols);
+
+/* Stores a + b as a new handle in *out. */
+MatrixStatus matrix_add(const Matrix *a, const Matrix *b, Matrix **out);
+
+/* Stores a * b as a new handle in *out. */
+MatrixStatus matrix_mul(const Matrix *a, const Matrix *b, Matrix **out);
+
+MatrixStatus matrix_get(const Matrix *matrix, size_t row, size_t col, double *out);
+
+MatrixStatus matrix_det(const Matrix *matrix, double *out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* MATRIX_H */
@@ -0,0 +1,220 @@
+use crate::matrix::Matrix;
+use crate::errors::MatrixError;
+use crate::operations::ops::MatrixOps;
+use crate::view::MatrixLike;
+
+/// Square matrix whose non-zero elements are all within `lower` diagonals below the main one and `upper` above,
+/// stored diagonal band only: `n * (lower + upper + 1)` values instead of `n * n`.
+///
+/// Tridiagonal matrices are `(1, 1)`, the pentadiagonal ones of finite differences `(2, 2)`.
+/// # Example
+/// ```
+/// # use matrix::{matrix, BandedMatrix, Matrix, MatrixError};
+/// fn main() {
+///     let dense = matrix![2.0, -1.0, 0.0; -1.0, 2.0, -1.0; 0.0, -1.0, 2.0];
+///     let band = BandedMatrix::from_dense(&dense, 1, 1).unwrap();
+///
+///     assert_eq!(band.bandwidth(), (1, 1));
+///     assert_eq!((band.get(0, 1), band.get(0, 2), band.get(3, 0)), (Some(-1.0), Some(0.0), None));
+///     assert_eq!(band.to_dense(), dense);
+///     assert_eq!(band.mul_vec(&[1.0, 1.0, 1.0]).unwrap(), vec![1.0, 0.0, 1.0]);
+///
+///     assert_eq!(
+///         BandedMatrix::from_dense(&dense, 0, 1),
+///         Err(MatrixError::OutsideBand { row: 1, col: 0, lower: 0, upper: 1 })
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandedMatrix {
+    n: usize,
+    lower: usize,
+    upper: usize,
+    /// Row `i` holds the elements `(i, i - lower) ..= (i, i + upper)`; the positions outside the matrix are 0.
+    band: Vec<Vec<f64>>,
+}
+
+impl BandedMatrix {
+    /// Creates an `n x n` zero matrix with the given bandwidths.
+    ///
+    /// Returns `InvalidMatrixSize` if `n` is 0 and `InvalidOperation` if a bandwidth is not less than `n`.
+    pub fn zeros(n: usize, lower: usize, upper: usize) -> Result<Self, MatrixError> {
+        if n == 0 {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+        if lower >= n || upper >= n {
+            return Err(MatrixError::InvalidOperation("bandwidths must be less than the size of the matrix"));
+        }
+        Ok(BandedMatrix { n, lower, upper, band: vec![vec![0.0; lower + upper + 1]; n] })
+    }
+
+    /// Copies the band of a square dense matrix.
+    ///
+    /// Returns `NotSquare` for non-square matrices, `OutsideBand` with the first non-zero element outside the band,
+    /// and the errors of `zeros` for the bandwidths.
+    pub fn from_dense(matrix: &Matrix, lower: usize, upper: usize) -> Result<Self, MatrixError> {
+        if matrix.rows != matrix.cols {
+            return Err(MatrixError::NotSquare { rows: matrix.rows, cols: matrix.cols });
+        }
+
+        let mut result = BandedMatrix::zeros(matrix.rows, lower, upper)?;
+        for (i, row) in matrix.data.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                if value != 0.0 {
+                    result.set(i, j, value)?;
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Expands the matrix to dense storage.
+    pub fn to_dense(&self) -> Matrix {
+        let data = (0..self.n).map(|i| (0..self.n).map(|j| self.at(i, j)).collect()).collect();
+        Matrix { rows: self.n, cols: self.n, data }
+    }
+
+    /// Returns the number of rows, which is also the number of columns.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the `(lower, upper)` bandwidths the matrix was created with.
+    pub fn bandwidth(&self) -> (usize, usize) {
+        (self.lower, self.upper)
+    }
+
+    /// Gets the element at (`row`, `col`), 0 outside the band and `None` outside the matrix.
+    pub fn get(&self, row: usize, col: usize) -> Option<f64> {
+        (row < self.n && col < self.n).then(|| self.at(row, col))
+    }
+
+    /// Sets the element at (`row`, `col`), returning `IndexOutOfBounds` outside the matrix and `OutsideBand`
+    /// outside the band.
+    pub fn set(&mut self, row: usize, col: usize, value: f64) -> Result<(), MatrixError> {
+        if row >= self.n || col >= self.n {
+            return Err(MatrixError::IndexOutOfBounds { index: row.max(col), bound: self.n });
+        }
+        let (lower, upper) = (self.lower, self.upper);
+        let offset = self.offset(row, col).ok_or(MatrixError::OutsideBand { row, col, lower, upper })?;
+        self.band[row][offset] = value;
+        Ok(())
+    }
+
+    /// Computes `A x`, in `O(n * (lower + upper))`. Returns `ShapeMismatch` unless `x` has `n` values.
+    pub fn mul_vec(&self, x: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        if x.len() != self.n {
+            return Err(MatrixError::ShapeMismatch { operation: "mul_vec", left: (self.n, self.n), right: (x.len(), 1) });
+        }
+
+        Ok((0..self.n)
+            .map(|i| {
+                let (start, end) = self.columns(i);
+                (start..end).map(|j| self.at(i, j) * x[j]).sum()
+            })
+            .collect())
+    }
+
+    /// Solves `A X = B` with an LU factorization computed inside the band, in `O(n * lower * upper)` plus
+    /// `O(n * (lower + upper))` per column of `B`, without ever allocating an `n x n` matrix.
+    ///
+    /// Like `Matrix::solve_tridiagonal` it does not pivot, since row exchanges would widen the band: it is meant
+    /// for the diagonally dominant or symmetric positive-definite systems of discretizations, and returns
+    /// `Singular` on a zero pivot. Returns `DimensionMismatch` if `B` does not have `n` rows.
+    /// # Example
+    /// ```
+    /// # use matrix::{BandedMatrix, Matrix};
+    /// fn main() {
+    ///     // Pentadiagonal and diagonally dominant.
+    ///     let n = 50;
+    ///     let mut band = BandedMatrix::zeros(n, 2, 2).unwrap();
+    ///     for i in 0..n {
+    ///         band.set(i, i, 6.0).unwrap();
+    ///         for (offset, value) in [(1, -2.0), (2, 0.5)] {
+    ///             if i + offset < n {
+    ///                 band.set(i, i + offset, value).unwrap();
+    ///                 band.set(i + offset, i, value * 1.5).unwrap();
+    ///             }
+    ///         }
+    ///     }
+    ///     let b = Matrix::from_rows((0..n).map(|i| vec![(i as f64).cos(), 1.0]).collect()).unwrap();
+    ///
+    ///     let x = band.solve(&b).unwrap();
+    ///     assert!(x.diff(&band.to_dense().solve(&b).unwrap(), 1e-12).is_equal());
+    ///     let column: Vec<f64> = x.data.iter().map(|row| row[0]).collect();
+    ///     let ax = band.mul_vec(&column).unwrap();
+    ///     assert!(ax.iter().zip(b.data.iter()).all(|(y, b)| (y - b[0]).abs() < 1e-12));
+    ///
+    ///     let swap = BandedMatrix::from_dense(&Matrix::from_rows(vec![vec![0.0, 1.0], vec![1.0, 0.0]]).unwrap(), 1, 1).unwrap();
+    ///     assert!(swap.solve(&Matrix::identity(2).unwrap()).is_err());
+    ///     assert!(band.solve(&Matrix::zeros(3, 1).unwrap()).is_err());
+    /// }
+    /// ```
+    pub fn solve(&self, b: &Matrix) -> Result<Matrix, MatrixError> {
+        if b.rows != self.n {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        // Doolittle LU in place: the multipliers replace the band below the diagonal, U the rest.
+        // Row `k` only updates rows `k + 1 ..= k + lower` in columns `k + 1 ..= k + upper`, all inside the band.
+        let mut lu = self.clone();
+        for k in 0..self.n {
+            let pivot = lu.at(k, k);
+            if pivot == 0.0 {
+                return Err(MatrixError::Singular { operation: "BandedMatrix::solve" });
+            }
+            let (_, end) = lu.columns(k);
+            for i in k + 1..(k + self.lower + 1).min(self.n) {
+                let factor = lu.at(i, k) / pivot;
+                lu.band[i][k + self.lower - i] = factor;
+                for j in k + 1..end {
+                    let value = lu.at(k, j);
+                    lu.band[i][j + self.lower - i] -= factor * value;
+                }
+            }
+        }
+
+        let mut x = b.clone();
+        for c in 0..b.cols {
+            for i in 0..self.n {
+                let (start, _) = lu.columns(i);
+                let sum: f64 = (start..i).map(|j| lu.at(i, j) * x.data[j][c]).sum();
+                x.data[i][c] -= sum;
+            }
+            for i in (0..self.n).rev() {
+                let (_, end) = lu.columns(i);
+                let sum: f64 = (i + 1..end).map(|j| lu.at(i, j) * x.data[j][c]).sum();
+                x.data[i][c] = (x.data[i][c] - sum) / lu.at(i, i);
+            }
+        }
+        Ok(x)
+    }
+
+    /// Position of (`row`, `col`) in `band[row]`, `None` outside the band.
+    fn offset(&self, row: usize, col: usize) -> Option<usize> {
+        let offset = (col + self.lower).checked_sub(row)?;
+        (offset <= self.lower + self.upper).then_some(offset)
+    }
+
+    /// Reads an element inside the matrix.
+    fn at(&self, row: usize, col: usize) -> f64 {
+        self.offset(row, col).map_or(0.0, |offset| self.band[row][offset])
+    }
+
+    /// Range of the columns of row `i` that are inside the band, as `start..end`.
+    fn columns(&self, i: usize) -> (usize, usize) {
+        (i.saturating_sub(self.lower), (i + self.upper + 1).min(self.n))
+    }
+}
+
+impl MatrixLike for BandedMatrix {
+    fn shape(&self) -> (usize, usize) {
+        (self.n, self.n)
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<f64> {
+        BandedMatrix::get(self, row, col)
+    }
+}
+
+impl MatrixOps for BandedMatrix {}
@@ -0,0 +1,311 @@
+use crate::display::{fmt_rows, DisplayOptions};
+use crate::matrix::{dim_checked_mul, Matrix};
+use crate::operations::kernels::map_rows;
+use crate::operations::mul::Mul;
+use crate::MatrixError::{self, DataMismatch, InvalidMatrixSize};
+
+/// Complex number with `f64` parts, the element type of `CMatrix`.
+///
+/// It supports `+`, `-`, `*` and unary `-`, and converts from `(re, im)` tuples.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    /// The imaginary unit.
+    pub const I: Complex = Complex { re: 0.0, im: 1.0 };
+
+    /// Creates `re + im·i`.
+    pub fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    /// Returns the complex conjugate, `re - im·i`.
+    pub fn conj(self) -> Self {
+        Self { re: self.re, im: -self.im }
+    }
+
+    /// Returns the modulus `|z|`.
+    pub fn abs(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+}
+
+impl From<(f64, f64)> for Complex {
+    fn from((re, im): (f64, f64)) -> Self {
+        Self { re, im }
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Self {
+        Self { re, im: 0.0 }
+    }
+}
+
+impl std::ops::Add for Complex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self { re: self.re + other.re, im: self.im + other.im }
+    }
+}
+
+impl std::ops::Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self { re: self.re - other.re, im: self.im - other.im }
+    }
+}
+
+impl std::ops::Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            re: self.re * other.re - self.im * other.im,
+            im: self.re * other.im + self.im * other.re,
+        }
+    }
+}
+
+impl std::ops::Neg for Complex {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { re: -self.re, im: -self.im }
+    }
+}
+
+impl std::fmt::Display for Complex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.im.is_sign_negative() {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+/// Matrix of complex elements, with the same builder and `add`, `sub`, `mul` operations as `Matrix`.
+/// # Example
+/// ```
+/// # use matrix::{matrix, CMatrix, Complex, add::Add};
+/// fn main() {
+///     let a = CMatrix::builder()
+///         .rows(1)
+///         .cols(2)
+///         .data(vec![vec![(1.0, 2.0).into(), (0.0, -1.0).into()]])
+///         .done()
+///         .unwrap();
+///
+///     assert_eq!(a.add(a.conjugate()).unwrap(), CMatrix::from_real(&matrix![2.0, 0.0]));
+///     assert_eq!(a.scale(Complex::I).data[0][0], Complex::new(-2.0, 1.0));
+///     assert_eq!(format!("{}", a), "|1+2i 0-1i|");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CMatrix {
+    pub rows: usize,
+    pub cols: usize,
+    pub data: Vec<Vec<Complex>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuilderCMatrix {
+    rows: Option<usize>,
+    cols: Option<usize>,
+    data: Option<Vec<Vec<Complex>>>,
+}
+
+impl std::fmt::Display for CMatrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_rows(f, self.rows, self.cols, &self.data, &DisplayOptions::default())
+    }
+}
+
+impl CMatrix {
+    /// Creates a builder for a `CMatrix`, see `Matrix::builder`.
+    pub fn builder() -> BuilderCMatrix {
+        BuilderCMatrix {
+            rows: Some(1),
+            cols: Some(1),
+            data: Some(Vec::new()),
+        }
+    }
+
+    /// Creates the `n x n` identity matrix.
+    pub fn identity(n: usize) -> Result<Self, MatrixError> {
+        let mut result = CMatrix::builder().rows(n).cols(n).done()?;
+        for i in 0..n {
+            result.data[i][i] = Complex::from(1.0);
+        }
+        Ok(result)
+    }
+
+    /// Creates a complex matrix from a real one, with all imaginary parts zero.
+    pub fn from_real(matrix: &Matrix) -> Self {
+        let data = matrix.data
+            .iter()
+            .map(|row| row.iter().map(|&x| Complex::from(x)).collect())
+            .collect();
+        CMatrix { rows: matrix.rows, cols: matrix.cols, data }
+    }
+
+    /// Gets the element at (`row`, `col`), `None` if it is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<Complex> {
+        self.data.get(row).and_then(|r| r.get(col)).copied()
+    }
+
+    /// Returns the real parts as a real matrix.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, CMatrix, Complex};
+    /// fn main() {
+    ///     let a = CMatrix::builder().data(vec![vec![Complex::new(3.0, -4.0)]]).done().unwrap();
+    ///
+    ///     assert_eq!(a.re(), matrix![3.0]);
+    ///     assert_eq!(a.im(), matrix![-4.0]);
+    ///     assert_eq!(CMatrix::from_real(&a.re()).im(), matrix![0.0]);
+    /// }
+    /// ```
+    pub fn re(&self) -> Matrix {
+        self.parts(|z| z.re)
+    }
+
+    /// Returns the imaginary parts as a real matrix, see `re`.
+    pub fn im(&self) -> Matrix {
+        self.parts(|z| z.im)
+    }
+
+    fn parts(&self, f: impl Fn(Complex) -> f64) -> Matrix {
+        let data = self.data
+            .iter()
+            .map(|row| row.iter().map(|&z| f(z)).collect())
+            .collect();
+        Matrix { rows: self.rows, cols: self.cols, data }
+    }
+
+    /// Multiplies every element by the complex scalar `k`.
+    pub fn scale(&self, k: Complex) -> CMatrix {
+        CMatrix { rows: self.rows, cols: self.cols, data: map_rows(&self.data, |z| z * k) }
+    }
+
+    /// Returns the element-wise complex conjugate.
+    pub fn conjugate(&self) -> CMatrix {
+        CMatrix { rows: self.rows, cols: self.cols, data: map_rows(&self.data, Complex::conj) }
+    }
+
+    /// Returns the conjugate transpose (`A^H`).
+    pub fn hermitian(&self) -> CMatrix {
+        let data = (0..self.cols)
+            .map(|j| self.data.iter().map(|row| row[j].conj()).collect())
+            .collect();
+        CMatrix { rows: self.cols, cols: self.rows, data }
+    }
+
+    /// Checks if the matrix is square and equal to its conjugate transpose, within `eps` per element.
+    pub fn is_hermitian(&self, eps: f64) -> bool {
+        self.rows == self.cols && approx_eq(self, &self.hermitian(), eps)
+    }
+
+    /// Checks if the matrix is square and `A^H A` is the identity, within `eps` per element.
+    /// # Example
+    /// ```
+    /// # use matrix::{CMatrix, Complex, mul::Mul};
+    /// fn pauli(data: [[(f64, f64); 2]; 2]) -> CMatrix {
+    ///     let data = data.iter().map(|row| row.iter().map(|&z| z.into()).collect()).collect();
+    ///     CMatrix::builder().rows(2).cols(2).data(data).done().unwrap()
+    /// }
+    ///
+    /// fn main() {
+    ///     let x = pauli([[(0.0, 0.0), (1.0, 0.0)], [(1.0, 0.0), (0.0, 0.0)]]);
+    ///     let y = pauli([[(0.0, 0.0), (0.0, -1.0)], [(0.0, 1.0), (0.0, 0.0)]]);
+    ///     let z = pauli([[(1.0, 0.0), (0.0, 0.0)], [(0.0, 0.0), (-1.0, 0.0)]]);
+    ///     let identity = CMatrix::identity(2).unwrap();
+    ///
+    ///     for p in [&x, &y, &z] {
+    ///         assert!(p.is_unitary(1e-12));
+    ///         assert!(p.is_hermitian(1e-12));
+    ///         assert_eq!(p.mul(p.clone()).unwrap(), identity);
+    ///     }
+    ///
+    ///     // XY = iZ
+    ///     assert_eq!(x.mul(y.clone()).unwrap(), z.scale(Complex::I));
+    ///
+    ///     // iX is still unitary, but no longer Hermitian.
+    ///     let ix = x.scale(Complex::I);
+    ///     assert!(ix.is_unitary(1e-12));
+    ///     assert!(!ix.is_hermitian(1e-12));
+    ///
+    ///     // A non-unitary matrix.
+    ///     assert!(!x.scale(Complex::from(2.0)).is_unitary(1e-12));
+    /// }
+    /// ```
+    pub fn is_unitary(&self, eps: f64) -> bool {
+        if self.rows != self.cols {
+            return false;
+        }
+        match (self.hermitian().mul(self.clone()), CMatrix::identity(self.rows)) {
+            (Ok(product), Ok(identity)) => approx_eq(&product, &identity, eps),
+            _ => false,
+        }
+    }
+}
+
+/// Compares two equally-shaped complex matrices element by element, within `eps` in modulus.
+fn approx_eq(a: &CMatrix, b: &CMatrix, eps: f64) -> bool {
+    a.data
+        .iter()
+        .flatten()
+        .zip(b.data.iter().flatten())
+        .all(|(&x, &y)| (x - y).abs() <= eps)
+}
+
+impl BuilderCMatrix {
+    /// Sets the number of rows for the matrix.
+    /// - If no `data` is provided, it initializes the matrix with zeros.
+    pub fn rows(mut self, n: usize) -> Self {
+        self.rows = Some(n);
+        self
+    }
+
+    /// Sets the number of columns for the matrix.
+    /// - If no `data` is provided, it initializes the matrix with zeros.
+    pub fn cols(mut self, n: usize) -> Self {
+        self.cols = Some(n);
+        self
+    }
+
+    /// Sets the data for the matrix.
+    pub fn data(mut self, data: Vec<Vec<Complex>>) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Finalizes the matrix creation process, with the same checks as `BuilderMatrix::done`.
+    pub fn done(self) -> Result<CMatrix, MatrixError> {
+        let BuilderCMatrix { rows, cols, data } = self;
+        let (rows, cols) = (rows.unwrap_or(1), cols.unwrap_or(1));
+        let data = data.unwrap_or_default();
+
+        if rows == 0 || cols == 0 {
+            return Err(InvalidMatrixSize);
+        }
+
+        if data.is_empty() {
+            dim_checked_mul(rows, cols)?;
+            let data = vec![vec![Complex::default(); cols]; rows];
+            return Ok(CMatrix { rows, cols, data });
+        }
+
+        if data.len() != rows || data.iter().any(|row| row.len() != cols) {
+            return Err(DataMismatch);
+        }
+
+        Ok(CMatrix { rows, cols, data })
+    }
+}
@@ -0,0 +1,137 @@
+use crate::matrix::Matrix;
+use std::fmt::{Display, Formatter, Result};
+
+/// Element-wise comparison report produced by `Matrix::diff`.
+///
+/// The `Display` implementation prints a short summary followed by the sample differences,
+/// which makes it a much better test failure message than the `Debug` output of both matrices.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatrixDiff {
+    /// Shape (rows, cols) of the left matrix.
+    pub left_shape: (usize, usize),
+    /// Shape (rows, cols) of the right matrix.
+    pub right_shape: (usize, usize),
+    /// Tolerance used for the comparison.
+    pub eps: f64,
+    /// Number of elements whose absolute difference exceeds `eps`.
+    pub differing: usize,
+    /// Largest absolute difference found, `0.0` if nothing differs.
+    pub max_abs_diff: f64,
+    /// Location (row, col) of the largest difference.
+    pub max_location: Option<(usize, usize)>,
+    /// Up to `MatrixDiff::MAX_SAMPLES` differences as (row, col, left value, right value), in row-major order.
+    pub samples: Vec<(usize, usize, f64, f64)>,
+}
+
+impl MatrixDiff {
+    /// Maximum number of sample differences kept in the report.
+    pub const MAX_SAMPLES: usize = 10;
+
+    /// Returns true if both matrices have the same shape.
+    pub fn shapes_match(&self) -> bool {
+        self.left_shape == self.right_shape
+    }
+
+    /// Returns true if the shapes match and no element differs by more than `eps`.
+    pub fn is_equal(&self) -> bool {
+        self.shapes_match() && self.differing == 0
+    }
+}
+
+impl Display for MatrixDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let (lr, lc) = self.left_shape;
+        let (rr, rc) = self.right_shape;
+
+        if !self.shapes_match() {
+            return write!(f, "shape mismatch: {}x{} vs {}x{}", lr, lc, rr, rc);
+        }
+
+        if self.differing == 0 {
+            return write!(f, "matrices are equal ({}x{}, eps = {})", lr, lc, self.eps);
+        }
+
+        write!(f, "{} of {} elements differ (eps = {})", self.differing, lr * lc, self.eps)?;
+        if let Some((i, j)) = self.max_location {
+            write!(f, ", max abs diff {} at ({}, {})", self.max_abs_diff, i, j)?;
+        }
+
+        for (i, j, left, right) in &self.samples {
+            write!(f, "\n  ({}, {}): {} vs {}", i, j, left, right)?;
+        }
+
+        if self.differing > self.samples.len() {
+            write!(f, "\n  ... and {} more", self.differing - self.samples.len())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Matrix {
+    /// Compares two matrices element by element and returns a `MatrixDiff` report.
+    ///
+    /// A shape mismatch is not an error, it is reported through `MatrixDiff::shapes_match`.
+    /// Two elements are considered equal if they are `==` or if their absolute difference is at most `eps`,
+    /// so a `NaN` is always reported as a difference.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let a = Matrix::builder().rows(2).cols(2).data(vec![
+    ///         vec![1.0, 2.0],
+    ///         vec![3.0, 4.0],
+    ///     ]).done().unwrap();
+    ///
+    ///     let b = Matrix::builder().rows(2).cols(2).data(vec![
+    ///         vec![1.0, 2.5],
+    ///         vec![3.0, 4.0],
+    ///     ]).done().unwrap();
+    ///
+    ///     let diff = a.diff(&b, 1e-9);
+    ///
+    ///     assert!(!diff.is_equal());
+    ///     assert_eq!(diff.differing, 1);
+    ///     assert_eq!(diff.max_location, Some((0, 1)));
+    ///     assert_eq!(diff.to_string(), "1 of 4 elements differ (eps = 0.000000001), max abs diff 0.5 at (0, 1)\n  (0, 1): 2 vs 2.5");
+    ///
+    ///     let c = Matrix::builder().rows(1).cols(2).done().unwrap();
+    ///     assert_eq!(a.diff(&c, 1e-9).to_string(), "shape mismatch: 2x2 vs 1x2");
+    /// }
+    /// ```
+    pub fn diff(&self, other: &Matrix, eps: f64) -> MatrixDiff {
+        let mut report = MatrixDiff {
+            left_shape: (self.rows, self.cols),
+            right_shape: (other.rows, other.cols),
+            eps,
+            differing: 0,
+            max_abs_diff: 0.0,
+            max_location: None,
+            samples: Vec::new(),
+        };
+
+        if !report.shapes_match() {
+            return report;
+        }
+
+        for (i, (left_row, right_row)) in self.data.iter().zip(other.data.iter()).enumerate() {
+            for (j, (&left, &right)) in left_row.iter().zip(right_row.iter()).enumerate() {
+                let delta = (left - right).abs();
+                if left == right || delta <= eps {
+                    continue;
+                }
+
+                report.differing += 1;
+                if report.max_location.is_none() || delta > report.max_abs_diff || delta.is_nan() {
+                    report.max_abs_diff = delta;
+                    report.max_location = Some((i, j));
+                }
+                if report.samples.len() < MatrixDiff::MAX_SAMPLES {
+                    report.samples.push((i, j, left, right));
+                }
+            }
+        }
+
+        report
+    }
+}
@@ -0,0 +1,347 @@
+use crate::matrix::Matrix;
+use crate::errors::MatrixError;
+use std::fmt::{self, Display, Formatter};
+
+/// Controls how matrices are printed.
+///
+/// An axis longer than `threshold` is truncated to its first and last `edge_items` rows (or columns),
+/// with `...` in between, and a `[rows x cols matrix]` note is added at the end.
+/// `Display` for every matrix type uses `DisplayOptions::default()`, other options go through `Matrix::display_with`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayOptions {
+    pub threshold: usize,
+    pub edge_items: usize,
+}
+
+impl Default for DisplayOptions {
+    /// Truncates axes longer than 10 to 3 items on each side.
+    fn default() -> Self {
+        Self { threshold: 10, edge_items: 3 }
+    }
+}
+
+/// Formats a `Matrix` with custom `DisplayOptions`, see `Matrix::display_with`.
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixDisplay<'a> {
+    matrix: &'a Matrix,
+    options: DisplayOptions,
+}
+
+impl Display for MatrixDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_rows(f, self.matrix.rows, self.matrix.cols, &self.matrix.data, &self.options)
+    }
+}
+
+impl Matrix {
+    /// Returns a value that prints the matrix with the given `options` instead of the default ones.
+    /// # Example
+    /// ```
+    /// # use matrix::{Matrix, DisplayOptions};
+    /// fn main() {
+    ///     let small = Matrix::from_flat(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+    ///     assert_eq!(small.to_string(), "|1 2 3|\n|4 5 6|");
+    ///
+    ///     let big = Matrix::from_flat(12, 12, (0..144).map(|x| x as f64).collect()).unwrap();
+    ///     assert_eq!(
+    ///         big.to_string(),
+    ///         "|0 1 2 ... 9 10 11|\n\
+    ///          |12 13 14 ... 21 22 23|\n\
+    ///          |24 25 26 ... 33 34 35|\n\
+    ///          ...\n\
+    ///          |108 109 110 ... 117 118 119|\n\
+    ///          |120 121 122 ... 129 130 131|\n\
+    ///          |132 133 134 ... 141 142 143|\n\
+    ///          [12x12 matrix]"
+    ///     );
+    ///
+    ///     let options = DisplayOptions { threshold: 2, edge_items: 1 };
+    ///     assert_eq!(small.display_with(options).to_string(), "|1 ... 3|\n|4 ... 6|\n[2x3 matrix]");
+    /// }
+    /// ```
+    pub fn display_with(&self, options: DisplayOptions) -> MatrixDisplay<'_> {
+        MatrixDisplay { matrix: self, options }
+    }
+
+    /// Renders the matrix as an aligned text table, with `row_labels` on the left and `col_labels` as the header.
+    ///
+    /// Values are printed with `precision` decimals and right-aligned, and each column is as wide as its
+    /// widest label or value. Returns `ShapeMismatch` if the label counts don't match the dimensions.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let sales = matrix![1250.5, 980.0; 87.25, 1432.126];
+    ///
+    ///     let table = sales.to_table(&["north", "south"], &["q1", "second quarter"], 2).unwrap();
+    ///     assert_eq!(
+    ///         table,
+    ///         "      |      q1 | second quarter\n\
+    ///          ------+---------+---------------\n\
+    ///          north | 1250.50 |         980.00\n\
+    ///          south |   87.25 |        1432.13"
+    ///     );
+    ///
+    ///     assert_eq!(
+    ///         sales.to_table(&["north"], &["q1", "q2"], 2),
+    ///         Err(MatrixError::ShapeMismatch { operation: "to_table", left: (2, 2), right: (1, 2) })
+    ///     );
+    /// }
+    /// ```
+    pub fn to_table(&self, row_labels: &[&str], col_labels: &[&str], precision: usize) -> Result<String, MatrixError> {
+        if row_labels.len() != self.rows || col_labels.len() != self.cols {
+            return Err(MatrixError::ShapeMismatch {
+                operation: "to_table",
+                left: (self.rows, self.cols),
+                right: (row_labels.len(), col_labels.len()),
+            });
+        }
+
+        let cells: Vec<Vec<String>> = self.data
+            .iter()
+            .map(|row| row.iter().map(|x| format!("{:.*}", precision, x)).collect())
+            .collect();
+        let label_width = row_labels.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let widths: Vec<usize> = col_labels
+            .iter()
+            .enumerate()
+            .map(|(j, label)| {
+                let widest_value = cells.iter().map(|row| row[j].len()).max().unwrap_or(0);
+                widest_value.max(label.chars().count())
+            })
+            .collect();
+
+        let line = |label: &str, values: &mut dyn Iterator<Item = &str>| {
+            let mut line = format!("{:<width$}", label, width = label_width);
+            for (value, width) in values.zip(widths.iter()) {
+                line.push_str(&format!(" | {:>width$}", value, width = width));
+            }
+            line
+        };
+
+        let mut lines = vec![line("", &mut col_labels.iter().copied())];
+        let mut separator = "-".repeat(label_width);
+        for width in &widths {
+            separator.push_str(&format!("-+-{}", "-".repeat(*width)));
+        }
+        lines.push(separator);
+        for (label, row) in row_labels.iter().zip(cells.iter()) {
+            lines.push(line(label, &mut row.iter().map(String::as_str)));
+        }
+        Ok(lines.join("\n"))
+    }
+
+    /// Returns a copy with every element rounded to `decimals` decimal places, half away from zero.
+    ///
+    /// Rounding is done on `x * 10^decimals`, so a value whose binary representation is just below a half
+    /// (`1.005` is really `1.00499999...`) rounds down. Values too large to have any digit at that precision,
+    /// and NaN or infinities, are returned unchanged. A result of `-0.0` is normalized to `0.0`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.25, -1.25, 2.0 / 3.0; -0.001, 1e300, f64::NAN];
+    ///     let r = a.rounded(1);
+    ///
+    ///     assert_eq!(r.data[0], vec![1.3, -1.3, 0.7]);
+    ///     assert_eq!(r.data[1][0].to_bits(), 0.0f64.to_bits());
+    ///     assert_eq!(r.data[1][1], 1e300);
+    ///     assert!(r.data[1][2].is_nan());
+    ///     assert_eq!(matrix![0.5, 1.5, -2.5].rounded(0), matrix![1.0, 2.0, -3.0]);
+    /// }
+    /// ```
+    pub fn rounded(&self, decimals: u32) -> Matrix {
+        let data = self.data.iter().map(|row| row.iter().map(|&x| round_to(x, decimals)).collect()).collect();
+        Matrix { rows: self.rows, cols: self.cols, data }
+    }
+
+    /// Renders the matrix as CSV, one line per row, without a header.
+    ///
+    /// With `Some(decimals)` every value is rounded like `rounded` then printed with exactly that many decimals,
+    /// without changing the matrix. With `None` values use the shortest representation that reads back the same.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![0.1 + 0.2, -0.0004; 1e21, 3.0];
+    ///
+    ///     assert_eq!(a.to_csv_string(None), "0.30000000000000004,-0.0004\n1000000000000000000000,3");
+    ///     assert_eq!(a.to_csv_string(Some(2)), "0.30,0.00\n1000000000000000000000.00,3.00");
+    /// }
+    /// ```
+    pub fn to_csv_string(&self, decimals: Option<u32>) -> String {
+        self.format_rows(decimals, ",", "\n")
+    }
+
+    /// Renders the matrix as a LaTeX `bmatrix`, formatting values like `to_csv_string`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 0.5; -2.0 / 3.0, 4.0];
+    ///
+    ///     assert_eq!(
+    ///         a.to_latex(Some(3)),
+    ///         "\\begin{bmatrix}\n1.000 & 0.500 \\\\\n-0.667 & 4.000\n\\end{bmatrix}"
+    ///     );
+    ///     assert_eq!(a.to_latex(None).lines().nth(1), Some("1 & 0.5 \\\\"));
+    /// }
+    /// ```
+    pub fn to_latex(&self, decimals: Option<u32>) -> String {
+        format!("\\begin{{bmatrix}}\n{}\n\\end{{bmatrix}}", self.format_rows(decimals, " & ", " \\\\\n"))
+    }
+
+    /// Renders the matrix as a Markdown table whose header is the column indices, formatting values like `to_csv_string`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 0.125; -3.5, 2.0];
+    ///
+    ///     assert_eq!(
+    ///         a.to_markdown(Some(1)),
+    ///         "| 0 | 1 |\n| --- | --- |\n| 1.0 | 0.1 |\n| -3.5 | 2.0 |"
+    ///     );
+    /// }
+    /// ```
+    pub fn to_markdown(&self, decimals: Option<u32>) -> String {
+        let header: Vec<String> = (0..self.cols).map(|j| j.to_string()).collect();
+        format!(
+            "| {} |\n|{}\n| {} |",
+            header.join(" | "),
+            " --- |".repeat(self.cols),
+            self.format_rows(decimals, " | ", " |\n| ")
+        )
+    }
+
+    fn format_rows(&self, decimals: Option<u32>, separator: &str, row_separator: &str) -> String {
+        let format = |&x: &f64| match decimals {
+            Some(decimals) => format!("{:.*}", decimals as usize, round_to(x, decimals)),
+            None => x.to_string(),
+        };
+        self.data
+            .iter()
+            .map(|row| row.iter().map(format).collect::<Vec<_>>().join(separator))
+            .collect::<Vec<_>>()
+            .join(row_separator)
+    }
+}
+
+/// Rounds half away from zero to `decimals` places, see `Matrix::rounded`.
+fn round_to(x: f64, decimals: u32) -> f64 {
+    let factor = 10f64.powi(decimals.min(i32::MAX as u32) as i32);
+    let scaled = x * factor;
+    // From 2^52 on every f64 is an integer, so there is nothing left to round (and dividing back would lose bits).
+    if scaled.is_nan() || scaled.abs() >= (1u64 << 52) as f64 {
+        return x;
+    }
+    let rounded = scaled.round() / factor;
+    if rounded == 0.0 { 0.0 } else { rounded }
+}
+
+impl fmt::Debug for Matrix {
+    /// Prints the fields on one line, like a derived `Debug`, but matrices larger than the default
+    /// `DisplayOptions` threshold only show their shape and first and last elements.
+    /// The alternate form (`{:#?}`) always prints every element, one per line.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let small = matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0];
+    ///     assert_eq!(
+    ///         format!("{:?}", small),
+    ///         "Matrix { rows: 3, cols: 2, data: [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]] }"
+    ///     );
+    ///
+    ///     let big = Matrix::from_flat(20, 30, (0..600).map(|x| x as f64).collect()).unwrap();
+    ///     assert_eq!(
+    ///         format!("{:?}", big),
+    ///         "Matrix { rows: 20, cols: 30, data: [20x30, first = 0.0, last = 599.0] }"
+    ///     );
+    ///
+    ///     let pretty = format!("{:#?}", matrix![1.0, 2.0]);
+    ///     assert_eq!(pretty, "Matrix {\n    rows: 1,\n    cols: 2,\n    data: [\n        [\n            1.0,\n            2.0,\n        ],\n    ],\n}");
+    ///     assert_eq!(format!("{:#?}", big).lines().count(), 4 + 20 * 32 + 2);
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let options = DisplayOptions::default();
+        let large = self.rows > options.threshold || self.cols > options.threshold;
+        let first = self.data.first().and_then(|row| row.first());
+        let last = self.data.last().and_then(|row| row.last());
+
+        match (first, last) {
+            (Some(first), Some(last)) if large && !f.alternate() => write!(
+                f,
+                "Matrix {{ rows: {}, cols: {}, data: [{}x{}, first = {:?}, last = {:?}] }}",
+                self.rows, self.cols, self.rows, self.cols, first, last
+            ),
+            _ => f
+                .debug_struct("Matrix")
+                .field("rows", &self.rows)
+                .field("cols", &self.cols)
+                .field("data", &self.data)
+                .finish(),
+        }
+    }
+}
+
+/// Indices to print along an axis of length `len`, with `None` where the `...` goes.
+fn visible(len: usize, options: &DisplayOptions) -> Vec<Option<usize>> {
+    if len <= options.threshold || len <= 2 * options.edge_items {
+        return (0..len).map(Some).collect();
+    }
+    (0..options.edge_items)
+        .map(Some)
+        .chain(std::iter::once(None))
+        .chain((len - options.edge_items..len).map(Some))
+        .collect()
+}
+
+/// Writes rows as `|a b|` lines, shared by the `Display` impls of all the matrix types.
+///
+/// The fields are public, so `data` may not match `rows x cols`. Such a matrix is printed as a single
+/// `|<empty RxC>|` (no elements at all) or `|<inconsistent RxC>|` placeholder instead of indexing past the data.
+pub(crate) fn fmt_rows<T: Display>(
+    f: &mut Formatter<'_>,
+    rows: usize,
+    cols: usize,
+    data: &[Vec<T>],
+    options: &DisplayOptions,
+) -> fmt::Result {
+    if data.iter().all(Vec::is_empty) {
+        return write!(f, "|<empty {}x{}>|", rows, cols);
+    }
+    if rows == 0 || cols == 0 || data.len() != rows || data.iter().any(|row| row.len() != cols) {
+        return write!(f, "|<inconsistent {}x{}>|", rows, cols);
+    }
+
+    let visible_rows = visible(rows, options);
+    let visible_cols = visible(cols, options);
+    for (n, i) in visible_rows.iter().enumerate() {
+        if n > 0 {
+            writeln!(f)?;
+        }
+        let Some(i) = *i else {
+            write!(f, "...")?;
+            continue;
+        };
+
+        write!(f, "|")?;
+        for (m, j) in visible_cols.iter().enumerate() {
+            if m > 0 {
+                write!(f, " ")?;
+            }
+            match j {
+                Some(j) => write!(f, "{}", data[i][*j])?,
+                None => write!(f, "...")?,
+            }
+        }
+        write!(f, "|")?;
+    }
+
+    if visible_rows.contains(&None) || visible_cols.contains(&None) {
+        write!(f, "\n[{}x{} matrix]", rows, cols)?;
+    }
+    Ok(())
+}
@@ -1,23 +1,105 @@
 use std::error::Error as StdErr;
 use std::fmt::{Display, Result, Formatter};
+use std::path::PathBuf;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq)]
 pub enum MatrixError {
     InvalidMatrixSize,
     DimensionMismatch,
-    InvalidOperation,
+    InvalidOperation(&'static str),
     DataMismatch,
+    NotSquare { rows: usize, cols: usize },
+    DidNotConverge { iterations: usize, residual: f64 },
+    IndexOutOfBounds { index: usize, bound: usize },
+    AllocationFailed { rows: usize, cols: usize },
+    ShapeMismatch { operation: &'static str, left: (usize, usize), right: (usize, usize) },
+    NonFiniteValue { row: usize, col: usize, value: f64 },
+    Overflow { operation: &'static str },
+    Singular { operation: &'static str },
+    ShapeMismatchAt { operation: &'static str, index: usize, expected: (usize, usize), found: (usize, usize) },
+    Breakdown { operation: &'static str, index: usize },
+    Io { path: PathBuf, kind: std::io::ErrorKind },
+    /// `text` is the whole offending line and `token` the part of it at byte `offset` that could not be parsed,
+    /// empty when a value is missing at the end of the line.
+    ParseError { path: PathBuf, line: usize, reason: &'static str, text: String, offset: usize, token: String },
+    UnsupportedFormat { format: &'static str, reason: String },
+    SelectorOutOfBounds { axis: &'static str, selector: String, bound: usize },
+    BlockOverflow { operation: &'static str, rows: usize, cols: usize },
+    NotInteger { row: usize, col: usize, value: f64 },
+    NotPositiveDefinite { operation: &'static str },
+    OutsideBand { row: usize, col: usize, lower: usize, upper: usize },
+}
+
+/// Broad category of a `MatrixError`, see `MatrixError::kind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixErrorKind {
+    /// Invalid or incompatible dimensions and indices.
+    Shape,
+    /// Numerical failures and invalid arguments: singular matrices, non-convergence, overflow, NaN...
+    Numeric,
+    /// Malformed input text.
+    Parse,
+    /// Failures of the system rather than of the input, like running out of memory.
+    Io,
 }
 
 impl StdErr for MatrixError {}
 
+impl From<MatrixError> for std::io::Error {
+    /// Wraps the error, with `InvalidInput` for shape errors, `InvalidData` for numeric and parse errors,
+    /// `OutOfMemory` for allocation failures and the original kind for `Io`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, add::Add};
+    /// fn run() -> std::io::Result<Matrix> {
+    ///     Ok(matrix![1.0, 2.0].add(matrix![1.0; 2.0])?)
+    /// }
+    ///
+    /// fn main() {
+    ///     let error = run().unwrap_err();
+    ///     assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    ///     assert_eq!(error.to_string(), "DimensionMismatch: Matrix dimensions do not match");
+    /// }
+    /// ```
+    fn from(error: MatrixError) -> Self {
+        let kind = match &error {
+            MatrixError::Io { kind, .. } => *kind,
+            _ => match error.kind() {
+                MatrixErrorKind::Shape => std::io::ErrorKind::InvalidInput,
+                MatrixErrorKind::Numeric | MatrixErrorKind::Parse => std::io::ErrorKind::InvalidData,
+                MatrixErrorKind::Io => std::io::ErrorKind::OutOfMemory,
+            },
+        };
+        std::io::Error::new(kind, error)
+    }
+}
+
 impl Display for MatrixError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
-            MatrixError::InvalidMatrixSize => write!(f, "InvalidMatrixSize: Invalid matrix size, rows and columns must be greater than zero"),
+            MatrixError::InvalidMatrixSize => write!(f, "InvalidMatrixSize: Invalid matrix size, rows and columns must be greater than zero and their product must fit in memory"),
             MatrixError::DimensionMismatch => write!(f, "DimensionMismatch: Matrix dimensions do not match"),
-            MatrixError::InvalidOperation => write!(f, "InvalidOperation: Invalid operation on matrices"),
+            MatrixError::InvalidOperation(reason) => write!(f, "InvalidOperation: {}", reason),
             MatrixError::DataMismatch => write!(f, "DataMismatch: Data must have the same dimensions as the matrix"),
+            MatrixError::NotSquare { rows, cols } => write!(f, "NotSquare: Operation requires a square matrix, got {}x{}", rows, cols),
+            MatrixError::DidNotConverge { iterations, residual } => write!(f, "DidNotConverge: No convergence after {} iterations (residual {})", iterations, residual),
+            MatrixError::IndexOutOfBounds { index, bound } => write!(f, "IndexOutOfBounds: Index {} is out of bounds for length {}", index, bound),
+            MatrixError::AllocationFailed { rows, cols } => write!(f, "AllocationFailed: Could not allocate a {}x{} matrix", rows, cols),
+            MatrixError::ShapeMismatch { operation, left, right } => write!(f, "ShapeMismatch: `{}` got incompatible shapes {}x{} and {}x{}", operation, left.0, left.1, right.0, right.1),
+            MatrixError::NonFiniteValue { row, col, value } => write!(f, "NonFiniteValue: Element ({}, {}) is {}", row, col, value),
+            MatrixError::Overflow { operation } => write!(f, "Overflow: Integer arithmetic overflowed in `{}`", operation),
+            MatrixError::Singular { operation } => write!(f, "Singular: `{}` requires a non-singular matrix", operation),
+            MatrixError::ShapeMismatchAt { operation, index, expected, found } => write!(f, "ShapeMismatchAt: `{}` expected a {}x{} matrix at index {}, got {}x{}", operation, expected.0, expected.1, index, found.0, found.1),
+            MatrixError::Breakdown { operation, index } => write!(f, "Breakdown: `{}` hit a non-positive pivot at index {}", operation, index),
+            MatrixError::Io { path, kind } => write!(f, "Io: Could not access {}: {}", path.display(), kind),
+            MatrixError::ParseError { path, line, reason, .. } if path.as_os_str().is_empty() => write!(f, "ParseError: line {}: {}", line, reason),
+            MatrixError::ParseError { path, line, reason, .. } => write!(f, "ParseError: {}:{}: {}", path.display(), line, reason),
+            MatrixError::SelectorOutOfBounds { axis, selector, bound } => write!(f, "SelectorOutOfBounds: {} selector {} is out of bounds for length {}", axis, selector, bound),
+            MatrixError::BlockOverflow { operation, rows, cols } => write!(f, "BlockOverflow: `{}` block overflows the matrix by {} rows and {} columns", operation, rows, cols),
+            MatrixError::UnsupportedFormat { format, reason } => write!(f, "UnsupportedFormat: Cannot read {} data: {}", format, reason),
+            MatrixError::NotInteger { row, col, value } => write!(f, "NotInteger: Element ({}, {}) is {}, not an integer", row, col, value),
+            MatrixError::NotPositiveDefinite { operation } => write!(f, "NotPositiveDefinite: `{}` requires a symmetric positive-definite matrix", operation),
+            MatrixError::OutsideBand { row, col, lower, upper } => write!(f, "OutsideBand: Element ({}, {}) is outside the band of lower bandwidth {} and upper bandwidth {}", row, col, lower, upper),
         }
     }
     
@@ -27,4 +109,134 @@ impl MatrixError {
     pub fn new(error: MatrixError) -> Self {
         error
     }
+
+    /// Returns the category of the error, for callers that handle errors by group (exit codes, for example).
+    /// # Example
+    /// ```
+    /// # use matrix::{MatrixError::*, MatrixErrorKind};
+    /// fn main() {
+    ///     let shape = [
+    ///         InvalidMatrixSize,
+    ///         DimensionMismatch,
+    ///         DataMismatch,
+    ///         NotSquare { rows: 2, cols: 3 },
+    ///         IndexOutOfBounds { index: 3, bound: 3 },
+    ///         ShapeMismatch { operation: "zip_with", left: (1, 2), right: (2, 1) },
+    ///         ShapeMismatchAt { operation: "sum_all", index: 1, expected: (1, 2), found: (2, 1) },
+    ///         SelectorOutOfBounds { axis: "row", selector: "Idx(3)".into(), bound: 3 },
+    ///         BlockOverflow { operation: "set_submatrix", rows: 1, cols: 0 },
+    ///         OutsideBand { row: 3, col: 0, lower: 1, upper: 1 },
+    ///     ];
+    ///     let numeric = [
+    ///         InvalidOperation("reason"),
+    ///         DidNotConverge { iterations: 10, residual: 0.5 },
+    ///         NonFiniteValue { row: 0, col: 0, value: f64::NAN },
+    ///         Overflow { operation: "mul" },
+    ///         Singular { operation: "solve" },
+    ///         Breakdown { operation: "incomplete_cholesky", index: 2 },
+    ///         NotInteger { row: 0, col: 1, value: 0.5 },
+    ///         NotPositiveDefinite { operation: "sqrt_spd" },
+    ///     ];
+    ///
+    ///     assert!(shape.iter().all(|e| e.kind() == MatrixErrorKind::Shape));
+    ///     assert!(numeric.iter().all(|e| e.kind() == MatrixErrorKind::Numeric));
+    ///     assert_eq!(AllocationFailed { rows: 1, cols: 1 }.kind(), MatrixErrorKind::Io);
+    ///     assert_eq!(Io { path: "a.txt".into(), kind: std::io::ErrorKind::NotFound }.kind(), MatrixErrorKind::Io);
+    ///     let parse = ParseError { path: "a.txt".into(), line: 1, reason: "invalid number", text: "x".into(), offset: 0, token: "x".into() };
+    ///     assert_eq!(parse.kind(), MatrixErrorKind::Parse);
+    ///     assert_eq!(UnsupportedFormat { format: "npy", reason: "dtype '<i8'".into() }.kind(), MatrixErrorKind::Parse);
+    /// }
+    /// ```
+    pub fn kind(&self) -> MatrixErrorKind {
+        match self {
+            MatrixError::InvalidMatrixSize
+            | MatrixError::DimensionMismatch
+            | MatrixError::DataMismatch
+            | MatrixError::NotSquare { .. }
+            | MatrixError::IndexOutOfBounds { .. }
+            | MatrixError::ShapeMismatch { .. }
+            | MatrixError::ShapeMismatchAt { .. }
+            | MatrixError::SelectorOutOfBounds { .. }
+            | MatrixError::BlockOverflow { .. }
+            | MatrixError::OutsideBand { .. } => MatrixErrorKind::Shape,
+            MatrixError::InvalidOperation(_)
+            | MatrixError::DidNotConverge { .. }
+            | MatrixError::NonFiniteValue { .. }
+            | MatrixError::Overflow { .. }
+            | MatrixError::Singular { .. }
+            | MatrixError::Breakdown { .. }
+            | MatrixError::NotInteger { .. }
+            | MatrixError::NotPositiveDefinite { .. } => MatrixErrorKind::Numeric,
+            MatrixError::ParseError { .. } | MatrixError::UnsupportedFormat { .. } => MatrixErrorKind::Parse,
+            MatrixError::AllocationFailed { .. } | MatrixError::Io { .. } => MatrixErrorKind::Io,
+        }
+    }
+
+    /// Renders the error over several lines, echoing the offending line of a `ParseError` with a caret under the
+    /// token, like the compiler does. Every other error is rendered like `Display`, which stays on one line.
+    ///
+    /// Tabs in the echoed line are shown as single spaces so that the caret lines up.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, add::Add};
+    /// fn main() {
+    ///     let error = "2 3\n1 2 3\n4 5.5.0 6".parse::<Matrix>().unwrap_err();
+    ///     assert_eq!(error.render_pretty(), [
+    ///         "ParseError: line 3: invalid number",
+    ///         "  |",
+    ///         "3 | 4 5.5.0 6",
+    ///         "  |   ^^^^^",
+    ///     ].join("\n"));
+    ///
+    ///     // A row with one value too many: the caret points at the first extra one.
+    ///     let error = "2 2\n1 2\n3 4 5".parse::<Matrix>().unwrap_err();
+    ///     assert_eq!(error.render_pretty(), [
+    ///         "ParseError: line 3: expected rows * cols values",
+    ///         "  |",
+    ///         "3 | 3 4 5",
+    ///         "  |     ^",
+    ///     ].join("\n"));
+    ///
+    ///     // One value short: the caret is past the end of the last line.
+    ///     let error = "2 2\n1 2\n3".parse::<Matrix>().unwrap_err();
+    ///     assert!(error.render_pretty().ends_with("3 | 3\n  |  ^"));
+    ///
+    ///     let error = matrix![1.0].add(matrix![1.0, 2.0]).unwrap_err();
+    ///     assert_eq!(error.render_pretty(), error.to_string());
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     // Wide line numbers widen the gutter, and the caret counts characters rather than bytes.
+    ///     let text = format!("12 1\n{}é\tx", "1\n".repeat(10));
+    ///     let error = text.parse::<Matrix>().unwrap_err();
+    ///     assert_eq!(error.render_pretty(), [
+    ///         "ParseError: line 12: invalid number",
+    ///         "   |",
+    ///         "12 | é x",
+    ///         "   | ^",
+    ///     ].join("\n"));
+    /// }
+    /// ```
+    pub fn render_pretty(&self) -> String {
+        let MatrixError::ParseError { line, text, offset, token, .. } = self else {
+            return self.to_string();
+        };
+
+        let number = line.to_string();
+        let gutter = " ".repeat(number.len());
+        let column = text.get(..*offset).unwrap_or(text).chars().count();
+        let width = token.chars().count().max(1);
+        format!(
+            "{}\n{} |\n{} | {}\n{} | {}{}",
+            self,
+            gutter,
+            number,
+            text.replace('\t', " "),
+            gutter,
+            " ".repeat(column),
+            "^".repeat(width)
+        )
+    }
 }
\ No newline at end of file
@@ -0,0 +1,251 @@
+//! C ABI for embedding the crate in other languages, enabled by the `ffi` feature. The matching header is
+//! `include/matrix.h`.
+//!
+//! Matrices are opaque `Matrix *` handles created by `matrix_new`, `matrix_add` and `matrix_mul` and released
+//! with `matrix_free`. Every other function returns a `MatrixStatus` and writes its result through an
+//! out-parameter, which is left untouched on failure. Null pointers and lengths that don't match the shape are
+//! reported as status codes, and a panic never crosses the boundary.
+//! # Example
+//! ```
+//! # use matrix::ffi::*;
+//! # use std::ptr;
+//! fn main() {
+//!     let data = [1.0, 2.0, 3.0, 4.0];
+//!     let mut a = ptr::null_mut();
+//!     unsafe {
+//!         assert_eq!(matrix_new(2, 2, data.as_ptr(), data.len(), &mut a), MatrixStatus::Ok);
+//!
+//!         let mut sum = ptr::null_mut();
+//!         assert_eq!(matrix_add(a, a, &mut sum), MatrixStatus::Ok);
+//!         let mut product = ptr::null_mut();
+//!         assert_eq!(matrix_mul(a, sum, &mut product), MatrixStatus::Ok);
+//!
+//!         let mut value = 0.0;
+//!         assert_eq!(matrix_get(product, 1, 0, &mut value), MatrixStatus::Ok);
+//!         assert_eq!(value, 30.0);
+//!         let (mut rows, mut cols) = (0, 0);
+//!         assert_eq!(matrix_shape(product, &mut rows, &mut cols), MatrixStatus::Ok);
+//!         assert_eq!((rows, cols), (2, 2));
+//!
+//!         let mut det = 0.0;
+//!         assert_eq!(matrix_det(a, &mut det), MatrixStatus::Ok);
+//!         assert!((det + 2.0).abs() < 1e-12);
+//!
+//!         matrix_free(product);
+//!         matrix_free(sum);
+//!         matrix_free(a);
+//!     }
+//! }
+//! ```
+//! ```
+//! # use matrix::ffi::*;
+//! # use std::ptr;
+//! fn main() {
+//!     let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+//!     unsafe {
+//!         let mut m = ptr::null_mut();
+//!         assert_eq!(matrix_new(2, 2, data.as_ptr(), data.len(), &mut m), MatrixStatus::LengthMismatch);
+//!         assert_eq!(matrix_new(2, 3, ptr::null(), 6, &mut m), MatrixStatus::NullPointer);
+//!         assert_eq!(matrix_new(0, 3, data.as_ptr(), 0, &mut m), MatrixStatus::InvalidMatrixSize);
+//!         assert_eq!(matrix_new(2, 3, data.as_ptr(), 6, ptr::null_mut()), MatrixStatus::NullPointer);
+//!         assert!(m.is_null());
+//!
+//!         assert_eq!(matrix_new(2, 3, data.as_ptr(), 6, &mut m), MatrixStatus::Ok);
+//!         let mut out = ptr::null_mut();
+//!         assert_eq!(matrix_add(m, ptr::null(), &mut out), MatrixStatus::NullPointer);
+//!         assert_eq!(matrix_mul(m, m, &mut out), MatrixStatus::DimensionMismatch);
+//!         assert!(out.is_null());
+//!
+//!         let mut value = -1.0;
+//!         assert_eq!(matrix_get(m, 2, 0, &mut value), MatrixStatus::IndexOutOfBounds);
+//!         assert_eq!(matrix_det(m, &mut value), MatrixStatus::NotSquare);
+//!         assert_eq!(value, -1.0);
+//!
+//!         matrix_free(m);
+//!         matrix_free(ptr::null_mut());
+//!     }
+//! }
+//! ```
+use crate::errors::MatrixError;
+use crate::matrix::Matrix;
+use crate::operations::add::Add;
+use crate::operations::mul::Mul;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Result code of every `matrix_*` function, mirrored by `MatrixStatus` in `include/matrix.h`.
+///
+/// The specific `MatrixError` variants that these functions can return have their own code, any other error
+/// is reported by its `MatrixErrorKind`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixStatus {
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// `len` is not `rows * cols`.
+    LengthMismatch = 2,
+    InvalidMatrixSize = 3,
+    DimensionMismatch = 4,
+    NotSquare = 5,
+    IndexOutOfBounds = 6,
+    Singular = 7,
+    /// Any other shape error.
+    ShapeError = 8,
+    /// Any other numeric error.
+    NumericError = 9,
+    /// Allocation failures, I/O and parse errors.
+    OtherError = 10,
+    /// The call panicked; no output was written.
+    Panic = 11,
+}
+
+impl From<MatrixError> for MatrixStatus {
+    fn from(error: MatrixError) -> Self {
+        match error {
+            MatrixError::InvalidMatrixSize => MatrixStatus::InvalidMatrixSize,
+            MatrixError::DimensionMismatch => MatrixStatus::DimensionMismatch,
+            MatrixError::DataMismatch => MatrixStatus::LengthMismatch,
+            MatrixError::NotSquare { .. } => MatrixStatus::NotSquare,
+            MatrixError::IndexOutOfBounds { .. } => MatrixStatus::IndexOutOfBounds,
+            MatrixError::Singular { .. } => MatrixStatus::Singular,
+            other => match other.kind() {
+                crate::errors::MatrixErrorKind::Shape => MatrixStatus::ShapeError,
+                crate::errors::MatrixErrorKind::Numeric => MatrixStatus::NumericError,
+                _ => MatrixStatus::OtherError,
+            },
+        }
+    }
+}
+
+/// Runs `f`, turning errors into their status and panics into `Panic`.
+fn guard(f: impl FnOnce() -> Result<(), MatrixStatus>) -> MatrixStatus {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => MatrixStatus::Ok,
+        Ok(Err(status)) => status,
+        Err(_) => MatrixStatus::Panic,
+    }
+}
+
+/// Dereferences a handle, or returns `NullPointer`.
+///
+/// # Safety
+/// `matrix` must be null or a live handle from this module.
+unsafe fn handle<'a>(matrix: *const Matrix) -> Result<&'a Matrix, MatrixStatus> {
+    unsafe { matrix.as_ref() }.ok_or(MatrixStatus::NullPointer)
+}
+
+/// Writes `value` through `out`, or returns `NullPointer`.
+///
+/// # Safety
+/// `out` must be null or valid for writes.
+unsafe fn write<T>(out: *mut T, value: T) -> Result<(), MatrixStatus> {
+    if out.is_null() {
+        return Err(MatrixStatus::NullPointer);
+    }
+    unsafe { out.write(value) };
+    Ok(())
+}
+
+/// Creates a `rows x cols` matrix from `len` values in row-major order and stores its handle in `*out`.
+///
+/// # Safety
+/// `data` must point to `len` readable `f64` values, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_new(rows: usize, cols: usize, data: *const f64, len: usize, out: *mut *mut Matrix) -> MatrixStatus {
+    guard(|| {
+        if data.is_null() || out.is_null() {
+            return Err(MatrixStatus::NullPointer);
+        }
+        if rows.checked_mul(cols) != Some(len) {
+            return Err(if rows == 0 || cols == 0 { MatrixStatus::InvalidMatrixSize } else { MatrixStatus::LengthMismatch });
+        }
+        let values = unsafe { std::slice::from_raw_parts(data, len) }.to_vec();
+        let matrix = Matrix::from_flat(rows, cols, values)?;
+        unsafe { write(out, Box::into_raw(Box::new(matrix))) }
+    })
+}
+
+/// Releases a handle. Null is accepted and ignored.
+///
+/// # Safety
+/// `matrix` must be null or a live handle, which must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_free(matrix: *mut Matrix) {
+    if !matrix.is_null() {
+        drop(unsafe { Box::from_raw(matrix) });
+    }
+}
+
+/// Stores the number of rows and columns in `*rows` and `*cols`.
+///
+/// # Safety
+/// `matrix` must be null or a live handle, `rows` and `cols` null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_shape(matrix: *const Matrix, rows: *mut usize, cols: *mut usize) -> MatrixStatus {
+    guard(|| {
+        let matrix = unsafe { handle(matrix) }?;
+        if rows.is_null() || cols.is_null() {
+            return Err(MatrixStatus::NullPointer);
+        }
+        unsafe { write(rows, matrix.rows)? };
+        unsafe { write(cols, matrix.cols) }
+    })
+}
+
+/// Stores `a + b` as a new handle in `*out`.
+///
+/// # Safety
+/// `a` and `b` must be null or live handles, `out` null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_add(a: *const Matrix, b: *const Matrix, out: *mut *mut Matrix) -> MatrixStatus {
+    guard(|| {
+        let (a, b) = unsafe { (handle(a)?, handle(b)?) };
+        if out.is_null() {
+            return Err(MatrixStatus::NullPointer);
+        }
+        let sum = a.add(b.clone())?;
+        unsafe { write(out, Box::into_raw(Box::new(sum))) }
+    })
+}
+
+/// Stores `a * b` as a new handle in `*out`.
+///
+/// # Safety
+/// `a` and `b` must be null or live handles, `out` null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_mul(a: *const Matrix, b: *const Matrix, out: *mut *mut Matrix) -> MatrixStatus {
+    guard(|| {
+        let (a, b) = unsafe { (handle(a)?, handle(b)?) };
+        if out.is_null() {
+            return Err(MatrixStatus::NullPointer);
+        }
+        let product = a.mul(b)?;
+        unsafe { write(out, Box::into_raw(Box::new(product))) }
+    })
+}
+
+/// Stores the element at (`row`, `col`) in `*out`.
+///
+/// # Safety
+/// `matrix` must be null or a live handle, `out` null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_get(matrix: *const Matrix, row: usize, col: usize, out: *mut f64) -> MatrixStatus {
+    guard(|| {
+        let matrix = unsafe { handle(matrix) }?;
+        let value = matrix.get(row, col).ok_or(MatrixStatus::IndexOutOfBounds)?;
+        unsafe { write(out, value) }
+    })
+}
+
+/// Stores the determinant in `*out`.
+///
+/// # Safety
+/// `matrix` must be null or a live handle, `out` null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_det(matrix: *const Matrix, out: *mut f64) -> MatrixStatus {
+    guard(|| {
+        let matrix = unsafe { handle(matrix) }?;
+        let det = matrix.determinant()?;
+        unsafe { write(out, det) }
+    })
+}
@@ -0,0 +1,226 @@
+use crate::matrix::{dim_checked_mul, Matrix};
+use crate::errors::MatrixError;
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+impl Matrix {
+    /// Serializes the matrix in the text format of `save`: a `rows cols` line, then one line of space-separated
+    /// values per row.
+    ///
+    /// Values are written like `{:?}` does, in the shortest form that reads back to the same `f64`, with an exponent
+    /// for very large or small magnitudes, and non-finite values are written `nan`, `inf` and `-inf`.
+    /// Parsing the result with `from_str` gives back a bitwise identical matrix, `-0.0` and subnormals included;
+    /// only the payload of a `NaN` is not kept.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![0.1 + 0.2, -0.0, f64::NAN; 1e-310, f64::NEG_INFINITY, 1e21];
+    ///
+    ///     let text = a.to_exact_string();
+    ///     assert_eq!(text, "2 3\n0.30000000000000004 -0.0 nan\n1e-310 -inf 1e21\n");
+    ///
+    ///     let b: Matrix = text.parse().unwrap();
+    ///     assert_eq!(b.data[0][0], 0.1 + 0.2);
+    ///     assert!(b.data[0][1] == 0.0 && b.data[0][1].is_sign_negative());
+    ///     assert!(b.data[0][2].is_nan());
+    ///     assert_eq!(b.data[1], a.data[1]);
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::Matrix;
+    /// # use std::str::FromStr;
+    /// fn main() {
+    ///     // Arbitrary bit patterns: every finite value comes back with the same bits.
+    ///     let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    ///     let mut next = || {
+    ///         state ^= state << 13;
+    ///         state ^= state >> 7;
+    ///         state ^= state << 17;
+    ///         state
+    ///     };
+    ///     for _ in 0..50 {
+    ///         let (rows, cols) = (1 + next() as usize % 6, 1 + next() as usize % 6);
+    ///         let values = (0..rows * cols).map(|_| f64::from_bits(next())).collect();
+    ///         let a = Matrix::from_flat(rows, cols, values).unwrap();
+    ///
+    ///         let b = Matrix::from_str(&a.to_exact_string()).unwrap();
+    ///         for (x, y) in a.data.iter().flatten().zip(b.data.iter().flatten()) {
+    ///             assert!(x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan()));
+    ///         }
+    ///     }
+    ///
+    ///     for seed in 0..20 {
+    ///         let a = Matrix::from_seed(7, 5, seed).unwrap().scale(1e6);
+    ///         assert_eq!(Matrix::from_str(&a.to_exact_string()).unwrap(), a);
+    ///     }
+    /// }
+    /// ```
+    pub fn to_exact_string(&self) -> String {
+        let mut text = format!("{} {}\n", self.rows, self.cols);
+        for row in &self.data {
+            let line: Vec<String> = row.iter().map(|&x| exact_token(x)).collect();
+            let _ = writeln!(text, "{}", line.join(" "));
+        }
+        text
+    }
+
+    /// Writes the matrix to a text file, in the format of `to_exact_string`.
+    ///
+    /// Every value reads back to the same `f64`, so `load` restores the matrix exactly, which makes the files
+    /// usable as golden files in tests. Failures return `Io` with the path.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let path = std::env::temp_dir().join(format!("matrix-save-{}.txt", std::process::id()));
+    ///     let a = matrix![0.1 + 0.2, -1.5, f64::INFINITY; 1e-300, 0.0, 1e21];
+    ///
+    ///     a.save(&path).unwrap();
+    ///     assert_eq!(
+    ///         std::fs::read_to_string(&path).unwrap(),
+    ///         "2 3\n0.30000000000000004 -1.5 inf\n1e-300 0.0 1e21\n"
+    ///     );
+    ///     assert_eq!(Matrix::load(&path).unwrap(), a);
+    ///     std::fs::remove_file(&path).unwrap();
+    /// }
+    /// ```
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), MatrixError> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_exact_string()).map_err(|error| MatrixError::Io { path: path.to_path_buf(), kind: error.kind() })
+    }
+
+    /// Reads a matrix written by `save`.
+    ///
+    /// After the `rows cols` line, values can be laid out with any whitespace, but there must be exactly
+    /// `rows * cols` of them. Malformed content returns `ParseError` with the path, the 1-based line number and
+    /// the offending token, see `MatrixError::render_pretty`. Zero dimensions return `InvalidMatrixSize` and a
+    /// file that can't be read returns `Io`.
+    /// # Example
+    /// ```
+    /// # use matrix::{Matrix, MatrixError};
+    /// fn main() {
+    ///     let path = std::env::temp_dir().join(format!("matrix-load-{}.txt", std::process::id()));
+    ///
+    ///     std::fs::write(&path, "2 2\n1 2\n3\n").unwrap();
+    ///     assert!(matches!(
+    ///         Matrix::load(&path),
+    ///         Err(MatrixError::ParseError { line: 3, reason: "expected rows * cols values", .. })
+    ///     ));
+    ///
+    ///     std::fs::write(&path, "2 1\n1\nx\n").unwrap();
+    ///     let error = Matrix::load(&path).unwrap_err();
+    ///     assert_eq!(error, MatrixError::ParseError {
+    ///         path: path.clone(),
+    ///         line: 3,
+    ///         reason: "invalid number",
+    ///         text: "x".into(),
+    ///         offset: 0,
+    ///         token: "x".into(),
+    ///     });
+    ///     assert!(error.to_string().ends_with(":3: invalid number"));
+    ///
+    ///     std::fs::write(&path, "2\n1 2\n").unwrap();
+    ///     assert!(matches!(Matrix::load(&path), Err(MatrixError::ParseError { line: 1, .. })));
+    ///
+    ///     std::fs::remove_file(&path).unwrap();
+    ///     assert!(matches!(Matrix::load(&path), Err(MatrixError::Io { kind: std::io::ErrorKind::NotFound, .. })));
+    /// }
+    /// ```
+    pub fn load(path: impl AsRef<Path>) -> Result<Matrix, MatrixError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|error| MatrixError::Io { path: path.to_path_buf(), kind: error.kind() })?;
+        parse_text(&text, path)
+    }
+}
+
+/// Parses the text format of `to_exact_string`, with the same rules and errors as `Matrix::load`.
+///
+/// `ParseError`s have an empty path, which their message leaves out.
+/// # Example
+/// ```
+/// # use matrix::{Matrix, MatrixError};
+/// fn main() {
+///     let a: Matrix = "2 2\n1 -inf\n  nan 4e-3".parse().unwrap();
+///     assert_eq!((a.data[0][1], a.data[1][1]), (f64::NEG_INFINITY, 0.004));
+///     assert!(a.data[1][0].is_nan());
+///
+///     let error = "1 2\n1 two".parse::<Matrix>().unwrap_err();
+///     assert!(matches!(error, MatrixError::ParseError { line: 2, offset: 2, reason: "invalid number", .. }));
+///     assert_eq!(error.to_string(), "ParseError: line 2: invalid number");
+///     assert_eq!("0 2\n".parse::<Matrix>(), Err(MatrixError::InvalidMatrixSize));
+///
+///     // A huge header with a few values is a parse error, nothing that large is allocated.
+///     let error = "1000000 1000000\n1 2 3".parse::<Matrix>().unwrap_err();
+///     assert!(matches!(error, MatrixError::ParseError { line: 2, reason: "expected rows * cols values", .. }));
+/// }
+/// ```
+impl FromStr for Matrix {
+    type Err = MatrixError;
+
+    fn from_str(text: &str) -> Result<Matrix, MatrixError> {
+        parse_text(text, Path::new(""))
+    }
+}
+
+/// Formats `x` so that `f64::from_str` reads it back exactly, with lower-case tokens for the non-finite values.
+fn exact_token(x: f64) -> String {
+    if x.is_nan() {
+        "nan".to_string()
+    } else {
+        format!("{:?}", x)
+    }
+}
+
+fn parse_text(text: &str, path: &Path) -> Result<Matrix, MatrixError> {
+    let parse_error = |line: usize, text: &str, offset: usize, token: &str, reason| MatrixError::ParseError {
+        path: path.to_path_buf(),
+        line,
+        reason,
+        text: text.to_string(),
+        offset,
+        token: token.to_string(),
+    };
+
+    let mut lines = text.lines().enumerate().map(|(i, line)| (i + 1, line));
+    let (_, header_line) = lines.next().unwrap_or((1, ""));
+    let header: Vec<usize> = header_line
+        .split_whitespace()
+        .map(|x| x.parse().ok())
+        .collect::<Option<_>>()
+        .filter(|header: &Vec<usize>| header.len() == 2)
+        .ok_or_else(|| {
+            let token = header_line.trim();
+            let offset = tokens(header_line).next().map_or(0, |(offset, _)| offset);
+            parse_error(1, header_line, offset, token, "expected a `rows cols` header")
+        })?;
+    let (rows, cols) = (header[0], header[1]);
+    if rows == 0 || cols == 0 {
+        return Err(MatrixError::InvalidMatrixSize);
+    }
+    let len = dim_checked_mul(rows, cols)?;
+
+    // The header is untrusted: every value takes at least one byte and a separator, so never reserve more.
+    let mut values = Vec::with_capacity(len.min(text.len() / 2 + 1));
+    let (mut last_number, mut last_line) = (1, header_line);
+    for (number, line) in lines {
+        for (offset, token) in tokens(line) {
+            if values.len() == len {
+                return Err(parse_error(number, line, offset, token, "expected rows * cols values"));
+            }
+            values.push(token.parse::<f64>().map_err(|_| parse_error(number, line, offset, token, "invalid number"))?);
+        }
+        (last_number, last_line) = (number, line);
+    }
+    if values.len() != len {
+        return Err(parse_error(last_number, last_line, last_line.len(), "", "expected rows * cols values"));
+    }
+
+    Matrix::from_flat(rows, cols, values)
+}
+
+/// The whitespace-separated tokens of `line`, with their byte offsets.
+fn tokens(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace().map(move |token| (token.as_ptr() as usize - line.as_ptr() as usize, token))
+}
@@ -0,0 +1,109 @@
+use crate::matrix::Matrix;
+use std::hash::{Hash, Hasher};
+
+/// Wrapper around `Matrix` that implements `Hash` and `Eq` by comparing the raw bit patterns of the elements.
+///
+/// Since `f64` is not `Hash`, this is an explicit opt-in:
+/// - `NaN` is equal to itself when the bit patterns match (unlike `==` on `f64`).
+/// - `0.0` and `-0.0` are different keys, because their bits are different.
+/// # Example
+/// ```
+/// # use matrix::{HashableMatrix, Matrix};
+/// use std::collections::HashMap;
+///
+/// fn main() {
+///     let a = Matrix::builder().rows(1).cols(2).data(vec![vec![1.0, 2.0]]).done().unwrap();
+///     let b = Matrix::builder().rows(1).cols(2).data(vec![vec![1.0, 2.0]]).done().unwrap();
+///
+///     let mut cache = HashMap::new();
+///     cache.insert(HashableMatrix(a), "cached");
+///
+///     assert_eq!(cache.get(&HashableMatrix(b)), Some(&"cached"));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HashableMatrix(pub Matrix);
+
+impl HashableMatrix {
+    /// Returns the wrapped matrix.
+    pub fn into_inner(self) -> Matrix {
+        self.0
+    }
+}
+
+impl From<Matrix> for HashableMatrix {
+    fn from(matrix: Matrix) -> Self {
+        Self(matrix)
+    }
+}
+
+impl PartialEq for HashableMatrix {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.rows == other.0.rows
+            && self.0.cols == other.0.cols
+            && self.0.data.len() == other.0.data.len()
+            && self.0.data.iter().zip(other.0.data.iter()).all(|(a, b)| {
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| x.to_bits() == y.to_bits())
+            })
+    }
+}
+
+impl Eq for HashableMatrix {}
+
+impl Hash for HashableMatrix {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.rows.hash(state);
+        self.0.cols.hash(state);
+        for row in &self.0.data {
+            for value in row {
+                value.to_bits().hash(state);
+            }
+        }
+    }
+}
+
+/// 64-bit FNV-1a, used by `bit_hash` so the result doesn't depend on the std hasher implementation.
+struct Fnv1a(u64);
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+}
+
+impl Matrix {
+    /// Hashes the dimensions and the raw bit patterns of every element.
+    ///
+    /// The result is deterministic across runs, so it can be stored or compared between processes.
+    /// The same caveats as `HashableMatrix` apply: `NaN` payloads and `-0.0` vs `0.0` hash differently.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let a = Matrix::builder().rows(2).cols(1).data(vec![vec![1.0], vec![2.0]]).done().unwrap();
+    ///     let b = Matrix::builder().rows(2).cols(1).data(vec![vec![1.0], vec![2.0]]).done().unwrap();
+    ///     let c = Matrix::builder().rows(1).cols(2).data(vec![vec![1.0, 2.0]]).done().unwrap();
+    ///
+    ///     assert_eq!(a.bit_hash(), b.bit_hash());
+    ///     assert_ne!(a.bit_hash(), c.bit_hash());
+    /// }
+    /// ```
+    pub fn bit_hash(&self) -> u64 {
+        let mut hasher = Fnv1a(0xcbf2_9ce4_8422_2325);
+        hasher.write(&(self.rows as u64).to_le_bytes());
+        hasher.write(&(self.cols as u64).to_le_bytes());
+        for row in &self.data {
+            for value in row {
+                hasher.write(&value.to_bits().to_le_bytes());
+            }
+        }
+        hasher.finish()
+    }
+}
@@ -0,0 +1,281 @@
+use crate::display::{fmt_rows, DisplayOptions};
+use crate::matrix::{dim_checked_mul, Matrix};
+use crate::operations::mul::Mul;
+use crate::MatrixError::{self, DataMismatch, InvalidMatrixSize};
+
+/// Matrix of `i64` elements for exact arithmetic, such as adjacency matrices and combinatorics.
+///
+/// `add`, `sub` and `mul` use checked arithmetic and return `Overflow` instead of wrapping.
+/// # Example
+/// ```
+/// # use matrix::{IMatrix, MatrixError, add::Add};
+/// fn main() {
+///     let a = IMatrix::builder()
+///         .rows(2)
+///         .cols(2)
+///         .data(vec![vec![1, 2], vec![3, 4]])
+///         .done()
+///         .unwrap();
+///
+///     assert_eq!(a.add(a.clone()).unwrap().data, vec![vec![2, 4], vec![6, 8]]);
+///     assert_eq!(format!("{}", a), "|1 2|\n|3 4|");
+///
+///     let max = IMatrix::builder().data(vec![vec![i64::MAX]]).done().unwrap();
+///     assert_eq!(max.add(max.clone()), Err(MatrixError::Overflow { operation: "add" }));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IMatrix {
+    pub rows: usize,
+    pub cols: usize,
+    pub data: Vec<Vec<i64>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuilderIMatrix {
+    rows: Option<usize>,
+    cols: Option<usize>,
+    data: Option<Vec<Vec<i64>>>,
+}
+
+impl std::fmt::Display for IMatrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_rows(f, self.rows, self.cols, &self.data, &DisplayOptions::default())
+    }
+}
+
+impl IMatrix {
+    /// Creates a builder for an `IMatrix`, see `Matrix::builder`.
+    pub fn builder() -> BuilderIMatrix {
+        BuilderIMatrix {
+            rows: Some(1),
+            cols: Some(1),
+            data: Some(Vec::new()),
+        }
+    }
+
+    /// Creates the `n x n` identity matrix.
+    pub fn identity(n: usize) -> Result<Self, MatrixError> {
+        let mut result = IMatrix::builder().rows(n).cols(n).done()?;
+        for i in 0..n {
+            result.data[i][i] = 1;
+        }
+        Ok(result)
+    }
+
+    /// Gets the element at (`row`, `col`), `None` if it is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<i64> {
+        self.data.get(row).and_then(|r| r.get(col)).copied()
+    }
+
+    /// Returns the transpose of the matrix.
+    pub fn transpose(&self) -> Self {
+        let data = (0..self.cols)
+            .map(|j| self.data.iter().map(|row| row[j]).collect())
+            .collect();
+
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            data,
+        }
+    }
+
+    /// Raises a square matrix to the `n`-th power by repeated squaring, exactly.
+    ///
+    /// Returns `NotSquare` for non-square matrices and `Overflow` if an intermediate product does not fit in an `i64`.
+    /// # Example
+    /// ```
+    /// # use matrix::{IMatrix, MatrixError, Matrix, mul::Mul};
+    /// fn main() {
+    ///     // Fibonacci numbers: [[1, 1], [1, 0]]^n = [[F(n+1), F(n)], [F(n), F(n-1)]].
+    ///     let fib = IMatrix::builder().rows(2).cols(2).data(vec![vec![1, 1], vec![1, 0]]).done().unwrap();
+    ///
+    ///     let exact = fib.pow(90).unwrap();
+    ///     assert_eq!(exact.data[0][0], 4660046610375530309);
+    ///     assert_eq!(exact.data[0][1], 2880067194370816120);
+    ///
+    ///     // The same power in f64 is rounded: F(91) is far beyond 2^53.
+    ///     let float = fib.to_f64();
+    ///     let mut power = float.clone();
+    ///     for _ in 1..90 {
+    ///         power = power.mul(&float).unwrap();
+    ///     }
+    ///     assert_ne!(power.data[0][0] as i64, exact.data[0][0]);
+    ///
+    ///     assert_eq!(fib.pow(100), Err(MatrixError::Overflow { operation: "mul" }));
+    ///     assert_eq!(fib.pow(0).unwrap(), IMatrix::identity(2).unwrap());
+    /// }
+    /// ```
+    pub fn pow(&self, mut n: u32) -> Result<IMatrix, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        let mut result = IMatrix::identity(self.rows)?;
+        let mut base = self.clone();
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.mul(base.clone())?;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.mul(base.clone())?;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Computes the determinant exactly with the Bareiss fraction-free elimination.
+    ///
+    /// Every intermediate value is a minor of the matrix, so this only returns `Overflow`
+    /// when one of those minors does not fit in an `i64`.
+    /// # Example
+    /// ```
+    /// # use matrix::IMatrix;
+    /// fn main() {
+    ///     let a = IMatrix::builder()
+    ///         .rows(3)
+    ///         .cols(3)
+    ///         .data(vec![vec![0, 2, 1], vec![3, 1, 2], vec![1, 1, 2]])
+    ///         .done()
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(a.determinant(), Ok(-6));
+    ///     assert_eq!(IMatrix::identity(5).unwrap().determinant(), Ok(1));
+    /// }
+    /// ```
+    pub fn determinant(&self) -> Result<i64, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        let overflow = || MatrixError::Overflow { operation: "determinant" };
+        let n = self.rows;
+        let mut a: Vec<Vec<i128>> = self.data.iter().map(|row| row.iter().map(|&x| x as i128).collect()).collect();
+        let mut sign = 1;
+        let mut previous = 1i128;
+
+        for k in 0..n {
+            if a[k][k] == 0 {
+                match (k + 1..n).find(|&i| a[i][k] != 0) {
+                    Some(i) => {
+                        a.swap(i, k);
+                        sign = -sign;
+                    }
+                    None => return Ok(0),
+                }
+            }
+
+            let (top, bottom) = a.split_at_mut(k + 1);
+            let pivot_row = &top[k];
+            let pivot = pivot_row[k];
+            for row in bottom.iter_mut() {
+                let factor = row[k];
+                for (value, &p) in row.iter_mut().zip(pivot_row.iter()).skip(k + 1) {
+                    let numerator = value
+                        .checked_mul(pivot)
+                        .zip(factor.checked_mul(p))
+                        .and_then(|(x, y)| x.checked_sub(y))
+                        .ok_or_else(overflow)?;
+                    *value = numerator / previous;
+                    if i64::try_from(*value).is_err() {
+                        return Err(overflow());
+                    }
+                }
+            }
+            previous = pivot;
+        }
+
+        i64::try_from(sign * a[n - 1][n - 1]).map_err(|_| overflow())
+    }
+
+    /// Converts to a `Matrix`. Values beyond 2^53 in magnitude are rounded to the nearest `f64`.
+    pub fn to_f64(&self) -> Matrix {
+        let data = self.data
+            .iter()
+            .map(|row| row.iter().map(|&x| x as f64).collect())
+            .collect();
+        Matrix { rows: self.rows, cols: self.cols, data }
+    }
+}
+
+impl Matrix {
+    /// Converts to an `IMatrix`, failing with `InvalidOperation` unless every element is an integer in the `i64` range.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, -2.0; 3.0, 4.0];
+    ///
+    ///     assert_eq!(a.to_i64().unwrap().data, vec![vec![1, -2], vec![3, 4]]);
+    ///     assert_eq!(a.to_i64().unwrap().to_f64(), a);
+    ///     assert!(matrix![0.5].to_i64().is_err());
+    ///     assert!(matrix![f64::NAN].to_i64().is_err());
+    /// }
+    /// ```
+    pub fn to_i64(&self) -> Result<IMatrix, MatrixError> {
+        // 2^63 is exactly representable, and is the first value past i64::MAX.
+        let limit = 9_223_372_036_854_775_808.0;
+        let data = self.data
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&x| {
+                        if x.fract() == 0.0 && x >= -limit && x < limit {
+                            Ok(x as i64)
+                        } else {
+                            Err(MatrixError::InvalidOperation("to_i64 requires integer values in the i64 range"))
+                        }
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(IMatrix { rows: self.rows, cols: self.cols, data })
+    }
+}
+
+impl BuilderIMatrix {
+    /// Sets the number of rows for the matrix.
+    /// - If no `data` is provided, it initializes the matrix with zeros.
+    pub fn rows(mut self, n: usize) -> Self {
+        self.rows = Some(n);
+        self
+    }
+
+    /// Sets the number of columns for the matrix.
+    /// - If no `data` is provided, it initializes the matrix with zeros.
+    pub fn cols(mut self, n: usize) -> Self {
+        self.cols = Some(n);
+        self
+    }
+
+    /// Sets the data for the matrix.
+    pub fn data(mut self, data: Vec<Vec<i64>>) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Finalizes the matrix creation process, with the same checks as `BuilderMatrix::done`.
+    pub fn done(self) -> Result<IMatrix, MatrixError> {
+        let BuilderIMatrix { rows, cols, data } = self;
+        let (rows, cols) = (rows.unwrap_or(1), cols.unwrap_or(1));
+        let data = data.unwrap_or_default();
+
+        if rows == 0 || cols == 0 {
+            return Err(InvalidMatrixSize);
+        }
+
+        if data.is_empty() {
+            dim_checked_mul(rows, cols)?;
+            let data = vec![vec![0; cols]; rows];
+            return Ok(IMatrix { rows, cols, data });
+        }
+
+        if data.len() != rows || data.iter().any(|row| row.len() != cols) {
+            return Err(DataMismatch);
+        }
+
+        Ok(IMatrix { rows, cols, data })
+    }
+}
@@ -0,0 +1,192 @@
+use crate::matrix::Matrix;
+use std::iter::FusedIterator;
+
+/// Iterator over the elements of a `Matrix` in row-major order, returned by `Matrix::iter`.
+///
+/// Row-major order is part of the contract: element `(i, j)` comes at position `i * cols + j`, on every call and
+/// every platform. Iterating from the back visits the same elements in exactly the reverse order.
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    data: &'a [Vec<f64>],
+    cols: usize,
+    front: usize,
+    back: usize,
+}
+
+/// Iterator over the elements of a `Matrix` and their `(row, col)` positions, in the order of `Iter`, returned by
+/// `Matrix::indexed_iter`.
+#[derive(Debug, Clone)]
+pub struct IndexedIter<'a> {
+    inner: Iter<'a>,
+}
+
+impl<'a> Iter<'a> {
+    fn position(&self, index: usize) -> (usize, usize) {
+        (index / self.cols, index % self.cols)
+    }
+
+    fn value(&self, index: usize) -> f64 {
+        let (row, col) = self.position(index);
+        self.data[row][col]
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.value(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<f64> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    fn next_back(&mut self) -> Option<f64> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.value(self.back))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl FusedIterator for Iter<'_> {}
+
+impl Iterator for IndexedIter<'_> {
+    type Item = ((usize, usize), f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.inner.position(self.inner.front);
+        self.inner.next().map(|value| (position, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for IndexedIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let value = self.inner.next_back()?;
+        Some((self.inner.position(self.inner.back), value))
+    }
+}
+
+impl ExactSizeIterator for IndexedIter<'_> {}
+
+impl FusedIterator for IndexedIter<'_> {}
+
+impl Matrix {
+    /// Iterates over the elements in row-major order, see `Iter` for the guarantees.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let mut a = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    ///
+    ///     assert_eq!(a.iter().collect::<Vec<_>>(), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    ///     assert_eq!(a.iter().rev().collect::<Vec<_>>(), vec![6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
+    ///     assert_eq!(a.iter().len(), 6);
+    ///     assert_eq!(a.iter().nth(4), Some(5.0));
+    ///
+    ///     // Both ends meet in the middle without repeating an element.
+    ///     let mut both = a.iter();
+    ///     assert_eq!((both.next(), both.next_back(), both.len()), (Some(1.0), Some(6.0), 4));
+    ///     assert_eq!(both.by_ref().rev().collect::<Vec<_>>(), vec![5.0, 4.0, 3.0, 2.0]);
+    ///     assert_eq!((both.next(), both.next_back(), both.len()), (None, None, 0));
+    ///
+    ///     // `zip` with another matrix of the same shape pairs the same positions.
+    ///     let b = a.scale(10.0);
+    ///     assert!(a.iter().zip(b.iter()).all(|(x, y)| y == 10.0 * x));
+    ///
+    ///     // The length follows the shape as rows come and go.
+    ///     a.retain_rows(|i, _| i == 1).unwrap();
+    ///     assert_eq!(a.iter().len(), 3);
+    ///     assert_eq!(a.iter().collect::<Vec<_>>(), vec![4.0, 5.0, 6.0]);
+    ///     (a.rows, a.data) = (3, vec![vec![4.0, 5.0, 6.0], vec![7.0, 8.0, 9.0], vec![0.0; 3]]);
+    ///     assert_eq!(a.iter().len(), 9);
+    ///     assert_eq!(a.iter().rev().nth(3), Some(9.0));
+    /// }
+    /// ```
+    pub fn iter(&self) -> Iter<'_> {
+        Iter { data: &self.data, cols: self.cols, front: 0, back: self.rows * self.cols }
+    }
+
+    /// Iterates over the elements with their `(row, col)` positions, in the row-major order of `iter`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0];
+    ///
+    ///     let positions: Vec<(usize, usize)> = a.indexed_iter().map(|(position, _)| position).collect();
+    ///     assert_eq!(positions, vec![(0, 0), (0, 1), (1, 0), (1, 1), (2, 0), (2, 1)]);
+    ///     assert_eq!(a.indexed_iter().next_back(), Some(((2, 1), 6.0)));
+    ///     assert_eq!(a.indexed_iter().rev().nth(2), Some(((1, 1), 4.0)));
+    ///     assert!(a.indexed_iter().all(|((i, j), x)| a.get(i, j) == Some(x)));
+    ///
+    ///     let mut iter = a.indexed_iter();
+    ///     iter.next();
+    ///     assert_eq!(iter.len(), 5);
+    ///     let reversed: Vec<_> = a.indexed_iter().rev().collect();
+    ///     assert_eq!(reversed, a.indexed_iter().collect::<Vec<_>>().into_iter().rev().collect::<Vec<_>>());
+    /// }
+    /// ```
+    pub fn indexed_iter(&self) -> IndexedIter<'_> {
+        IndexedIter { inner: self.iter() }
+    }
+
+    /// Iterates over the rows as slices, from the first to the last.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0];
+    ///
+    ///     assert_eq!(a.rows_iter().len(), 3);
+    ///     assert_eq!(a.rows_iter().next_back(), Some(&[5.0, 6.0][..]));
+    ///     let sums: Vec<f64> = a.rows_iter().rev().map(|row| row.iter().sum()).collect();
+    ///     assert_eq!(sums, vec![11.0, 7.0, 3.0]);
+    /// }
+    /// ```
+    pub fn rows_iter(&self) -> impl DoubleEndedIterator<Item = &[f64]> + ExactSizeIterator + FusedIterator + '_ {
+        self.data.iter().map(Vec::as_slice)
+    }
+
+    /// Returns the first element in row-major order, the top left one.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0; 3.0, 4.0];
+    ///     assert_eq!((a.first(), a.last()), (Some(1.0), Some(4.0)));
+    ///     assert_eq!((a.first(), a.last()), (a.iter().next(), a.iter().next_back()));
+    ///
+    ///     let single = matrix![7.0];
+    ///     assert_eq!((single.first(), single.last()), (Some(7.0), Some(7.0)));
+    /// }
+    /// ```
+    pub fn first(&self) -> Option<f64> {
+        self.iter().next()
+    }
+
+    /// Returns the last element in row-major order, the bottom right one, see `first`.
+    pub fn last(&self) -> Option<f64> {
+        self.iter().next_back()
+    }
+}
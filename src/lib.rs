@@ -1,7 +1,43 @@
 mod matrix;
 mod errors;
 mod operations;
+mod hash;
+mod diff;
+mod macros;
+mod view;
+mod static_matrix;
+mod matrix32;
+mod imatrix;
+mod cmatrix;
+mod display;
+mod vector;
+mod file;
+mod npy;
+mod pgm;
+mod ord;
+mod tolerance;
+mod banded_matrix;
+mod typed_builder;
+mod iter;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 
 pub use matrix::*;
 pub use errors::*;
 pub use operations::*;
+pub use hash::*;
+pub use diff::*;
+pub use view::*;
+pub use static_matrix::*;
+pub use matrix32::*;
+pub use imatrix::*;
+pub use cmatrix::*;
+pub use display::*;
+pub use vector::*;
+pub use ord::*;
+pub use tolerance::*;
+pub use banded_matrix::*;
+pub use typed_builder::*;
+pub use iter::*;
@@ -0,0 +1,59 @@
+/// Builds a `Matrix` from an inline literal, returning `Result<Matrix, MatrixError>`.
+///
+/// Rows are separated by `;` and elements by `,`. Trailing commas and semicolons are allowed.
+/// Rows of different lengths return `DataMismatch`.
+/// # Example
+/// ```
+/// # use matrix::{try_matrix, MatrixError};
+/// fn main() {
+///     let matrix = try_matrix![1.0, 2.0; 3.0, 4.0;].unwrap();
+///     assert_eq!(matrix.data, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+///
+///     assert_eq!(try_matrix![1.0, 2.0; 3.0], Err(MatrixError::DataMismatch));
+/// }
+/// ```
+#[macro_export]
+macro_rules! try_matrix {
+    ($($($value:expr),+ $(,)?);+ $(;)?) => {
+        $crate::Matrix::from_rows(vec![$(vec![$(($value) as f64),+]),+])
+    };
+}
+
+/// Builds a `Matrix` from an inline literal, panicking if the rows have different lengths.
+///
+/// Same syntax as `try_matrix!`: rows are separated by `;` and elements by `,`.
+/// # Examples
+/// ```
+/// # use matrix::matrix;
+/// fn main() {
+///     let single = matrix![7.0];
+///     assert_eq!((single.rows, single.cols), (1, 1));
+///
+///     let row = matrix![1.0, 2.0, 3.0,];
+///     assert_eq!((row.rows, row.cols), (1, 3));
+///
+///     let square = matrix![
+///         1.0, 2.0, 3.0;
+///         4.0, 5.0, 6.0;
+///         7.0, 8.0, 9.0;
+///     ];
+///     assert_eq!((square.rows, square.cols), (3, 3));
+///     assert_eq!(square.get(2, 1), Some(8.0));
+/// }
+/// ```
+/// ```should_panic
+/// # use matrix::matrix;
+/// fn main() {
+///     // Ragged rows panic with the DataMismatch message.
+///     let ragged = matrix![1.0, 2.0; 3.0];
+/// }
+/// ```
+#[macro_export]
+macro_rules! matrix {
+    ($($tokens:tt)+) => {
+        match $crate::try_matrix![$($tokens)+] {
+            Ok(matrix) => matrix,
+            Err(error) => panic!("invalid matrix! literal: {}", error),
+        }
+    };
+}
@@ -1,4 +1,7 @@
 use crate::MatrixError::{self, DataMismatch, InvalidMatrixSize};
+use crate::display::{fmt_rows, DisplayOptions};
+use crate::operations::random::SplitMix64;
+use crate::tolerance::{approx_eq_scalar, Tolerance};
 use std::ops::Neg;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -6,15 +9,57 @@ pub struct BuilderMatrix {
     rows: Option<usize>,
     cols: Option<usize>,
     data: Option<Vec<Vec<f64>>>,
+    reject_non_finite: bool,
+    seed: Option<u64>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+/// Dense matrix of `f64`, stored as a `Vec` of rows.
+///
+/// Empty matrices are not supported: every constructor, starting with the builder, rejects zero rows or
+/// columns with `InvalidMatrixSize`, and so do the operations that compute a new shape (`reshape`, `tile`,
+/// views, pooling windows...). Every operation can therefore rely on `rows >= 1`, `cols >= 1`,
+/// `data.len() == rows` and every row having `cols` elements. The fields are public, so a matrix built by
+/// hand that breaks this invariant is a bug in the caller.
+/// # Example
+/// ```
+/// # use matrix::{matrix, Matrix, MatrixError::InvalidMatrixSize, IMatrix, Matrix32, CMatrix};
+/// fn main() {
+///     assert_eq!(Matrix::builder().rows(0).cols(3).done(), Err(InvalidMatrixSize));
+///     assert_eq!(Matrix::builder().rows(3).cols(0).done(), Err(InvalidMatrixSize));
+///     assert_eq!(Matrix::from_rows(vec![]), Err(InvalidMatrixSize));
+///     assert_eq!(Matrix::from_rows(vec![vec![]]), Err(InvalidMatrixSize));
+///     assert_eq!(Matrix::from_flat(0, 0, vec![]), Err(InvalidMatrixSize));
+///     assert_eq!(Matrix::zeros(0, 0), Err(InvalidMatrixSize));
+///     assert_eq!(Matrix::try_with_capacity(2, 0), Err(InvalidMatrixSize));
+///     assert_eq!(Matrix::identity(0), Err(InvalidMatrixSize));
+///     assert_eq!(Matrix::from_diagonal(&[]), Err(InvalidMatrixSize));
+///
+///     let a = matrix![1.0, 2.0; 3.0, 4.0];
+///     assert_eq!(a.reshape(0, 4), Err(InvalidMatrixSize));
+///     assert_eq!(a.tile(0, 1), Err(InvalidMatrixSize));
+///     assert!(a.view(1..1, 0..2).is_err());
+///
+///     assert_eq!(IMatrix::builder().rows(0).done(), Err(InvalidMatrixSize));
+///     assert_eq!(Matrix32::builder().cols(0).done(), Err(InvalidMatrixSize));
+///     assert_eq!(CMatrix::builder().rows(0).done(), Err(InvalidMatrixSize));
+///
+///     // The smallest matrix is 1x1, which is what `new` returns.
+///     assert_eq!(Matrix::new(), matrix![0.0]);
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
 pub struct Matrix {
     pub rows: usize,
     pub cols: usize,
     pub data: Vec<Vec<f64>>,
 }
 
+// A `Matrix` can be moved to and shared between threads; this fails to compile if a field ever changes that.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Matrix>();
+};
+
 impl Neg for Matrix {
     type Output = Self;
 
@@ -35,58 +80,162 @@ impl Neg for Matrix {
     }
 }
 
-impl std::fmt::Display for Matrix {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.rows == 0 || self.cols == 0 {
-            return write!(f, "||");
-        }
-        
-        for (i, row) in self.data.iter().enumerate() {
-            write!(f, "|")?;
-
-            for (j, value) in row.iter().enumerate() {
-                write!(f, "{}", value)?;
-                if j < self.cols - 1 {
-                    write!(f, " ")?;
-                }
-            }
-
-            write!(f, "|")?;
+/// Multiplies two dimensions, mapping overflow to `InvalidMatrixSize`.
+///
+/// The product is also checked in bytes of `f64`, so a successful result can always be allocated in theory.
+pub(crate) fn dim_checked_mul(a: usize, b: usize) -> Result<usize, MatrixError> {
+    a.checked_mul(b)
+        .filter(|n| n.checked_mul(std::mem::size_of::<f64>()).is_some_and(|bytes| bytes <= isize::MAX as usize))
+        .ok_or(MatrixError::InvalidMatrixSize)
+}
 
-            if i < self.rows - 1 {
-                writeln!(f)?;
-            }
-        }
+impl Default for Matrix {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-        Ok(())
+/// Prints one `|a b c|` line per row, see `DisplayOptions` for the truncation of large matrices.
+///
+/// If the public fields were changed so that `data` no longer matches `rows x cols`, a placeholder is printed
+/// instead of the elements.
+/// # Example
+/// ```
+/// # use matrix::{matrix, Matrix};
+/// fn main() {
+///     assert_eq!(Matrix::new().to_string(), "|0|");
+///     assert_eq!(Matrix::default().to_string(), "|0|");
+///     assert_eq!(Matrix::builder().done().unwrap().to_string(), "|0|");
+///     assert_eq!(Matrix::builder().rows(2).cols(3).done().unwrap().to_string(), "|0 0 0|\n|0 0 0|");
+///     assert_eq!(Matrix::zeros(1, 2).unwrap().to_string(), "|0 0|");
+///     assert_eq!(Matrix::identity(2).unwrap().to_string(), "|1 0|\n|0 1|");
+///     assert_eq!(Matrix::from_rows(vec![vec![1.5], vec![-2.0]]).unwrap().to_string(), "|1.5|\n|-2|");
+///     assert_eq!(Matrix::from_flat(1, 3, vec![1.0, 2.0, 3.0]).unwrap().to_string(), "|1 2 3|");
+///     assert_eq!(matrix![f64::NAN, f64::INFINITY].to_string(), "|NaN inf|");
+///
+///     let mut emptied = matrix![1.0, 2.0; 3.0, 4.0];
+///     emptied.data.clear();
+///     assert_eq!(emptied.to_string(), "|<empty 2x2>|");
+///
+///     let mut new = Matrix::new();
+///     new.data = vec![vec![]];
+///     assert_eq!(new.to_string(), "|<empty 1x1>|");
+///
+///     let mut truncated = matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0];
+///     truncated.data.pop();
+///     assert_eq!(truncated.to_string(), "|<inconsistent 3x2>|");
+///
+///     let mut ragged = matrix![1.0, 2.0; 3.0, 4.0];
+///     ragged.data[1].pop();
+///     assert_eq!(ragged.to_string(), "|<inconsistent 2x2>|");
+///
+///     let mut zero_rows = matrix![1.0];
+///     zero_rows.rows = 0;
+///     assert_eq!(zero_rows.to_string(), "|<inconsistent 0x1>|");
+///     assert_eq!(zero_rows.display_with(Default::default()).to_string(), "|<inconsistent 0x1>|");
+/// }
+/// ```
+impl std::fmt::Display for Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_rows(f, self.rows, self.cols, &self.data, &DisplayOptions::default())
     }
 }
 
 impl Matrix {
-    /// Creates a new empty matrix.
+    /// Creates a 1x1 zero matrix, the same as `Matrix::builder().done()`.
+    ///
+    /// There are no empty matrices, see `Matrix`.
     /// # Example
     /// ```
-    /// let matrix = Matrix::new();
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let matrix = Matrix::new();
+    ///     assert_eq!(matrix.data, vec![vec![0.0]]);
+    ///     assert_eq!(matrix.get(0, 0), Some(0.0));
+    /// }
     /// ```
     /// If you want to build a matrix use the `builder` method:
     /// ```
+    /// # use matrix::Matrix;
     /// let matrix = Matrix::builder().done().unwrap();
     /// ```
     pub fn new() -> Self {
         Self {
             rows: 1,
             cols: 1,
-            data: Vec::new(),
+            data: vec![vec![0.0]],
+        }
+    }
+
+    /// Creates a `rows x cols` zero matrix.
+    ///
+    /// Every row is allocated zeroed on its own (`vec![0.0; cols]`) instead of cloning a first zero row,
+    /// so large rows can come straight from zeroed pages without being written. The builder uses the same path.
+    /// Returns `InvalidMatrixSize` for zero dimensions or if `rows * cols` overflows.
+    /// # Example
+    /// ```
+    /// # use matrix::{Matrix, MatrixError};
+    /// fn main() {
+    ///     let zeros = Matrix::zeros(3, 2).unwrap();
+    ///
+    ///     assert_eq!(zeros, Matrix::builder().rows(3).cols(2).done().unwrap());
+    ///     assert_eq!(zeros.data, vec![vec![0.0; 2]; 3]);
+    ///     assert_eq!(Matrix::zeros(0, 2), Err(MatrixError::InvalidMatrixSize));
+    /// }
+    /// ```
+    pub fn zeros(rows: usize, cols: usize) -> Result<Self, MatrixError> {
+        if rows == 0 || cols == 0 {
+            return Err(InvalidMatrixSize);
+        }
+        dim_checked_mul(rows, cols)?;
+
+        let data = (0..rows).map(|_| vec![0.0; cols]).collect();
+        Ok(Self { rows, cols, data })
+    }
+
+    /// Creates a `rows x cols` zero matrix, pre-allocating every row with `try_reserve_exact`.
+    ///
+    /// Absurd sizes return `InvalidMatrixSize` or `AllocationFailed` instead of aborting the process.
+    /// # Example
+    /// ```
+    /// # use matrix::{Matrix, MatrixError};
+    /// fn main() {
+    ///     let matrix = Matrix::try_with_capacity(2, 3).unwrap();
+    ///     assert_eq!(matrix, Matrix::builder().rows(2).cols(3).done().unwrap());
+    ///
+    ///     let too_big = Matrix::try_with_capacity(usize::MAX / 2, 3);
+    ///     assert_eq!(too_big, Err(MatrixError::InvalidMatrixSize));
+    /// }
+    /// ```
+    pub fn try_with_capacity(rows: usize, cols: usize) -> Result<Self, MatrixError> {
+        if rows == 0 || cols == 0 {
+            return Err(InvalidMatrixSize);
         }
+        dim_checked_mul(rows, cols)?;
+
+        let failed = |_| MatrixError::AllocationFailed { rows, cols };
+        let mut data: Vec<Vec<f64>> = Vec::new();
+        data.try_reserve_exact(rows).map_err(failed)?;
+        for _ in 0..rows {
+            let mut row = Vec::new();
+            row.try_reserve_exact(cols).map_err(failed)?;
+            row.resize(cols, 0.0);
+            data.push(row);
+        }
+
+        Ok(Self { rows, cols, data })
     }
 
     /// Creates a new instance of `BuilderMatrix` to start building a matrix.
     /// # Examples
     /// ```
+    /// # use matrix::Matrix;
     /// fn main() {
     ///    let matrix = Matrix::builder().done().unwrap();
     /// }
-    /// 
+    /// ```
+    /// ```
+    /// # use matrix::Matrix;
     /// fn main() {
     ///     let matrix = Matrix::builder()
     ///         .rows(2)
@@ -103,9 +252,32 @@ impl Matrix {
         BuilderMatrix::new()
     }
 
+    /// Creates a matrix from its rows, taking the dimensions from the data.
+    ///
+    /// Returns `InvalidMatrixSize` for empty data and `DataMismatch` if the rows have different lengths.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let matrix = Matrix::from_rows(vec![
+    ///         vec![1.0, 2.0, 3.0],
+    ///         vec![4.0, 5.0, 6.0],
+    ///     ]).unwrap();
+    ///
+    ///     assert_eq!((matrix.rows, matrix.cols), (2, 3));
+    /// }
+    /// ```
+    pub fn from_rows(data: Vec<Vec<f64>>) -> Result<Self, MatrixError> {
+        let rows = data.len();
+        let cols = data.first().map_or(0, |row| row.len());
+
+        Matrix::builder().rows(rows).cols(cols).data(data).done()
+    }
+
     /// Finds an element from the matrix at a specific row and column.
     /// # Example
     /// ```
+    /// # use matrix::Matrix;
     /// fn main() {
     ///     let matrix = Matrix::builder()
     ///         .rows(2)
@@ -132,6 +304,7 @@ impl Matrix {
     /// Finds the location of an element.
     /// # Example
     /// ```
+    /// # use matrix::Matrix;
     /// fn main() {
     ///     let matrix = Matrix::builder()
     ///         .rows(2)
@@ -158,25 +331,155 @@ impl Matrix {
         None
     }
 
+    /// Returns the position of the first `NaN` or infinite element in row-major order, if any.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let matrix = Matrix::from_rows(vec![
+    ///         vec![1.0, f64::NAN],
+    ///         vec![f64::INFINITY, 4.0],
+    ///     ]).unwrap();
+    ///
+    ///     assert_eq!(matrix.has_non_finite(), Some((0, 1)));
+    ///     assert_eq!(Matrix::identity(2).unwrap().has_non_finite(), None);
+    /// }
+    /// ```
+    pub fn has_non_finite(&self) -> Option<(usize, usize)> {
+        for (i, row) in self.data.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                if !value.is_finite() {
+                    return Some((i, j));
+                }
+            }
+        }
+        None
+    }
+
+    /// Checks the invariant documented on `Matrix`: at least one row and one column, `data.len() == rows` and
+    /// every row with `cols` elements.
+    ///
+    /// Every constructor and operation of the crate keeps it, so this only fails after the public fields were
+    /// changed by hand: `InvalidMatrixSize` for a zero dimension and `DataMismatch` for data of the wrong shape.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let mut a = matrix![1.0, 2.0; 3.0, 4.0];
+    ///     assert_eq!(a.check_invariants(), Ok(()));
+    ///
+    ///     a.data[1].push(5.0);
+    ///     assert_eq!(a.check_invariants(), Err(MatrixError::DataMismatch));
+    ///     a.data.pop();
+    ///     assert_eq!(a.check_invariants(), Err(MatrixError::DataMismatch));
+    ///     a.rows = 1;
+    ///     assert_eq!(a.check_invariants(), Ok(()));
+    ///
+    ///     let mut empty = Matrix::new();
+    ///     (empty.rows, empty.data) = (0, vec![]);
+    ///     assert_eq!(empty.check_invariants(), Err(MatrixError::InvalidMatrixSize));
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{Matrix, add::Add, mul::Mul};
+    /// fn main() {
+    ///     // Random shapes, including 0 and ragged data, through the constructors and then random sequences of
+    ///     // operations, checking the invariant and `get` after every step.
+    ///     let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    ///     let mut below = |n: usize| {
+    ///         state ^= state << 13;
+    ///         state ^= state >> 7;
+    ///         state ^= state << 17;
+    ///         (state % n as u64) as usize
+    ///     };
+    ///     let check = |m: &Matrix| {
+    ///         assert_eq!(m.check_invariants(), Ok(()));
+    ///         assert!((0..m.rows).all(|i| (0..m.cols).all(|j| m.get(i, j).is_some())));
+    ///         assert!(m.get(m.rows, 0).is_none() && m.get(0, m.cols).is_none());
+    ///     };
+    ///
+    ///     for seed in 0..300 {
+    ///         let (r, c) = (below(5), below(5));
+    ///         let ragged = below(6) == 0;
+    ///         let rows: Vec<Vec<f64>> = (0..r).map(|i| vec![i as f64 - 1.5; c + usize::from(ragged && i == 0)]).collect();
+    ///         let constructed = [
+    ///             Matrix::zeros(r, c),
+    ///             Matrix::from_flat(r, c, rows.concat()),
+    ///             Matrix::from_rows(rows.clone()),
+    ///             Matrix::from_seed(r, c, seed),
+    ///             Matrix::try_with_capacity(r, c),
+    ///             Matrix::identity(r),
+    ///             Matrix::one_hot(&vec![0; r], c),
+    ///             Matrix::builder().rows(r).cols(c).data(rows.clone()).done(),
+    ///             Matrix::builder_typed().shape(r, c).and_then(|b| b.data(rows.clone())).map(|b| b.done()),
+    ///         ];
+    ///         for mut m in constructed.into_iter().flatten() {
+    ///             check(&m);
+    ///             for _ in 0..8 {
+    ///                 let (i, j) = (below(m.rows + 1), below(m.cols + 1));
+    ///                 let next = match below(12) {
+    ///                     0 => {
+    ///                         let (rows, cols) = (m.rows, m.cols);
+    ///                         let _ = m.view_mut(0..rows, 0..cols).unwrap().set(i, j, 2.0);
+    ///                         Ok(m.clone())
+    ///                     }
+    ///                     1 => m.retain_rows(|k, _| k % 2 == i % 2).map(|_| m.clone()),
+    ///                     2 => m.retain_cols(|k, _| k % 2 == j % 2).map(|_| m.clone()),
+    ///                     3 => m.set_submatrix(i, j, &Matrix::from_seed(2, 1, seed).unwrap()).map(|_| m.clone()),
+    ///                     4 => m.reshape(below(6), below(6)),
+    ///                     5 => m.tile(below(3), below(3)),
+    ///                     6 => Ok(m.transpose()),
+    ///                     7 => m.select_rows(&[i.min(m.rows - 1), 0]),
+    ///                     8 => m.mul(&m.transpose()),
+    ///                     9 => m.add(m.clone()),
+    ///                     10 => m.par_chunks_rows(j.max(1)).and_then(Matrix::merge_row_blocks),
+    ///                     _ => {
+    ///                         let mut out = Matrix::zeros(1 + i, 1 + j).unwrap();
+    ///                         m.mul_into(&m.transpose(), &mut out).map(|_| out)
+    ///                     }
+    ///                 };
+    ///                 check(&m);
+    ///                 if let Ok(next) = next {
+    ///                     check(&next);
+    ///                     if next.rows * next.cols <= 64 {
+    ///                         m = next;
+    ///                     }
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn check_invariants(&self) -> Result<(), MatrixError> {
+        if self.rows == 0 || self.cols == 0 {
+            return Err(InvalidMatrixSize);
+        }
+        if self.data.len() != self.rows || self.data.iter().any(|row| row.len() != self.cols) {
+            return Err(DataMismatch);
+        }
+        Ok(())
+    }
+
     /// ### Function to verify if we can perform an operation with another matrix.
     /// - `is_mult` indicates if we are checking for multiplication (true) or addition (false).
     /// 
     /// If the matrixes are incompatible, it returns false.
     /// # Examples
     /// ```
+    /// # use matrix::Matrix;
     /// fn main() {
     ///     let matrix = Matrix::builder().rows(2).cols(3).data(vec![
     ///         vec![1.0, 2.0, 3.0],
     ///         vec![4.0, 5.0, 6.0],
-    ///     ]);
+    ///     ]).done().unwrap();
     /// 
     ///     let matrix2 = Matrix::builder().rows(3).cols(2).data(vec![
     ///        vec![7.0, 8.0],
     ///        vec![9.0, 10.0],
     ///        vec![11.0, 12.0],
-    ///     ]);
+    ///     ]).done().unwrap();
     ///
-    ///     let can_multiply = matrix.verify(true, matrix2);
+    ///     let can_multiply = matrix.verify(true, matrix2.clone());
     ///     let can_add = matrix.verify(false, matrix2);
     /// 
     ///     assert_eq!(can_multiply, true);
@@ -190,6 +493,306 @@ impl Matrix {
         }
         false
     }
+
+    /// Creates an `n x n` identity matrix.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let identity = Matrix::identity(2).unwrap();
+    ///
+    ///     assert_eq!(identity.data, vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+    /// }
+    /// ```
+    pub fn identity(n: usize) -> Result<Self, MatrixError> {
+        let mut result = Matrix::builder().rows(n).cols(n).done()?;
+        for i in 0..n {
+            result.data[i][i] = 1.0;
+        }
+        Ok(result)
+    }
+
+    /// Creates a square matrix with `values` on the main diagonal and zeros elsewhere.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let diagonal = Matrix::from_diagonal(&[2.0, 3.0]).unwrap();
+    ///
+    ///     assert_eq!(diagonal.data, vec![vec![2.0, 0.0], vec![0.0, 3.0]]);
+    /// }
+    /// ```
+    pub fn from_diagonal(values: &[f64]) -> Result<Self, MatrixError> {
+        let mut result = Matrix::builder().rows(values.len()).cols(values.len()).done()?;
+        for (i, value) in values.iter().enumerate() {
+            result.data[i][i] = *value;
+        }
+        Ok(result)
+    }
+
+    /// Returns the transpose of the matrix, swapping rows and columns.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let matrix = Matrix::builder()
+    ///         .rows(2)
+    ///         .cols(3)
+    ///         .data(vec![
+    ///             vec![1.0, 2.0, 3.0],
+    ///             vec![4.0, 5.0, 6.0],
+    ///         ])
+    ///         .done()
+    ///         .unwrap();
+    ///
+    ///     let transposed = matrix.transpose();
+    ///
+    ///     assert_eq!((transposed.rows, transposed.cols), (3, 2));
+    ///     assert_eq!(transposed.get(2, 0), Some(3.0));
+    /// }
+    /// ```
+    pub fn transpose(&self) -> Self {
+        let data = (0..self.cols)
+            .map(|j| self.data.iter().map(|row| row[j]).collect())
+            .collect();
+
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            data,
+        }
+    }
+
+    /// Returns true if the matrix has as many rows as columns.
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Returns the sum of the main diagonal, erroring with `NotSquare` for non-square matrices.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let matrix = Matrix::builder()
+    ///         .rows(2)
+    ///         .cols(2)
+    ///         .data(vec![
+    ///             vec![1.0, 2.0],
+    ///             vec![3.0, 4.0],
+    ///         ])
+    ///         .done()
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(matrix.trace(), Ok(5.0));
+    /// }
+    /// ```
+    pub fn trace(&self) -> Result<f64, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        Ok(self.data.iter().enumerate().map(|(i, row)| row[i]).sum())
+    }
+
+    /// Checks if the matrix is square and equal to its transpose, up to `eps`.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let matrix = Matrix::builder()
+    ///         .rows(2)
+    ///         .cols(2)
+    ///         .data(vec![
+    ///             vec![1.0, 2.0],
+    ///             vec![2.0, 1.0],
+    ///         ])
+    ///         .done()
+    ///         .unwrap();
+    ///
+    ///     assert!(matrix.is_symmetric(1e-12));
+    /// }
+    /// ```
+    pub fn is_symmetric(&self, eps: f64) -> bool {
+        self.is_symmetric_with_tol(Tolerance::absolute(eps))
+    }
+
+    /// Checks if the matrix is square and every pair `a_ij`, `a_ji` is equal up to `tol`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, Tolerance, DEFAULT_TOLERANCE};
+    /// fn main() {
+    ///     let a = matrix![1.0, 1e6; 1e6 + 1e-4, 1.0];
+    ///
+    ///     assert!(!a.is_symmetric(1e-6));
+    ///     assert!(a.is_symmetric_with_tol(Tolerance::relative(1e-9)));
+    ///     assert!(!a.is_symmetric_with_tol(DEFAULT_TOLERANCE));
+    ///     assert!(!matrix![1.0, 2.0].is_symmetric_with_tol(Tolerance::absolute(1e300)));
+    /// }
+    /// ```
+    pub fn is_symmetric_with_tol(&self, tol: Tolerance) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        for i in 0..self.rows {
+            for j in (i + 1)..self.cols {
+                if !approx_eq_scalar(self.data[i][j], self.data[j][i], tol) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Checks if every element is within `eps` of 0. `NaN` elements are never zero.
+    ///
+    /// The scan stops at the first element that is not, so it is cheap on matrices that are far from zero.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     assert!(Matrix::zeros(3, 2).unwrap().is_zero(0.0));
+    ///     assert!(matrix![1e-14, -0.0; 0.0, -1e-14].is_zero(1e-12));
+    ///     assert!(!matrix![1e-14, 0.0].is_zero(0.0));
+    ///     assert!(!matrix![0.0, f64::NAN].is_zero(1.0));
+    /// }
+    /// ```
+    pub fn is_zero(&self, eps: f64) -> bool {
+        self.data.iter().flatten().all(|x| x.abs() <= eps)
+    }
+
+    /// Checks if the matrix is square with its diagonal within `eps` of 1 and every other element within `eps` of 0.
+    ///
+    /// Like `is_zero`, the scan stops at the first element that doesn't match.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     assert!(Matrix::identity(4).unwrap().is_identity(0.0));
+    ///     assert!(matrix![1.0 + 1e-15, 0.0; -1e-15, 1.0].is_identity(1e-12));
+    ///     assert!(!matrix![1.0, 0.0; 0.0, 2.0].is_identity(1e-12));
+    ///     assert!(!matrix![1.0, 0.0].is_identity(1.0));
+    /// }
+    /// ```
+    pub fn is_identity(&self, eps: f64) -> bool {
+        self.is_square()
+            && self.data.iter().enumerate().all(|(i, row)| {
+                row.iter().enumerate().all(|(j, &x)| (x - if i == j { 1.0 } else { 0.0 }).abs() <= eps)
+            })
+    }
+
+    /// Copies the matrix into `target`, reusing the allocations `target` already has.
+    ///
+    /// With the same shape no memory is allocated, the rows are copied in place. Otherwise the rows that exist
+    /// are reused and grown or shrunk, and only the missing ones are allocated.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0; 3.0, 4.0];
+    ///     let mut target = Matrix::zeros(2, 2).unwrap();
+    ///     let first_row = target.data[0].as_ptr();
+    ///
+    ///     a.clone_into(&mut target);
+    ///     assert_eq!(target, a);
+    ///     assert_eq!(target.data[0].as_ptr(), first_row);
+    ///
+    ///     let mut other_shape = Matrix::zeros(5, 1).unwrap();
+    ///     a.clone_into(&mut other_shape);
+    ///     assert_eq!(other_shape, a);
+    /// }
+    /// ```
+    pub fn clone_into(&self, target: &mut Matrix) {
+        target.rows = self.rows;
+        target.cols = self.cols;
+        target.data.clone_from(&self.data);
+    }
+
+    /// Estimates the heap memory held by the matrix, in bytes: the capacity of the outer `Vec` times the size of a
+    /// `Vec<f64>`, plus the capacity of every row times the size of an `f64`.
+    ///
+    /// This is approximate: it is based on capacities rather than lengths, so it counts memory reserved for growth,
+    /// and it leaves out the allocator's own overhead and the `Matrix` value itself.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let a = Matrix::zeros(3, 4).unwrap();
+    ///     assert_eq!(a.memory_footprint(), 3 * std::mem::size_of::<Vec<f64>>() + 12 * std::mem::size_of::<f64>());
+    /// }
+    /// ```
+    pub fn memory_footprint(&self) -> usize {
+        let rows = self.data.capacity() * std::mem::size_of::<Vec<f64>>();
+        rows + self.data.iter().map(|row| row.capacity() * std::mem::size_of::<f64>()).sum::<usize>()
+    }
+
+    /// Releases the capacity that the outer `Vec` and the rows hold beyond their length, without changing the
+    /// elements.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     // Grown one row and one element at a time, like a matrix read from a stream.
+    ///     let mut a = Matrix::from_rows(vec![vec![0.0]]).unwrap();
+    ///     for i in 1..100 {
+    ///         let mut row = Vec::new();
+    ///         (0..10).for_each(|j| row.push((i * 10 + j) as f64));
+    ///         a.data.push(row);
+    ///         a.rows += 1;
+    ///     }
+    ///     a.data[0] = vec![0.0; 10];
+    ///     a.cols = 10;
+    ///     let before = (a.memory_footprint(), a.clone());
+    ///
+    ///     a.shrink_to_fit();
+    ///     assert!(a.memory_footprint() < before.0);
+    ///     assert_eq!(a.memory_footprint(), 100 * std::mem::size_of::<Vec<f64>>() + 1000 * std::mem::size_of::<f64>());
+    ///     assert_eq!(a, before.1);
+    ///     assert_eq!(a.check_invariants(), Ok(()));
+    /// }
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+        self.data.iter_mut().for_each(Vec::shrink_to_fit);
+    }
+
+    /// Reserves room in the outer `Vec` for at least `additional` more rows, so that pushing them to `data` does not
+    /// reallocate it. The rows themselves are allocated by whoever pushes them.
+    ///
+    /// Returns `AllocationFailed` with the requested shape if the memory can't be reserved, leaving the matrix as it
+    /// was.
+    /// # Example
+    /// ```
+    /// # use matrix::{Matrix, MatrixError};
+    /// fn main() {
+    ///     let mut a = Matrix::zeros(1, 3).unwrap();
+    ///     a.reserve_rows(9).unwrap();
+    ///     let outer = a.data.as_ptr();
+    ///     for _ in 0..9 {
+    ///         a.data.push(vec![1.0; 3]);
+    ///         a.rows += 1;
+    ///     }
+    ///     assert_eq!(a.data.as_ptr(), outer);
+    ///     assert_eq!(a.check_invariants(), Ok(()));
+    ///
+    ///     assert_eq!(a.reserve_rows(usize::MAX), Err(MatrixError::AllocationFailed { rows: usize::MAX, cols: 3 }));
+    /// }
+    /// ```
+    pub fn reserve_rows(&mut self, additional: usize) -> Result<(), MatrixError> {
+        self.data
+            .try_reserve(additional)
+            .map_err(|_| MatrixError::AllocationFailed { rows: self.rows.saturating_add(additional), cols: self.cols })
+    }
+
+    /// Gives the matrix the shape `rows x cols`, keeping the existing row allocations where possible.
+    ///
+    /// The content is unspecified afterwards: it is meant for output buffers that are about to be overwritten.
+    pub(crate) fn resize_for_output(&mut self, rows: usize, cols: usize) {
+        self.rows = rows;
+        self.cols = cols;
+        self.data.resize_with(rows, Vec::new);
+        self.data.iter_mut().for_each(|row| row.resize(cols, 0.0));
+    }
 }
 
 
@@ -200,6 +803,8 @@ impl BuilderMatrix {
             rows: Some(1),
             cols: Some(1),
             data: Some(Vec::new()),
+            reject_non_finite: false,
+            seed: None,
         }
     }
     
@@ -207,10 +812,13 @@ impl BuilderMatrix {
     /// - If no `data` is provided, it initializes the matrix with zeros.
     /// # Examples
     /// ```
+    /// # use matrix::Matrix;
     /// fn main() {
     ///     let matrix = Matrix::builder().rows(3).done().unwrap();
     /// }
-    /// 
+    /// ```
+    /// ```
+    /// # use matrix::Matrix;
     /// fn main() {
     ///     let matrix = Matrix::builder()
     ///         .rows(3)
@@ -232,10 +840,13 @@ impl BuilderMatrix {
     /// - If no `data` is provided, it initializes the matrix with zeros.
     /// # Examples
     /// ```
+    /// # use matrix::Matrix;
     /// fn main() {
     ///     let matrix = Matrix::builder().cols(3).done().unwrap();
     /// }
-    /// 
+    /// ```
+    /// ```
+    /// # use matrix::Matrix;
     /// fn main() {
     ///     let matrix = Matrix::builder()
     ///         .cols(3)
@@ -255,10 +866,13 @@ impl BuilderMatrix {
     /// - If you want more than 1 element, you must provide the rows and/or columns.
     /// # Examples
     /// ```
+    /// # use matrix::Matrix;
     /// fn main() {
     ///     let matrix = Matrix::builder().data(vec![vec![8.0]]).done().unwrap();
     /// }
-    /// 
+    /// ```
+    /// ```
+    /// # use matrix::Matrix;
     /// fn main() {
     ///     let matrix = Matrix::builder()
     ///         .rows(2)
@@ -276,6 +890,57 @@ impl BuilderMatrix {
         self
     }
 
+    /// Fills the matrix with pseudo-random values in `[0, 1)` instead of zeros, the same as `Matrix::from_seed`.
+    ///
+    /// Explicit `data` takes precedence over the seed.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let matrix = Matrix::builder().rows(3).cols(4).pseudo_random(42).done().unwrap();
+    ///
+    ///     assert_eq!(matrix, Matrix::from_seed(3, 4, 42).unwrap());
+    /// }
+    /// ```
+    pub fn pseudo_random(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Makes `done` fail with `NonFiniteValue` if any element of the data is `NaN` or infinite.
+    ///
+    /// This is off by default. The reported position is the first non-finite element in row-major order.
+    /// # Example
+    /// ```
+    /// # use matrix::{Matrix, MatrixError};
+    /// fn main() {
+    ///     let result = Matrix::builder()
+    ///         .rows(2)
+    ///         .cols(2)
+    ///         .data(vec![
+    ///             vec![1.0, 2.0],
+    ///             vec![f64::INFINITY, f64::NAN],
+    ///         ])
+    ///         .reject_non_finite()
+    ///         .done();
+    ///
+    ///     match result {
+    ///         Err(MatrixError::NonFiniteValue { row, col, value }) => {
+    ///             assert_eq!((row, col), (1, 0));
+    ///             assert_eq!(value, f64::INFINITY);
+    ///         }
+    ///         other => panic!("unexpected result: {:?}", other),
+    ///     }
+    ///
+    ///     let negative = Matrix::builder().data(vec![vec![f64::NEG_INFINITY]]).reject_non_finite().done();
+    ///     assert!(matches!(negative, Err(MatrixError::NonFiniteValue { row: 0, col: 0, .. })));
+    /// }
+    /// ```
+    pub fn reject_non_finite(mut self) -> Self {
+        self.reject_non_finite = true;
+        self
+    }
+
     /// Finalizes the matrix creation process.
     /// 
     /// This method MUST be called in the end of the matrix creation chain.
@@ -283,6 +948,7 @@ impl BuilderMatrix {
     /// It checks if the matrix has valid dimensions and data.
     /// Sometimes it will modify the matrix to ensure it will be valid.
     /// ```
+    /// # use matrix::Matrix;
     /// fn main() {
     ///     let matrix = Matrix::builder()
     ///         .rows(2)
@@ -294,7 +960,9 @@ impl BuilderMatrix {
     ///         .done()
     ///         .unwrap();
     /// }
-    /// 
+    /// ```
+    /// ```
+    /// # use matrix::Matrix;
     /// fn main() {
     ///     // This will return an error because the number of rows do not match the data provided.
     ///     let matrix = Matrix::builder()
@@ -309,19 +977,56 @@ impl BuilderMatrix {
     ///         .unwrap_err();
     /// }
     /// ```
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     // The data is moved into the matrix, never copied.
+    ///     let data = vec![vec![1.0; 1000]; 1000];
+    ///     let (outer, first_row) = (data.as_ptr(), data[0].as_ptr());
+    ///
+    ///     let matrix = Matrix::builder().rows(1000).cols(1000).data(data).done().unwrap();
+    ///
+    ///     assert_eq!(matrix.data.as_ptr(), outer);
+    ///     assert_eq!(matrix.data[0].as_ptr(), first_row);
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{Matrix, MatrixError};
+    /// fn main() {
+    ///     // Sizes whose element count overflows are rejected before allocating.
+    ///     let matrix = Matrix::builder().rows(usize::MAX / 2).cols(3).done();
+    ///     assert_eq!(matrix, Err(MatrixError::InvalidMatrixSize));
+    /// }
+    /// ```
     pub fn done(self) -> Result<Matrix, MatrixError> {
-        match self {
-            Self { rows, cols, .. } if rows == Some(0) || cols == Some(0) => Err(InvalidMatrixSize),
-            Self { rows, cols, data } if data.clone().unwrap().is_empty() => {
-                let data = vec![vec![0.0; cols.unwrap()]; rows.unwrap()];
-                Ok(Matrix { rows: rows.unwrap(), cols: cols.unwrap(), data })
+        let BuilderMatrix { rows, cols, data, reject_non_finite, seed } = self;
+        let (rows, cols) = (rows.unwrap_or(1), cols.unwrap_or(1));
+        let data = data.unwrap_or_default();
+
+        if rows == 0 || cols == 0 {
+            return Err(InvalidMatrixSize);
+        }
+
+        if data.is_empty() {
+            dim_checked_mul(rows, cols)?;
+            let data = match seed {
+                Some(seed) => SplitMix64::new(seed).fill(rows, cols),
+                None => (0..rows).map(|_| vec![0.0; cols]).collect(),
+            };
+            return Ok(Matrix { rows, cols, data });
+        }
+
+        if data.len() != rows || data.iter().any(|row| row.len() != cols) {
+            return Err(DataMismatch);
+        }
+
+        let matrix = Matrix { rows, cols, data };
+        if reject_non_finite {
+            if let Some((row, col)) = matrix.has_non_finite() {
+                return Err(MatrixError::NonFiniteValue { row, col, value: matrix.data[row][col] });
             }
-            Self { rows, cols, data } if data.clone().unwrap().len() != rows.unwrap() || data.clone().unwrap().iter().any(|row| row.len() != cols.unwrap()) => Err(DataMismatch),
-            _ => Ok(Matrix {
-                rows: self.rows.unwrap(),
-                cols: self.cols.unwrap(),
-                data: self.data.unwrap(),
-            }),
         }
+
+        Ok(matrix)
     }
 }
\ No newline at end of file
@@ -0,0 +1,163 @@
+use crate::display::{fmt_rows, DisplayOptions};
+use crate::matrix::{dim_checked_mul, Matrix};
+use crate::MatrixError::{self, DataMismatch, InvalidMatrixSize};
+
+/// Single-precision counterpart of `Matrix`, for data where `f64` would only double the memory (images, for example).
+///
+/// It has the same builder and the same `add`, `sub`, `mul` and `scale` operations as `Matrix`,
+/// which share their implementation so both types compute the same way.
+/// # Example
+/// ```
+/// # use matrix::{Matrix32, mul::Mul};
+/// fn main() {
+///     let a = Matrix32::builder()
+///         .rows(2)
+///         .cols(2)
+///         .data(vec![vec![1.0, 2.0], vec![3.0, 4.0]])
+///         .done()
+///         .unwrap();
+///
+///     let b = a.mul(a.clone()).unwrap();
+///     assert_eq!(b.data, vec![vec![7.0, 10.0], vec![15.0, 22.0]]);
+///     assert_eq!(format!("{}", a), "|1 2|\n|3 4|");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix32 {
+    pub rows: usize,
+    pub cols: usize,
+    pub data: Vec<Vec<f32>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuilderMatrix32 {
+    rows: Option<usize>,
+    cols: Option<usize>,
+    data: Option<Vec<Vec<f32>>>,
+}
+
+impl std::fmt::Display for Matrix32 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_rows(f, self.rows, self.cols, &self.data, &DisplayOptions::default())
+    }
+}
+
+impl Matrix32 {
+    /// Creates a builder for a `Matrix32`, see `Matrix::builder`.
+    pub fn builder() -> BuilderMatrix32 {
+        BuilderMatrix32 {
+            rows: Some(1),
+            cols: Some(1),
+            data: Some(Vec::new()),
+        }
+    }
+
+    /// Gets the element at (`row`, `col`), `None` if it is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<f32> {
+        self.data.get(row).and_then(|r| r.get(col)).copied()
+    }
+
+    /// Converts to a `Matrix`. Every `f32` is exactly representable as an `f64`, so this is lossless.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix32;
+    /// fn main() {
+    ///     let a = Matrix32::builder().data(vec![vec![0.1]]).done().unwrap();
+    ///
+    ///     assert_eq!(a.to_f64().data[0][0], 0.1f32 as f64);
+    ///     assert_eq!(a.to_f64().to_f32(), a);
+    /// }
+    /// ```
+    pub fn to_f64(&self) -> Matrix {
+        let data = self.data
+            .iter()
+            .map(|row| row.iter().map(|&x| x as f64).collect())
+            .collect();
+        Matrix { rows: self.rows, cols: self.cols, data }
+    }
+}
+
+impl Matrix {
+    /// Converts to a `Matrix32`. This is lossy: every element is rounded to the nearest `f32`,
+    /// values beyond the `f32` range become infinite and tiny ones may become zero.
+    ///
+    /// For values in the normal `f32` range, the relative error of the round trip is at most `f32::EPSILON / 2`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![0.1, -2.0 / 3.0; 12345.6789, 1e-20];
+    ///     let round_trip = a.to_f32().to_f64();
+    ///
+    ///     for (x, y) in a.data.iter().flatten().zip(round_trip.data.iter().flatten()) {
+    ///         assert!(((x - y) / x).abs() <= f32::EPSILON as f64 / 2.0);
+    ///     }
+    ///
+    ///     let huge = matrix![1e300];
+    ///     assert_eq!(huge.to_f32().data[0][0], f32::INFINITY);
+    /// }
+    /// ```
+    pub fn to_f32(&self) -> Matrix32 {
+        let data = self.data
+            .iter()
+            .map(|row| row.iter().map(|&x| x as f32).collect())
+            .collect();
+        Matrix32 { rows: self.rows, cols: self.cols, data }
+    }
+}
+
+impl BuilderMatrix32 {
+    /// Sets the number of rows for the matrix.
+    /// - If no `data` is provided, it initializes the matrix with zeros.
+    pub fn rows(mut self, n: usize) -> Self {
+        self.rows = Some(n);
+        self
+    }
+
+    /// Sets the number of columns for the matrix.
+    /// - If no `data` is provided, it initializes the matrix with zeros.
+    pub fn cols(mut self, n: usize) -> Self {
+        self.cols = Some(n);
+        self
+    }
+
+    /// Sets the data for the matrix.
+    pub fn data(mut self, data: Vec<Vec<f32>>) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Finalizes the matrix creation process, with the same checks as `BuilderMatrix::done`.
+    /// # Example
+    /// ```
+    /// # use matrix::{Matrix32, MatrixError};
+    /// fn main() {
+    ///     let zeros = Matrix32::builder().rows(2).cols(3).done().unwrap();
+    ///     assert_eq!(zeros.data, vec![vec![0.0; 3]; 2]);
+    ///
+    ///     let bad = Matrix32::builder().rows(2).data(vec![vec![1.0]]).done();
+    ///     assert_eq!(bad, Err(MatrixError::DataMismatch));
+    /// }
+    /// ```
+    pub fn done(self) -> Result<Matrix32, MatrixError> {
+        let BuilderMatrix32 { rows, cols, data } = self;
+        let (rows, cols) = (rows.unwrap_or(1), cols.unwrap_or(1));
+        let data = data.unwrap_or_default();
+
+        if rows == 0 || cols == 0 {
+            return Err(InvalidMatrixSize);
+        }
+
+        if data.is_empty() {
+            dim_checked_mul(rows, cols)?;
+            let data = vec![vec![0.0; cols]; rows];
+            return Ok(Matrix32 { rows, cols, data });
+        }
+
+        if data.len() != rows || data.iter().any(|row| row.len() != cols) {
+            return Err(DataMismatch);
+        }
+
+        Ok(Matrix32 { rows, cols, data })
+    }
+}
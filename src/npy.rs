@@ -0,0 +1,198 @@
+use crate::matrix::{dim_checked_mul, Matrix};
+use crate::errors::MatrixError;
+use std::path::Path;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+impl Matrix {
+    /// Reads a NumPy `.npy` file holding a 2-D `float64` array, see `from_npy_bytes`.
+    ///
+    /// A file that can't be read returns `Io` with the path.
+    pub fn load_npy(path: impl AsRef<Path>) -> Result<Matrix, MatrixError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(|error| MatrixError::Io { path: path.to_path_buf(), kind: error.kind() })?;
+        Matrix::from_npy_bytes(&bytes)
+    }
+
+    /// Writes the matrix as a NumPy `.npy` file, see `to_npy_bytes`. Failures return `Io` with the path.
+    pub fn save_npy(&self, path: impl AsRef<Path>) -> Result<(), MatrixError> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_npy_bytes()).map_err(|error| MatrixError::Io { path: path.to_path_buf(), kind: error.kind() })
+    }
+
+    /// Parses the content of a NumPy `.npy` file (format versions 1.0 to 3.0) holding a 2-D `float64` array.
+    ///
+    /// Both byte orders are accepted, and arrays saved in Fortran (column-major) order are transposed back while
+    /// reading, so the result always has the shape numpy reports. Any other dtype or number of dimensions, and
+    /// malformed or truncated content, return `UnsupportedFormat` with the reason. An array with a zero dimension
+    /// returns `InvalidMatrixSize`, like everywhere else.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     // np.save("arange_2x3.npy", np.arange(6.0).reshape(2, 3) / 4 - 0.5), in C and in Fortran order.
+    ///     let expected = matrix![-0.5, -0.25, 0.0; 0.25, 0.5, 0.75];
+    ///     let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data");
+    ///
+    ///     assert_eq!(Matrix::load_npy(format!("{}/arange_2x3.npy", dir)).unwrap(), expected);
+    ///     assert_eq!(Matrix::load_npy(format!("{}/arange_2x3_fortran.npy", dir)).unwrap(), expected);
+    ///
+    ///     // `to_npy_bytes` writes exactly what numpy writes.
+    ///     assert_eq!(expected.to_npy_bytes(), std::fs::read(format!("{}/arange_2x3.npy", dir)).unwrap());
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{Matrix, MatrixError};
+    /// fn npy(header: &str, data: &[u8]) -> Vec<u8> {
+    ///     let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    ///     bytes.extend((header.len() as u16).to_le_bytes());
+    ///     bytes.extend(header.as_bytes());
+    ///     bytes.extend(data);
+    ///     bytes
+    /// }
+    ///
+    /// fn reason(bytes: &[u8]) -> String {
+    ///     match Matrix::from_npy_bytes(bytes) {
+    ///         Err(MatrixError::UnsupportedFormat { format: "npy", reason }) => reason,
+    ///         other => panic!("unexpected {:?}", other),
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let ints = npy("{'descr': '<i8', 'fortran_order': False, 'shape': (1, 1), }\n", &[0; 8]);
+    ///     assert_eq!(reason(&ints), "dtype '<i8' is not supported, only float64 ('<f8' or '>f8')");
+    ///
+    ///     let vector = npy("{'descr': '<f8', 'fortran_order': False, 'shape': (3,), }\n", &[0; 24]);
+    ///     assert_eq!(reason(&vector), "expected a 2-D array, got shape (3,)");
+    ///
+    ///     let truncated = npy("{'descr': '<f8', 'fortran_order': False, 'shape': (2, 2), }\n", &[0; 24]);
+    ///     assert_eq!(reason(&truncated), "expected 32 bytes of data, got 24");
+    ///
+    ///     assert_eq!(reason(b"PK\x03\x04"), "missing the \\x93NUMPY magic string");
+    ///
+    ///     // A version 2 header claiming `u32::MAX` bytes.
+    ///     assert_eq!(reason(b"\x93NUMPY\x02\x00\xff\xff\xff\xff{}"), "truncated header");
+    ///
+    ///     let big_endian = npy("{'descr': '>f8', 'fortran_order': False, 'shape': (1, 2), }\n", &[63, 240, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0]);
+    ///     assert_eq!(Matrix::from_npy_bytes(&big_endian).unwrap().data, vec![vec![1.0, -2.0]]);
+    ///
+    ///     let empty = npy("{'descr': '<f8', 'fortran_order': False, 'shape': (0, 3), }\n", &[]);
+    ///     assert_eq!(Matrix::from_npy_bytes(&empty), Err(MatrixError::InvalidMatrixSize));
+    /// }
+    /// ```
+    pub fn from_npy_bytes(bytes: &[u8]) -> Result<Matrix, MatrixError> {
+        let unsupported = |reason: String| MatrixError::UnsupportedFormat { format: "npy", reason };
+
+        if !bytes.starts_with(MAGIC) {
+            return Err(unsupported("missing the \\x93NUMPY magic string".into()));
+        }
+        let (header_start, header_len): (usize, usize) = match bytes.get(6..8) {
+            Some([1, _]) if bytes.len() >= 10 => (10, u16::from_le_bytes([bytes[8], bytes[9]]) as usize),
+            Some([2 | 3, _]) if bytes.len() >= 12 => (12, u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize),
+            Some([major, minor]) => return Err(unsupported(format!("unsupported format version {}.{}", major, minor))),
+            _ => return Err(unsupported("truncated header".into())),
+        };
+        // The length is a `u32` from the file, which can overflow a 32-bit `usize` once added to the start.
+        let header_end = header_start.checked_add(header_len).ok_or_else(|| unsupported("truncated header".into()))?;
+        let header = bytes
+            .get(header_start..header_end)
+            .and_then(|header| std::str::from_utf8(header).ok())
+            .ok_or_else(|| unsupported("truncated header".into()))?;
+
+        let descr = header_value(header, "descr").ok_or_else(|| unsupported("header has no 'descr'".into()))?;
+        let big_endian = match descr {
+            "'<f8'" => false,
+            "'>f8'" => true,
+            other => return Err(unsupported(format!("dtype {} is not supported, only float64 ('<f8' or '>f8')", other))),
+        };
+        let fortran_order = match header_value(header, "fortran_order") {
+            Some("False") => false,
+            Some("True") => true,
+            _ => return Err(unsupported("header has no valid 'fortran_order'".into())),
+        };
+        let shape = header_value(header, "shape").ok_or_else(|| unsupported("header has no 'shape'".into()))?;
+        let dims: Vec<usize> = shape
+            .trim_start_matches('(')
+            .trim_end_matches(')')
+            .split(',')
+            .map(str::trim)
+            .filter(|dim| !dim.is_empty())
+            .map(|dim| dim.parse().map_err(|_| unsupported(format!("invalid shape {}", shape))))
+            .collect::<Result<_, _>>()?;
+        let [rows, cols] = dims[..] else {
+            return Err(unsupported(format!("expected a 2-D array, got shape {}", shape)));
+        };
+        if rows == 0 || cols == 0 {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+
+        let len = dim_checked_mul(rows, cols)?;
+        let data = &bytes[header_end..];
+        let expected = len.checked_mul(8).ok_or(MatrixError::InvalidMatrixSize)?;
+        if data.len() != expected {
+            return Err(unsupported(format!("expected {} bytes of data, got {}", expected, data.len())));
+        }
+
+        let values: Vec<f64> = data
+            .chunks_exact(8)
+            .map(|chunk| {
+                let chunk = <[u8; 8]>::try_from(chunk).expect("chunks_exact yields 8 bytes");
+                if big_endian { f64::from_be_bytes(chunk) } else { f64::from_le_bytes(chunk) }
+            })
+            .collect();
+        if fortran_order {
+            Ok(Matrix::from_flat(cols, rows, values)?.transpose())
+        } else {
+            Matrix::from_flat(rows, cols, values)
+        }
+    }
+
+    /// Serializes the matrix as a NumPy `.npy` file: format version 1.0, little-endian `float64` in C order,
+    /// with the header padded to 64 bytes like numpy does, so `np.load` reads it back as a 2-D array.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, f64::NAN; -0.0, 1e300; f64::NEG_INFINITY, 0.1];
+    ///     let bytes = a.to_npy_bytes();
+    ///     assert_eq!(bytes.len(), 128 + 6 * 8);
+    ///
+    ///     let back = Matrix::from_npy_bytes(&bytes).unwrap();
+    ///     assert!(a.data.iter().flatten().zip(back.data.iter().flatten()).all(|(x, y)| x.to_bits() == y.to_bits()));
+    ///
+    ///     let path = std::env::temp_dir().join(format!("matrix-npy-{}.npy", std::process::id()));
+    ///     let b = Matrix::from_seed(20, 7, 1).unwrap();
+    ///     b.save_npy(&path).unwrap();
+    ///     assert_eq!(Matrix::load_npy(&path).unwrap(), b);
+    ///     std::fs::remove_file(&path).unwrap();
+    /// }
+    /// ```
+    pub fn to_npy_bytes(&self) -> Vec<u8> {
+        let mut header = format!("{{'descr': '<f8', 'fortran_order': False, 'shape': ({}, {}), }}", self.rows, self.cols);
+        let unpadded = MAGIC.len() + 4 + header.len() + 1;
+        header.push_str(&" ".repeat((64 - unpadded % 64) % 64));
+        header.push('\n');
+
+        let mut bytes = Vec::with_capacity(MAGIC.len() + 4 + header.len() + self.rows * self.cols * 8);
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        for value in self.data.iter().flatten() {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+}
+
+/// Finds the raw value of `'key'` in a `.npy` header dictionary: a quoted string, a tuple or a bare word.
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let start = header.find(&format!("'{}':", key))? + key.len() + 3;
+    let rest = header[start..].trim_start();
+    let end = match rest.chars().next()? {
+        '\'' => rest[1..].find('\'')? + 2,
+        '(' => rest.find(')')? + 1,
+        _ => rest.find([',', '}'])?,
+    };
+    Some(rest[..end].trim())
+}
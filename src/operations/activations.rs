@@ -0,0 +1,162 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use crate::operations::kernels::map_rows;
+
+/// Logistic function, written so that neither branch exponentiates a large positive number.
+fn sigmoid(x: f64) -> f64 {
+    if x >= 0.0 {
+        1.0 / (1.0 + (-x).exp())
+    } else {
+        let e = x.exp();
+        e / (1.0 + e)
+    }
+}
+
+impl Matrix {
+    /// Applies `max(x, 0)` to every element.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let x = matrix![-1.0, 0.0, 2.5];
+    ///
+    ///     assert_eq!(x.relu(), matrix![0.0, 0.0, 2.5]);
+    ///     assert_eq!(x.relu_derivative(), matrix![0.0, 0.0, 1.0]);
+    /// }
+    /// ```
+    pub fn relu(&self) -> Matrix {
+        Matrix { rows: self.rows, cols: self.cols, data: map_rows(&self.data, |x| x.max(0.0)) }
+    }
+
+    /// Derivative of `relu` at every element: 1 where it is positive, 0 elsewhere (0 included).
+    pub fn relu_derivative(&self) -> Matrix {
+        Matrix { rows: self.rows, cols: self.cols, data: map_rows(&self.data, |x| if x > 0.0 { 1.0 } else { 0.0 }) }
+    }
+
+    /// Applies the logistic function `1 / (1 + e^-x)` to every element, without overflowing for large `|x|`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let x = matrix![0.0, -1000.0, 1000.0];
+    ///
+    ///     assert_eq!(x.sigmoid(), matrix![0.5, 0.0, 1.0]);
+    ///     assert_eq!(x.sigmoid_derivative(), matrix![0.25, 0.0, 0.0]);
+    /// }
+    /// ```
+    pub fn sigmoid(&self) -> Matrix {
+        Matrix { rows: self.rows, cols: self.cols, data: map_rows(&self.data, sigmoid) }
+    }
+
+    /// Derivative of `sigmoid` at every element, `s(x) * (1 - s(x))`.
+    ///
+    /// Like the other derivatives it takes the input of the activation, not its output.
+    pub fn sigmoid_derivative(&self) -> Matrix {
+        let derivative = |x| {
+            let s = sigmoid(x);
+            s * (1.0 - s)
+        };
+        Matrix { rows: self.rows, cols: self.cols, data: map_rows(&self.data, derivative) }
+    }
+
+    /// Applies the hyperbolic tangent to every element.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let x = matrix![0.0, 0.5; -0.5, 20.0];
+    ///     let (y, dy) = (x.tanh(), x.tanh_derivative());
+    ///
+    ///     assert_eq!((dy.rows, dy.cols), (x.rows, x.cols));
+    ///     assert_eq!(y.data[0][0], 0.0);
+    ///     assert_eq!(y.data[0][1], -y.data[1][0]);
+    ///     assert_eq!(dy.data[0][0], 1.0);
+    ///     assert!((dy.data[0][1] - (1.0 - 0.5f64.tanh().powi(2))).abs() < 1e-15);
+    ///     assert!(dy.data[1][1] < 1e-15);
+    /// }
+    /// ```
+    pub fn tanh(&self) -> Matrix {
+        Matrix { rows: self.rows, cols: self.cols, data: map_rows(&self.data, f64::tanh) }
+    }
+
+    /// Derivative of `tanh` at every element, `1 - tanh(x)^2`.
+    pub fn tanh_derivative(&self) -> Matrix {
+        Matrix { rows: self.rows, cols: self.cols, data: map_rows(&self.data, |x| 1.0 - x.tanh().powi(2)) }
+    }
+
+    /// Applies softmax to every row, so each row becomes a probability distribution.
+    ///
+    /// The row maximum is subtracted before exponentiating, which gives the same result
+    /// without overflowing: a row of identical huge values is still uniform.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let logits = matrix![1.0, 2.0, 3.0; 1e308, 1e308, 1e308; -1000.0, 0.0, 1000.0];
+    ///     let p = logits.softmax_rows();
+    ///
+    ///     for row in &p.data {
+    ///         assert!((row.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    ///     }
+    ///     assert!(p.data[0][0] < p.data[0][1] && p.data[0][1] < p.data[0][2]);
+    ///     assert_eq!(p.data[1], vec![1.0 / 3.0; 3]);
+    ///     assert_eq!(p.data[2], vec![0.0, 0.0, 1.0]);
+    ///
+    ///     // Adding a constant to a row doesn't change its softmax.
+    ///     let shifted = matrix![101.0, 102.0, 103.0].softmax_rows();
+    ///     assert!(shifted.data[0].iter().zip(&p.data[0]).all(|(a, b)| (a - b).abs() < 1e-15));
+    /// }
+    /// ```
+    pub fn softmax_rows(&self) -> Matrix {
+        let data = self.data
+            .iter()
+            .map(|row| {
+                let max = row.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                let exps: Vec<f64> = row.iter().map(|x| (x - max).exp()).collect();
+                let sum: f64 = exps.iter().sum();
+                exps.into_iter().map(|e| e / sum).collect()
+            })
+            .collect();
+        Matrix { rows: self.rows, cols: self.cols, data }
+    }
+
+    /// Backpropagates `upstream`, the gradient of a loss with respect to `softmax_rows()`, through the softmax.
+    ///
+    /// The derivative of softmax is a full Jacobian per row, `diag(s) - s s^T`, so instead of a matrix of
+    /// derivatives this returns its product with the gradient, row by row: `s * (g - sum(g * s))`.
+    /// `upstream` must have the same shape as `self`, otherwise it returns `ShapeMismatch`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let logits = matrix![0.5, -1.0, 2.0; 0.0, 0.0, 0.0];
+    ///
+    ///     // The gradient of `p[i][k]` alone is `p[i][k] * (delta_jk - p[i][j])`.
+    ///     let p = logits.softmax_rows();
+    ///     let grad = logits.softmax_rows_backward(&matrix![0.0, 0.0, 1.0; 0.0, 0.0, 1.0]).unwrap();
+    ///     assert_eq!((grad.rows, grad.cols), (logits.rows, logits.cols));
+    ///     for i in 0..2 {
+    ///         let expected = [-p.data[i][2] * p.data[i][0], -p.data[i][2] * p.data[i][1], p.data[i][2] * (1.0 - p.data[i][2])];
+    ///         assert!(grad.data[i].iter().zip(expected).all(|(a, b)| (a - b).abs() < 1e-15));
+    ///         // Softmax is invariant to a shift of its row, so the gradient sums to 0.
+    ///         assert!(grad.data[i].iter().sum::<f64>().abs() < 1e-15);
+    ///     }
+    ///
+    ///     assert!(matches!(logits.softmax_rows_backward(&matrix![1.0]), Err(MatrixError::ShapeMismatch { .. })));
+    /// }
+    /// ```
+    pub fn softmax_rows_backward(&self, upstream: &Matrix) -> Result<Matrix, MatrixError> {
+        self.check_same_shape(upstream, "softmax_rows_backward")?;
+
+        let s = self.softmax_rows();
+        let data = s.data
+            .iter()
+            .zip(upstream.data.iter())
+            .map(|(s_row, g_row)| {
+                let dot: f64 = s_row.iter().zip(g_row.iter()).map(|(s, g)| s * g).sum();
+                s_row.iter().zip(g_row.iter()).map(|(s, g)| s * (g - dot)).collect()
+            })
+            .collect();
+        Ok(Matrix { rows: self.rows, cols: self.cols, data })
+    }
+}
@@ -1,5 +1,9 @@
 use crate::matrix::*;
+use crate::matrix32::Matrix32;
+use crate::imatrix::IMatrix;
+use crate::cmatrix::CMatrix;
 use crate::errors::MatrixError;
+use crate::operations::kernels::{try_zip_rows, zip_rows};
 pub trait Add<Other = Self> {
     type Output;
 
@@ -24,14 +28,88 @@ impl Add for Matrix {
             return Err(MatrixError::DimensionMismatch);
         }
 
-        // Build a new matrix with the same dimensions as the `self` matrix (and `other` as well).
-        // It will ensure that `result` variable can be accessed in the loop below.
-        let mut result = Matrix::builder().rows(self.rows).cols(self.cols).done()?;
-        for i in 0..self.rows {
-            for j in 0..self.cols {
-                result.data[i][j] = self.data[i][j] + other.data[i][j];
+        let data = zip_rows(&self.data, &other.data, |a, b| a + b);
+        Ok(Matrix { rows: self.rows, cols: self.cols, data })
+    }
+}
+
+impl Add for Matrix32 {
+    type Output = Matrix32;
+
+    fn add(&self, other: Self) -> Result<Self::Output, MatrixError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let data = zip_rows(&self.data, &other.data, |a, b| a + b);
+        Ok(Matrix32 { rows: self.rows, cols: self.cols, data })
+    }
+}
+
+impl Add for IMatrix {
+    type Output = IMatrix;
+
+    /// Adds two integer matrices, returning `Overflow` if any sum does not fit in an `i64`.
+    fn add(&self, other: Self) -> Result<Self::Output, MatrixError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let data = try_zip_rows(&self.data, &other.data, i64::checked_add)
+            .ok_or(MatrixError::Overflow { operation: "add" })?;
+        Ok(IMatrix { rows: self.rows, cols: self.cols, data })
+    }
+}
+
+impl Add for CMatrix {
+    type Output = CMatrix;
+
+    fn add(&self, other: Self) -> Result<Self::Output, MatrixError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let data = zip_rows(&self.data, &other.data, |a, b| a + b);
+        Ok(CMatrix { rows: self.rows, cols: self.cols, data })
+    }
+}
+
+impl Matrix {
+    /// Writes `self + other` into `out`, like `add` but reusing the allocations of `out`.
+    ///
+    /// `out` is resized to the shape of `self` first (see `clone_into`), so any matrix can be passed, and a buffer of
+    /// the right shape is filled without allocating. Returns `DimensionMismatch` like `add`, leaving `out` unchanged.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, add::Add};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0; 3.0, 4.0];
+    ///     let b = matrix![0.5, -1.0; 2.0, 0.0];
+    ///
+    ///     let mut out = Matrix::new();
+    ///     a.add_into(&b, &mut out).unwrap();
+    ///     assert_eq!(out, a.add(b.clone()).unwrap());
+    ///
+    ///     let row = out.data[1].as_ptr();
+    ///     b.add_into(&b, &mut out).unwrap();
+    ///     assert_eq!(out, b.add(b.clone()).unwrap());
+    ///     assert_eq!(out.data[1].as_ptr(), row);
+    ///
+    ///     assert_eq!(a.add_into(&matrix![1.0], &mut out), Err(MatrixError::DimensionMismatch));
+    ///     assert_eq!(out, b.add(b.clone()).unwrap());
+    /// }
+    /// ```
+    pub fn add_into(&self, other: &Matrix, out: &mut Matrix) -> Result<(), MatrixError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        out.resize_for_output(self.rows, self.cols);
+        for ((result, a), b) in out.data.iter_mut().zip(self.data.iter()).zip(other.data.iter()) {
+            for ((value, x), y) in result.iter_mut().zip(a.iter()).zip(b.iter()) {
+                *value = x + y;
             }
         }
-        Ok(result)
+        Ok(())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,304 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+/// Direction of the lanes passed to the closure of `Matrix::apply_along_axis` and `Matrix::map_along_axis`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Every row, one after the other.
+    Rows,
+    /// Every column, gathered from the rows.
+    Cols,
+}
+
+impl Matrix {
+    /// Returns column `j` as a `Vec`, gathering one element from every row.
+    pub(crate) fn column_vec(&self, j: usize) -> Vec<f64> {
+        self.data.iter().map(|row| row[j]).collect()
+    }
+
+    /// Calls `f` on every row or column, in order. Columns are gathered into one buffer reused for all of them.
+    fn lanes<T>(&self, axis: Axis, mut f: impl FnMut(&[f64]) -> T) -> Vec<T> {
+        match axis {
+            Axis::Rows => self.data.iter().map(|row| f(row)).collect(),
+            Axis::Cols => {
+                let mut column = vec![0.0; self.rows];
+                (0..self.cols)
+                    .map(|j| {
+                        column.iter_mut().zip(self.data.iter()).for_each(|(x, row)| *x = row[j]);
+                        f(&column)
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Reduces every row (`Axis::Rows`) or every column (`Axis::Cols`) to a single value.
+    ///
+    /// This is the generic form of `fold_rows`, `fold_cols` and the per-axis statistics.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, axis::Axis};
+    /// fn main() {
+    ///     // 10 samples of 2 features.
+    ///     let samples = Matrix::from_rows((1..=10).map(|i| vec![i as f64, (i * i) as f64]).collect()).unwrap();
+    ///
+    ///     // 90th percentile of every column, with linear interpolation between the closest ranks.
+    ///     let percentile = |p: f64| {
+    ///         move |lane: &[f64]| {
+    ///             let mut sorted = lane.to_vec();
+    ///             sorted.sort_by(f64::total_cmp);
+    ///             let rank = p * (sorted.len() - 1) as f64;
+    ///             let (below, fraction) = (rank.floor() as usize, rank.fract());
+    ///             let above = (below + 1).min(sorted.len() - 1);
+    ///             sorted[below] + fraction * (sorted[above] - sorted[below])
+    ///         }
+    ///     };
+    ///     let p90 = samples.apply_along_axis(Axis::Cols, percentile(0.9));
+    ///     assert!((p90[0] - 9.1).abs() < 1e-12);
+    ///     assert!((p90[1] - 82.9).abs() < 1e-12);
+    ///     assert_eq!(samples.apply_along_axis(Axis::Cols, percentile(0.0)), vec![1.0, 1.0]);
+    ///
+    ///     let mean = |lane: &[f64]| lane.iter().sum::<f64>() / lane.len() as f64;
+    ///     assert_eq!(samples.apply_along_axis(Axis::Cols, mean), vec![5.5, 38.5]);
+    ///     assert_eq!(matrix![1.0, 3.0; 2.0, 6.0].apply_along_axis(Axis::Rows, mean), vec![2.0, 4.0]);
+    /// }
+    /// ```
+    pub fn apply_along_axis(&self, axis: Axis, f: impl Fn(&[f64]) -> f64) -> Vec<f64> {
+        self.lanes(axis, f)
+    }
+
+    /// Maps every row (`Axis::Rows`) or every column (`Axis::Cols`) to a new one, and builds a matrix from them:
+    /// the returned lanes are the rows or the columns of the result, so they can be longer or shorter than the input.
+    ///
+    /// All returned lanes must have the same length, otherwise it returns `DataMismatch`
+    /// (and `InvalidMatrixSize` if they are empty).
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, axis::Axis};
+    /// fn main() {
+    ///     let a = matrix![1.0, 4.0; 3.0, 2.0; 2.0, 6.0];
+    ///
+    ///     // Min and max of every column, as the two rows of a 2 x cols matrix.
+    ///     let range = a.map_along_axis(Axis::Cols, |col| {
+    ///         vec![col.iter().copied().fold(f64::INFINITY, f64::min), col.iter().copied().fold(f64::NEG_INFINITY, f64::max)]
+    ///     });
+    ///     assert_eq!(range.unwrap(), matrix![1.0, 2.0; 3.0, 6.0]);
+    ///
+    ///     let doubled = a.map_along_axis(Axis::Rows, |row| row.iter().chain(row).copied().collect());
+    ///     assert_eq!(doubled.unwrap(), matrix![1.0, 4.0, 1.0, 4.0; 3.0, 2.0, 3.0, 2.0; 2.0, 6.0, 2.0, 6.0]);
+    ///
+    ///     let ragged = a.map_along_axis(Axis::Rows, |row| row.iter().copied().take(row[0] as usize).collect());
+    ///     assert_eq!(ragged, Err(MatrixError::DataMismatch));
+    ///     assert_eq!(a.map_along_axis(Axis::Cols, |_| vec![]), Err(MatrixError::InvalidMatrixSize));
+    /// }
+    /// ```
+    pub fn map_along_axis(&self, axis: Axis, f: impl Fn(&[f64]) -> Vec<f64>) -> Result<Matrix, MatrixError> {
+        let lanes = Matrix::from_rows(self.lanes(axis, f))?;
+        match axis {
+            Axis::Rows => Ok(lanes),
+            Axis::Cols => Ok(lanes.transpose()),
+        }
+    }
+
+    /// Applies `f` to every row and builds a matrix from the returned rows.
+    ///
+    /// All returned rows must have the same length, otherwise it returns `DataMismatch`
+    /// (and `InvalidMatrixSize` if they are empty).
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let logits = matrix![1.0, 2.0, 3.0; 0.0, 0.0, 0.0];
+    ///
+    ///     let softmax = logits.map_rows(|row| {
+    ///         let max = row.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    ///         let exps: Vec<f64> = row.iter().map(|x| (x - max).exp()).collect();
+    ///         let sum: f64 = exps.iter().sum();
+    ///         exps.iter().map(|e| e / sum).collect()
+    ///     }).unwrap();
+    ///
+    ///     for row in &softmax.data {
+    ///         assert!((row.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    ///     }
+    /// }
+    /// ```
+    pub fn map_rows(&self, f: impl Fn(&[f64]) -> Vec<f64>) -> Result<Matrix, MatrixError> {
+        self.map_along_axis(Axis::Rows, f)
+    }
+
+    /// Applies `f` to every column and builds a matrix from the returned columns.
+    ///
+    /// All returned columns must have the same length, otherwise it returns `DataMismatch`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let scores = matrix![30.0, 1.0; 10.0, 3.0; 20.0, 2.0];
+    ///
+    ///     // Rank of every value inside its column (0 = smallest).
+    ///     let ranks = scores.map_cols(|col| {
+    ///         col.iter().map(|x| col.iter().filter(|y| *y < x).count() as f64).collect()
+    ///     }).unwrap();
+    ///
+    ///     assert_eq!(ranks, matrix![2.0, 0.0; 0.0, 2.0; 1.0, 1.0]);
+    /// }
+    /// ```
+    pub fn map_cols(&self, f: impl Fn(&[f64]) -> Vec<f64>) -> Result<Matrix, MatrixError> {
+        self.map_along_axis(Axis::Cols, f)
+    }
+
+    /// Reduces every row to a single value.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0; 3.0, 4.0];
+    ///
+    ///     assert_eq!(a.fold_rows(|row| row.iter().sum()), vec![3.0, 7.0]);
+    ///     assert_eq!(a.fold_cols(|col| col.iter().sum()), vec![4.0, 6.0]);
+    /// }
+    /// ```
+    pub fn fold_rows(&self, f: impl Fn(&[f64]) -> f64) -> Vec<f64> {
+        self.apply_along_axis(Axis::Rows, f)
+    }
+
+    /// Reduces every column to a single value, see `fold_rows`.
+    pub fn fold_cols(&self, f: impl Fn(&[f64]) -> f64) -> Vec<f64> {
+        self.apply_along_axis(Axis::Cols, f)
+    }
+
+    /// For each row, the column index of its maximum.
+    ///
+    /// Ties go to the first occurrence and `NaN` elements are skipped; a row containing only `NaN` gives 0.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let scores = matrix![
+    ///         0.1, 0.7, 0.2;
+    ///         -3.0, -1.0, -2.0;
+    ///         0.5, 0.5, 0.1;
+    ///         f64::NAN, 0.2, 0.9
+    ///     ];
+    ///
+    ///     assert_eq!(scores.argmax_rows(), vec![1, 1, 0, 2]);
+    ///     assert_eq!(scores.argmin_rows(), vec![0, 0, 2, 1]);
+    ///     assert_eq!(scores.argmax_cols(), vec![2, 0, 3]);
+    ///     assert_eq!(scores.argmin_cols(), vec![1, 1, 1]);
+    ///     assert_eq!(matrix![f64::NAN, f64::NAN].argmax_rows(), vec![0]);
+    /// }
+    /// ```
+    pub fn argmax_rows(&self) -> Vec<usize> {
+        self.data.iter().map(|row| arg_best(row, |x, best| x > best)).collect()
+    }
+
+    /// For each row, the column index of its minimum, see `argmax_rows`.
+    pub fn argmin_rows(&self) -> Vec<usize> {
+        self.data.iter().map(|row| arg_best(row, |x, best| x < best)).collect()
+    }
+
+    /// For each column, the row index of its maximum, see `argmax_rows`.
+    pub fn argmax_cols(&self) -> Vec<usize> {
+        self.lanes(Axis::Cols, |col| arg_best(col, |x, best| x > best))
+    }
+
+    /// For each column, the row index of its minimum, see `argmax_rows`.
+    pub fn argmin_cols(&self) -> Vec<usize> {
+        self.lanes(Axis::Cols, |col| arg_best(col, |x, best| x < best))
+    }
+
+    /// Iterates over every group of `size` consecutive rows, as matrices, like `slice::windows`.
+    ///
+    /// A `size` of zero returns `InvalidMatrixSize`, and a `size` larger than `rows` yields nothing.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     // One row per timestep.
+    ///     let series = matrix![1.0, 10.0; 2.0, 20.0; 3.0, 30.0; 4.0, 40.0; 5.0, 50.0];
+    ///
+    ///     let windows: Vec<Matrix> = series.row_windows(3).unwrap().collect();
+    ///     assert_eq!(windows.len(), 3);
+    ///     assert!(windows.iter().all(|w| (w.rows, w.cols) == (3, 2)));
+    ///     assert_eq!(windows[1], matrix![2.0, 20.0; 3.0, 30.0; 4.0, 40.0]);
+    ///
+    ///     assert_eq!(series.row_windows(6).unwrap().count(), 0);
+    ///     assert!(series.row_windows(0).is_err());
+    ///
+    ///     let cols: Vec<Matrix> = series.col_windows(2).unwrap().collect();
+    ///     assert_eq!(cols, vec![series.clone()]);
+    /// }
+    /// ```
+    pub fn row_windows(&self, size: usize) -> Result<impl Iterator<Item = Matrix> + '_, MatrixError> {
+        check_group_size(size)?;
+        Ok(self.data.windows(size).map(rows_to_matrix))
+    }
+
+    /// Iterates over groups of `size` consecutive rows that don't overlap, like `slice::chunks`.
+    ///
+    /// The last chunk has fewer rows when `size` does not divide `rows`. A `size` of zero returns `InvalidMatrixSize`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let series = matrix![1.0; 2.0; 3.0; 4.0; 5.0];
+    ///
+    ///     let shapes: Vec<(usize, usize)> = series.row_chunks(2).unwrap().map(|c| (c.rows, c.cols)).collect();
+    ///     assert_eq!(shapes, vec![(2, 1), (2, 1), (1, 1)]);
+    ///     assert_eq!(series.row_chunks(5).unwrap().count(), 1);
+    ///
+    ///     let wide = series.transpose();
+    ///     let chunks: Vec<Matrix> = wide.col_chunks(2).unwrap().collect();
+    ///     assert_eq!(chunks, vec![matrix![1.0, 2.0], matrix![3.0, 4.0], matrix![5.0]]);
+    ///     assert_eq!(wide.col_windows(4).unwrap().count(), 2);
+    ///     assert!(wide.col_chunks(0).is_err());
+    /// }
+    /// ```
+    pub fn row_chunks(&self, size: usize) -> Result<impl Iterator<Item = Matrix> + '_, MatrixError> {
+        check_group_size(size)?;
+        Ok(self.data.chunks(size).map(rows_to_matrix))
+    }
+
+    /// Iterates over every group of `size` consecutive columns, see `row_windows`.
+    pub fn col_windows(&self, size: usize) -> Result<impl Iterator<Item = Matrix> + '_, MatrixError> {
+        check_group_size(size)?;
+        let count = (self.cols + 1).saturating_sub(size);
+        Ok((0..count).map(move |start| self.col_range(start, start + size)))
+    }
+
+    /// Iterates over groups of `size` consecutive columns that don't overlap, see `row_chunks`.
+    pub fn col_chunks(&self, size: usize) -> Result<impl Iterator<Item = Matrix> + '_, MatrixError> {
+        check_group_size(size)?;
+        Ok((0..self.cols).step_by(size).map(move |start| self.col_range(start, (start + size).min(self.cols))))
+    }
+
+    fn col_range(&self, start: usize, end: usize) -> Matrix {
+        let data = self.data.iter().map(|row| row[start..end].to_vec()).collect();
+        Matrix { rows: self.rows, cols: end - start, data }
+    }
+}
+
+/// Index of the first element that no later element beats, skipping `NaN` (0 if everything is `NaN`).
+fn arg_best(values: &[f64], beats: impl Fn(f64, f64) -> bool) -> usize {
+    let mut best: Option<(usize, f64)> = None;
+    for (i, &x) in values.iter().enumerate() {
+        if x.is_nan() {
+            continue;
+        }
+        if best.is_none_or(|(_, b)| beats(x, b)) {
+            best = Some((i, x));
+        }
+    }
+    best.map_or(0, |(i, _)| i)
+}
+
+fn check_group_size(size: usize) -> Result<(), MatrixError> {
+    if size == 0 {
+        return Err(MatrixError::InvalidMatrixSize);
+    }
+    Ok(())
+}
+
+fn rows_to_matrix(rows: &[Vec<f64>]) -> Matrix {
+    Matrix { rows: rows.len(), cols: rows[0].len(), data: rows.to_vec() }
+}
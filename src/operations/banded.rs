@@ -0,0 +1,118 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+impl Matrix {
+    /// Returns the (lower, upper) bandwidths of a square matrix: the largest `i - j` and `j - i`
+    /// over the elements `(i, j)` with `|a_ij| > eps`.
+    ///
+    /// A diagonal matrix is (0, 0), a tridiagonal one at most (1, 1). Returns `NotSquare` for non-square matrices.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![
+    ///         1.0, 2.0, 4.0, 0.0;
+    ///         3.0, 1.0, 2.0, 0.0;
+    ///         1e-12, 3.0, 1.0, 5.0;
+    ///         0.0, 0.0, 3.0, 1.0
+    ///     ];
+    ///
+    ///     assert_eq!(a.bandwidth(1e-9).unwrap(), (1, 2));
+    ///     assert_eq!(a.bandwidth(0.0).unwrap(), (2, 2));
+    ///     assert_eq!(Matrix::identity(3).unwrap().bandwidth(0.0).unwrap(), (0, 0));
+    ///     assert!(!a.is_tridiagonal(1e-9));
+    ///     assert!(matrix![1.0, 2.0].bandwidth(0.0).is_err());
+    /// }
+    /// ```
+    pub fn bandwidth(&self, eps: f64) -> Result<(usize, usize), MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        let (mut lower, mut upper) = (0, 0);
+        for (i, row) in self.data.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                if value.abs() > eps {
+                    lower = lower.max(i.saturating_sub(j));
+                    upper = upper.max(j.saturating_sub(i));
+                }
+            }
+        }
+        Ok((lower, upper))
+    }
+
+    /// Returns true if the matrix is square and every element off the three central diagonals is within `eps` of 0.
+    pub fn is_tridiagonal(&self, eps: f64) -> bool {
+        self.bandwidth(eps).is_ok_and(|(lower, upper)| lower <= 1 && upper <= 1)
+    }
+
+    /// Solves `A X = B` for a tridiagonal `A` (`self`) with the Thomas algorithm, in `O(n)` per column of `B`.
+    ///
+    /// The structure is checked first, elements off the three diagonals must be exactly 0 or it returns
+    /// `InvalidOperation`. `NotSquare` and `DimensionMismatch` are returned like `solve`.
+    /// The algorithm does not pivot, so it is meant for the diagonally dominant or symmetric positive definite
+    /// systems that discretizations produce; a zero pivot returns `Singular`, use `solve` for other systems.
+    /// # Example
+    /// ```
+    /// # use matrix::{Matrix, MatrixError, matrix};
+    /// fn main() {
+    ///     // Second difference operator of a 1D Poisson problem: -x[i-1] + 2 x[i] - x[i+1].
+    ///     let n = 50;
+    ///     let mut a = Matrix::zeros(n, n).unwrap();
+    ///     for i in 0..n {
+    ///         a.data[i][i] = 2.0;
+    ///         if i > 0 {
+    ///             a.data[i][i - 1] = -1.0;
+    ///             a.data[i - 1][i] = -1.0;
+    ///         }
+    ///     }
+    ///     let b = Matrix::from_rows((0..n).map(|i| vec![(i as f64 / n as f64).sin(), 1.0]).collect()).unwrap();
+    ///
+    ///     let thomas = a.solve_tridiagonal(&b).unwrap();
+    ///     assert!(thomas.diff(&a.solve(&b).unwrap(), 1e-9).is_equal());
+    ///
+    ///     assert!(matches!(matrix![1.0, 0.0, 1.0; 0.0, 1.0, 0.0; 0.0, 0.0, 1.0].solve_tridiagonal(&matrix![1.0; 1.0; 1.0]),
+    ///         Err(MatrixError::InvalidOperation(_))));
+    ///     assert_eq!(
+    ///         matrix![0.0, 1.0; 1.0, 0.0].solve_tridiagonal(&matrix![1.0; 1.0]),
+    ///         Err(MatrixError::Singular { operation: "solve_tridiagonal" })
+    ///     );
+    /// }
+    /// ```
+    pub fn solve_tridiagonal(&self, b: &Matrix) -> Result<Matrix, MatrixError> {
+        if !self.bandwidth(0.0).map(|(lower, upper)| lower <= 1 && upper <= 1)? {
+            return Err(MatrixError::InvalidOperation("solve_tridiagonal requires a tridiagonal matrix"));
+        }
+        if b.rows != self.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let n = self.rows;
+        let a = &self.data;
+        // Forward sweep: `upper[i]` is the modified super-diagonal and `pivots[i]` the modified diagonal.
+        let mut upper = vec![0.0; n];
+        let mut pivots = vec![0.0; n];
+        for i in 0..n {
+            let below = if i > 0 { a[i][i - 1] * upper[i - 1] } else { 0.0 };
+            pivots[i] = a[i][i] - below;
+            if pivots[i] == 0.0 {
+                return Err(MatrixError::Singular { operation: "solve_tridiagonal" });
+            }
+            if i + 1 < n {
+                upper[i] = a[i][i + 1] / pivots[i];
+            }
+        }
+
+        let mut x = b.clone();
+        for j in 0..b.cols {
+            x.data[0][j] /= pivots[0];
+            for i in 1..n {
+                x.data[i][j] = (x.data[i][j] - a[i][i - 1] * x.data[i - 1][j]) / pivots[i];
+            }
+            for i in (0..n - 1).rev() {
+                x.data[i][j] -= upper[i] * x.data[i + 1][j];
+            }
+        }
+        Ok(x)
+    }
+}
@@ -0,0 +1,328 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use crate::operations::mul::Mul;
+
+/// Element-wise sum of equally-shaped matrices.
+///
+/// An empty slice returns `InvalidOperation`, and a matrix with a different shape than the first one
+/// returns `ShapeMismatchAt` with its index.
+/// # Example
+/// ```
+/// # use matrix::{matrix, Matrix, MatrixError, batch::sum_all};
+/// fn main() {
+///     let a = matrix![1.0, 2.0];
+///     let b = matrix![10.0, 20.0];
+///
+///     assert_eq!(sum_all(&[a.clone(), b.clone(), a.clone()]).unwrap(), matrix![12.0, 24.0]);
+///     assert_eq!(
+///         sum_all(&[a, b, matrix![1.0; 2.0]]),
+///         Err(MatrixError::ShapeMismatchAt { operation: "sum_all", index: 2, expected: (1, 2), found: (2, 1) })
+///     );
+///     assert!(sum_all(&[]).is_err());
+/// }
+/// ```
+pub fn sum_all(matrices: &[Matrix]) -> Result<Matrix, MatrixError> {
+    accumulate(matrices, "sum_all")
+}
+
+/// Element-wise mean of equally-shaped matrices, see `sum_all`.
+/// # Example
+/// ```
+/// # use matrix::{matrix, Matrix, batch::mean_all};
+/// fn main() {
+///     let samples = [matrix![1.0, 2.0], matrix![3.0, 6.0]];
+///
+///     assert_eq!(mean_all(&samples).unwrap(), matrix![2.0, 4.0]);
+/// }
+/// ```
+pub fn mean_all(matrices: &[Matrix]) -> Result<Matrix, MatrixError> {
+    let n = matrices.len() as f64;
+    Ok(accumulate(matrices, "mean_all")?.scale(1.0 / n))
+}
+
+fn accumulate(matrices: &[Matrix], operation: &'static str) -> Result<Matrix, MatrixError> {
+    let (first, rest) = matrices
+        .split_first()
+        .ok_or(MatrixError::InvalidOperation("batch operations require at least one matrix"))?;
+
+    let mut result = first.clone();
+    for (index, matrix) in rest.iter().enumerate() {
+        if matrix.rows != first.rows || matrix.cols != first.cols {
+            return Err(MatrixError::ShapeMismatchAt {
+                operation,
+                index: index + 1,
+                expected: (first.rows, first.cols),
+                found: (matrix.rows, matrix.cols),
+            });
+        }
+        for (row, other) in result.data.iter_mut().zip(matrix.data.iter()) {
+            for (value, x) in row.iter_mut().zip(other.iter()) {
+                *value += x;
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Multiplies a chain of matrices, `matrices[0] * matrices[1] * ... * matrices[n - 1]`.
+///
+/// Every adjacent pair is validated first: if `matrices[i]` does not have as many rows as `matrices[i - 1]`
+/// has columns, it returns `ShapeMismatchAt` with index `i`. An empty slice returns `InvalidOperation`.
+///
+/// The products are then associated in the order that needs the fewest scalar multiplications,
+/// chosen by `chain_order`.
+/// # Example
+/// ```
+/// # use matrix::{matrix, Matrix, MatrixError, batch::product_chain, mul::Mul};
+/// fn main() {
+///     let a = Matrix::builder().rows(10).cols(100).data(vec![vec![1.0; 100]; 10]).done().unwrap();
+///     let b = Matrix::builder().rows(100).cols(5).data(vec![vec![0.5; 5]; 100]).done().unwrap();
+///     let c = Matrix::builder().rows(5).cols(50).data(vec![vec![2.0; 50]; 5]).done().unwrap();
+///
+///     let expected = a.mul(&b).unwrap().mul(&c).unwrap();
+///     assert_eq!(product_chain(&[a.clone(), b.clone(), c.clone()]).unwrap(), expected);
+///
+///     // The middle pair (b, b) does not line up.
+///     assert_eq!(
+///         product_chain(&[a, b.clone(), b, c]),
+///         Err(MatrixError::ShapeMismatchAt { operation: "product_chain", index: 2, expected: (5, 5), found: (100, 5) })
+///     );
+/// }
+/// ```
+pub fn product_chain(matrices: &[Matrix]) -> Result<Matrix, MatrixError> {
+    let matrices: Vec<&Matrix> = matrices.iter().collect();
+    chain_product(&matrices, "product_chain")
+}
+
+/// Multiplies a chain of borrowed matrices, like `product_chain` but without owning them, associating the products
+/// in the order given by `chain_order`.
+///
+/// Errors are those of `product_chain`, with `multiply_chain` as the operation.
+/// # Example
+/// ```
+/// # use matrix::{Matrix, batch::{chain_order, multiply_chain}};
+/// fn main() {
+///     let a = Matrix::from_seed(50, 10, 1).unwrap();
+///     let b = Matrix::from_seed(10, 40, 2).unwrap();
+///     let c = Matrix::from_seed(40, 30, 3).unwrap();
+///     let d = Matrix::from_seed(30, 5, 4).unwrap();
+///
+///     // Left to right, counted: 50 * 10 * 40 + 50 * 40 * 30 + 50 * 30 * 5 multiplications.
+///     let (mut product, mut left_to_right) = (a.clone(), 0);
+///     for m in [&b, &c, &d] {
+///         let (next, count) = product.mul_counted(m).unwrap();
+///         (product, left_to_right) = (next, left_to_right + count.mults);
+///     }
+///     assert_eq!(left_to_right, 87_500);
+///
+///     // The order chosen by `chain_order`, replayed and counted the same way: a (b (c d)).
+///     let chain = [&a, &b, &c, &d];
+///     let dims: Vec<(usize, usize)> = chain.iter().map(|m| (m.rows, m.cols)).collect();
+///     let (order, flops) = chain_order(&dims).unwrap();
+///     assert_eq!(order, vec![(2, 3), (1, 3), (0, 3)]);
+///     assert_eq!(flops, 10_500);
+///
+///     // `partial[i]` holds the product of the chain from `i` to `ends[i]`.
+///     let mut partial: Vec<Option<Matrix>> = chain.iter().map(|&m| Some(m.clone())).collect();
+///     let mut ends: Vec<usize> = (0..chain.len()).collect();
+///     let mut optimized = 0;
+///     for &(i, j) in &order {
+///         let right = partial[ends[i] + 1].take().unwrap();
+///         let (product, count) = partial[i].as_ref().unwrap().mul_counted(&right).unwrap();
+///         (partial[i], ends[i]) = (Some(product), j);
+///         optimized += count.mults;
+///     }
+///     assert_eq!(optimized, flops);
+///     assert!(optimized < left_to_right / 8);
+///
+///     let result = multiply_chain(&chain).unwrap();
+///     assert!(result.diff(&product, 1e-9).is_equal());
+/// }
+/// ```
+pub fn multiply_chain(matrices: &[&Matrix]) -> Result<Matrix, MatrixError> {
+    chain_product(matrices, "multiply_chain")
+}
+
+/// Chooses how to associate the product of a chain of matrices with shapes `dims` to need the fewest scalar
+/// multiplications, with the classic `O(n^3)` dynamic programming algorithm.
+///
+/// Returns the products in the order they are computed, each as the `(i, j)` range of the chain it multiplies
+/// together, the last one being `(0, n - 1)`, and the number of scalar multiplications of the whole chain.
+/// A chain of one matrix needs no product. Returns `InvalidOperation` for an empty chain, `ShapeMismatchAt` like
+/// `product_chain` for shapes that don't line up and `Overflow` if the count does not fit in a `u64`.
+/// # Example
+/// ```
+/// # use matrix::{MatrixError, batch::chain_order};
+/// fn main() {
+///     // (a b) c costs 10 * 100 * 5 + 10 * 5 * 50 = 7500, a (b c) costs 100 * 5 * 50 + 10 * 100 * 50 = 75000.
+///     assert_eq!(chain_order(&[(10, 100), (100, 5), (5, 50)]), Ok((vec![(0, 1), (0, 2)], 7500)));
+///     assert_eq!(chain_order(&[(50, 10), (10, 40), (40, 30), (30, 5)]), Ok((vec![(2, 3), (1, 3), (0, 3)], 10_500)));
+///     assert_eq!(chain_order(&[(3, 4)]), Ok((vec![], 0)));
+///
+///     assert!(matches!(chain_order(&[]), Err(MatrixError::InvalidOperation(_))));
+///     assert_eq!(
+///         chain_order(&[(2, 3), (4, 5)]),
+///         Err(MatrixError::ShapeMismatchAt { operation: "chain_order", index: 1, expected: (3, 5), found: (4, 5) })
+///     );
+///     let huge = usize::MAX / 4;
+///     assert_eq!(chain_order(&[(huge, huge), (huge, huge)]), Err(MatrixError::Overflow { operation: "chain_order" }));
+/// }
+/// ```
+pub fn chain_order(dims: &[(usize, usize)]) -> Result<(Vec<(usize, usize)>, u64), MatrixError> {
+    check_chain(dims, "chain_order")?;
+    let (split, flops) = split_table(dims);
+    let flops = u64::try_from(flops).map_err(|_| MatrixError::Overflow { operation: "chain_order" })?;
+
+    let mut order = Vec::with_capacity(dims.len() - 1);
+    push_order(&split, 0, dims.len() - 1, &mut order);
+    Ok((order, flops))
+}
+
+fn push_order(split: &[Vec<usize>], i: usize, j: usize, order: &mut Vec<(usize, usize)>) {
+    if i < j {
+        push_order(split, i, split[i][j], order);
+        push_order(split, split[i][j] + 1, j, order);
+        order.push((i, j));
+    }
+}
+
+fn chain_product(matrices: &[&Matrix], operation: &'static str) -> Result<Matrix, MatrixError> {
+    let dims: Vec<(usize, usize)> = matrices.iter().map(|m| (m.rows, m.cols)).collect();
+    check_chain(&dims, operation)?;
+
+    let (split, _) = split_table(&dims);
+    multiply_range(matrices, &split, 0, matrices.len() - 1)
+}
+
+fn check_chain(dims: &[(usize, usize)], operation: &'static str) -> Result<(), MatrixError> {
+    if dims.is_empty() {
+        return Err(MatrixError::InvalidOperation("batch operations require at least one matrix"));
+    }
+    for (index, pair) in dims.windows(2).enumerate() {
+        if pair[0].1 != pair[1].0 {
+            return Err(MatrixError::ShapeMismatchAt {
+                operation,
+                index: index + 1,
+                expected: (pair[0].1, pair[1].1),
+                found: pair[1],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Multiplies corresponding pairs, `a[i] * b[i]` for every `i`.
+///
+/// Every pair is validated before anything is multiplied: lists of different lengths or an empty list
+/// return `InvalidOperation`, and a pair that does not line up returns `ShapeMismatchAt` with its index.
+/// With the `parallel` feature the batch is split across threads.
+/// # Example
+/// ```
+/// # use matrix::{matrix, Matrix, MatrixError, batch::batch_mul, mul::Mul};
+/// fn main() {
+///     let a = vec![matrix![1.0, 2.0; 3.0, 4.0], matrix![1.0, 0.0, 2.0]];
+///     let b = vec![matrix![0.0, 1.0; 1.0, 0.0], matrix![1.0; 2.0; 3.0]];
+///
+///     let expected: Vec<Matrix> = a.iter().zip(&b).map(|(x, y)| x.mul(y).unwrap()).collect();
+///     assert_eq!(batch_mul(&a, &b).unwrap(), expected);
+///
+///     assert!(batch_mul(&a, &b[..1]).is_err());
+///     assert_eq!(
+///         batch_mul(&a, &[b[0].clone(), b[0].clone()]),
+///         Err(MatrixError::ShapeMismatchAt { operation: "batch_mul", index: 1, expected: (3, 2), found: (2, 2) })
+///     );
+/// }
+/// ```
+/// ```
+/// # use matrix::{Matrix, batch::batch_mul, mul::Mul};
+/// fn main() {
+///     // A larger batch, checked against a loop of `mul` calls.
+///     let a: Vec<Matrix> = (0..40).map(|i| Matrix::from_seed(4, 8, i).unwrap()).collect();
+///     let b: Vec<Matrix> = (0..40).map(|i| Matrix::from_seed(8, 3, 100 + i).unwrap()).collect();
+///
+///     let products = batch_mul(&a, &b).unwrap();
+///     assert_eq!(products.len(), 40);
+///     for ((x, y), product) in a.iter().zip(&b).zip(&products) {
+///         assert_eq!(*product, x.mul(y).unwrap());
+///     }
+/// }
+/// ```
+pub fn batch_mul(a: &[Matrix], b: &[Matrix]) -> Result<Vec<Matrix>, MatrixError> {
+    if a.is_empty() {
+        return Err(MatrixError::InvalidOperation("batch operations require at least one matrix"));
+    }
+    if a.len() != b.len() {
+        return Err(MatrixError::InvalidOperation("batch_mul requires as many left as right matrices"));
+    }
+    for (index, (x, y)) in a.iter().zip(b.iter()).enumerate() {
+        if x.cols != y.rows {
+            return Err(MatrixError::ShapeMismatchAt {
+                operation: "batch_mul",
+                index,
+                expected: (x.cols, y.cols),
+                found: (y.rows, y.cols),
+            });
+        }
+    }
+
+    multiply_pairs(a, b)
+}
+
+#[cfg(not(feature = "parallel"))]
+fn multiply_pairs(a: &[Matrix], b: &[Matrix]) -> Result<Vec<Matrix>, MatrixError> {
+    a.iter().zip(b.iter()).map(|(x, y)| x.mul(y)).collect()
+}
+
+/// Splits the batch in one contiguous chunk per available thread, keeping the results in order.
+#[cfg(feature = "parallel")]
+fn multiply_pairs(a: &[Matrix], b: &[Matrix]) -> Result<Vec<Matrix>, MatrixError> {
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk = a.len().div_ceil(threads);
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = a
+            .chunks(chunk)
+            .zip(b.chunks(chunk))
+            .map(|(a, b)| scope.spawn(move || a.iter().zip(b.iter()).map(|(x, y)| x.mul(y)).collect::<Result<Vec<_>, _>>()))
+            .collect();
+
+        let mut products = Vec::with_capacity(a.len());
+        for handle in handles {
+            products.extend(handle.join().expect("batch_mul worker panicked")?);
+        }
+        Ok(products)
+    })
+}
+
+/// Returns `split[i][j]`, the best place to split the product of the chain `i..=j`, and the number of scalar
+/// multiplications of the whole chain.
+fn split_table(dims: &[(usize, usize)]) -> (Vec<Vec<usize>>, u128) {
+    let n = dims.len();
+    let sizes: Vec<u128> = std::iter::once(dims[0].0).chain(dims.iter().map(|d| d.1)).map(|d| d as u128).collect();
+
+    let mut cost = vec![vec![0u128; n]; n];
+    let mut split = vec![vec![0; n]; n];
+    for length in 1..n {
+        for i in 0..n - length {
+            let j = i + length;
+            cost[i][j] = u128::MAX;
+            for k in i..j {
+                let flops = sizes[i].saturating_mul(sizes[k + 1]).saturating_mul(sizes[j + 1]);
+                let total = cost[i][k].saturating_add(cost[k + 1][j]).saturating_add(flops);
+                if total < cost[i][j] {
+                    cost[i][j] = total;
+                    split[i][j] = k;
+                }
+            }
+        }
+    }
+    (split, cost[0][n - 1])
+}
+
+fn multiply_range(matrices: &[&Matrix], split: &[Vec<usize>], i: usize, j: usize) -> Result<Matrix, MatrixError> {
+    if i == j {
+        return Ok(matrices[i].clone());
+    }
+    let k = split[i][j];
+    multiply_range(matrices, split, i, k)?.mul(&multiply_range(matrices, split, k + 1, j)?)
+}
@@ -0,0 +1,104 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+impl Matrix {
+    /// Splits the matrix into `n` owned blocks of consecutive rows, ready to be moved into threads.
+    ///
+    /// Block sizes differ by at most one row, the first blocks being the larger ones. If `n` is larger than
+    /// `rows` there is one block per row, since a block can't be empty. An `n` of zero returns `InvalidMatrixSize`.
+    /// `merge_row_blocks` puts the blocks back together.
+    ///
+    /// `Matrix` is `Send + Sync`, so a matrix that every thread reads can also be shared by reference
+    /// with `std::thread::scope`, or wrapped in an `Arc`.
+    /// # Example
+    /// ```
+    /// # use matrix::{Matrix, mul::Mul};
+    /// use std::sync::Arc;
+    /// use std::thread;
+    ///
+    /// fn main() {
+    ///     let a = Matrix::from_seed(10, 6, 1).unwrap();
+    ///     let b = Arc::new(Matrix::from_seed(6, 4, 2).unwrap());
+    ///
+    ///     // Every thread multiplies its block of rows of `a` by the shared `b`.
+    ///     let handles: Vec<_> = a
+    ///         .par_chunks_rows(4)
+    ///         .unwrap()
+    ///         .into_iter()
+    ///         .map(|block| {
+    ///             let b = Arc::clone(&b);
+    ///             thread::spawn(move || block.mul(&*b).unwrap())
+    ///         })
+    ///         .collect();
+    ///     let blocks: Vec<Matrix> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    ///
+    ///     assert_eq!(blocks.iter().map(|m| m.rows).collect::<Vec<_>>(), vec![3, 3, 2, 2]);
+    ///     assert_eq!(Matrix::merge_row_blocks(blocks).unwrap(), a.mul(&*b).unwrap());
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0; 2.0];
+    ///
+    ///     assert_eq!(a.par_chunks_rows(8).unwrap(), vec![matrix![1.0], matrix![2.0]]);
+    ///     assert!(a.par_chunks_rows(0).is_err());
+    /// }
+    /// ```
+    pub fn par_chunks_rows(&self, n: usize) -> Result<Vec<Matrix>, MatrixError> {
+        if n == 0 {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+
+        let n = n.min(self.rows);
+        let (size, larger) = (self.rows / n, self.rows % n);
+        let mut start = 0;
+        let blocks = (0..n)
+            .map(|i| {
+                let end = start + size + usize::from(i < larger);
+                let data = self.data[start..end].to_vec();
+                start = end;
+                Matrix { rows: data.len(), cols: self.cols, data }
+            })
+            .collect();
+        Ok(blocks)
+    }
+
+    /// Stacks row blocks back into one matrix, in the order of `blocks`, moving their rows without copying them.
+    ///
+    /// Every block must have the same number of columns as the first one, otherwise it returns `ShapeMismatchAt`
+    /// with the index of the block. An empty `blocks` returns `InvalidOperation`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let blocks = vec![matrix![1.0, 2.0], matrix![3.0, 4.0; 5.0, 6.0]];
+    ///     assert_eq!(Matrix::merge_row_blocks(blocks).unwrap(), matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0]);
+    ///
+    ///     assert_eq!(
+    ///         Matrix::merge_row_blocks(vec![matrix![1.0, 2.0], matrix![3.0; 4.0]]),
+    ///         Err(MatrixError::ShapeMismatchAt { operation: "merge_row_blocks", index: 1, expected: (2, 2), found: (2, 1) })
+    ///     );
+    ///     assert!(Matrix::merge_row_blocks(vec![]).is_err());
+    /// }
+    /// ```
+    pub fn merge_row_blocks(blocks: Vec<Matrix>) -> Result<Matrix, MatrixError> {
+        let cols = blocks
+            .first()
+            .ok_or(MatrixError::InvalidOperation("merge_row_blocks requires at least one block"))?
+            .cols;
+        for (index, block) in blocks.iter().enumerate() {
+            if block.cols != cols {
+                return Err(MatrixError::ShapeMismatchAt {
+                    operation: "merge_row_blocks",
+                    index,
+                    expected: (block.rows, cols),
+                    found: (block.rows, block.cols),
+                });
+            }
+        }
+
+        let data: Vec<Vec<f64>> = blocks.into_iter().flat_map(|block| block.data).collect();
+        Ok(Matrix { rows: data.len(), cols, data })
+    }
+}
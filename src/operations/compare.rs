@@ -0,0 +1,217 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+/// Converts a comparison result to the 1.0/0.0 mask convention.
+fn mask(condition: bool) -> f64 {
+    if condition { 1.0 } else { 0.0 }
+}
+
+impl Matrix {
+    fn compare(&self, other: &Matrix, f: impl Fn(f64, f64) -> bool) -> Result<Matrix, MatrixError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let data = self.data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(a, b)| a.iter().zip(b.iter()).map(|(&x, &y)| mask(f(x, y))).collect())
+            .collect();
+        Ok(Matrix { rows: self.rows, cols: self.cols, data })
+    }
+
+    fn compare_scalar(&self, f: impl Fn(f64) -> bool) -> Matrix {
+        let data = self.data
+            .iter()
+            .map(|row| row.iter().map(|&x| mask(f(x))).collect())
+            .collect();
+        Matrix { rows: self.rows, cols: self.cols, data }
+    }
+
+    /// Element-wise `self > other`, as a same-shaped matrix of 1.0 (true) and 0.0 (false).
+    ///
+    /// All the comparison methods follow IEEE semantics: any comparison involving `NaN` is false.
+    /// Different shapes return `DimensionMismatch`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 5.0; f64::NAN, 2.0];
+    ///     let b = matrix![2.0, 3.0; 0.0, 2.0];
+    ///
+    ///     assert_eq!(a.gt(&b).unwrap(), matrix![0.0, 1.0; 0.0, 0.0]);
+    ///     assert_eq!(a.ge(&b).unwrap(), matrix![0.0, 1.0; 0.0, 1.0]);
+    ///     assert_eq!(a.lt(&b).unwrap(), matrix![1.0, 0.0; 0.0, 0.0]);
+    ///     assert_eq!(a.le(&b).unwrap(), matrix![1.0, 0.0; 0.0, 1.0]);
+    /// }
+    /// ```
+    pub fn gt(&self, other: &Matrix) -> Result<Matrix, MatrixError> {
+        self.compare(other, |x, y| x > y)
+    }
+
+    /// Element-wise `self < other`, see `gt`.
+    pub fn lt(&self, other: &Matrix) -> Result<Matrix, MatrixError> {
+        self.compare(other, |x, y| x < y)
+    }
+
+    /// Element-wise `self >= other`, see `gt`.
+    pub fn ge(&self, other: &Matrix) -> Result<Matrix, MatrixError> {
+        self.compare(other, |x, y| x >= y)
+    }
+
+    /// Element-wise `self <= other`, see `gt`.
+    pub fn le(&self, other: &Matrix) -> Result<Matrix, MatrixError> {
+        self.compare(other, |x, y| x <= y)
+    }
+
+    /// Element-wise approximate equality, `|self - other| <= eps`, see `gt`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0];
+    ///     let b = matrix![1.0 + 1e-12, 2.5];
+    ///
+    ///     assert_eq!(a.eq_elem(&b, 1e-9).unwrap(), matrix![1.0, 0.0]);
+    /// }
+    /// ```
+    pub fn eq_elem(&self, other: &Matrix, eps: f64) -> Result<Matrix, MatrixError> {
+        self.compare(other, |x, y| (x - y).abs() <= eps)
+    }
+
+    /// Element-wise `self > k`, as a matrix of 1.0 and 0.0.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![-1.0, 0.0, 3.0];
+    ///
+    ///     assert_eq!(a.gt_scalar(0.0), matrix![0.0, 0.0, 1.0]);
+    ///     assert_eq!(a.le_scalar(0.0), matrix![1.0, 1.0, 0.0]);
+    /// }
+    /// ```
+    pub fn gt_scalar(&self, k: f64) -> Matrix {
+        self.compare_scalar(|x| x > k)
+    }
+
+    /// Element-wise `self < k`, see `gt_scalar`.
+    pub fn lt_scalar(&self, k: f64) -> Matrix {
+        self.compare_scalar(|x| x < k)
+    }
+
+    /// Element-wise `self >= k`, see `gt_scalar`.
+    pub fn ge_scalar(&self, k: f64) -> Matrix {
+        self.compare_scalar(|x| x >= k)
+    }
+
+    /// Element-wise `self <= k`, see `gt_scalar`.
+    pub fn le_scalar(&self, k: f64) -> Matrix {
+        self.compare_scalar(|x| x <= k)
+    }
+
+    /// Uses `self` as a mask: takes the element of `if_true` where the mask is non-zero, and of `if_false` elsewhere.
+    ///
+    /// All three matrices must have the same shape.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     // ReLU: keep positive values, replace the others with zero.
+    ///     let x = matrix![-2.0, 0.5; 3.0, -0.1];
+    ///     let zeros = Matrix::builder().rows(2).cols(2).done().unwrap();
+    ///
+    ///     let relu = x.gt_scalar(0.0).select(&x, &zeros).unwrap();
+    ///
+    ///     assert_eq!(relu, matrix![0.0, 0.5; 3.0, 0.0]);
+    /// }
+    /// ```
+    pub fn select(&self, if_true: &Matrix, if_false: &Matrix) -> Result<Matrix, MatrixError> {
+        let same_shape = |m: &Matrix| m.rows == self.rows && m.cols == self.cols;
+        if !same_shape(if_true) || !same_shape(if_false) {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let data = self.data
+            .iter()
+            .zip(if_true.data.iter().zip(if_false.data.iter()))
+            .map(|(m, (t, f))| {
+                m.iter()
+                    .zip(t.iter().zip(f.iter()))
+                    .map(|(&m, (&t, &f))| if m != 0.0 { t } else { f })
+                    .collect()
+            })
+            .collect();
+        Ok(Matrix { rows: self.rows, cols: self.cols, data })
+    }
+
+    /// Counts the elements for which `predicate` returns true.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, -2.0; 3.0, 4.0];
+    ///
+    ///     assert_eq!(a.count_where(|x| x > 0.0), 3);
+    ///     assert_eq!(a.gt_scalar(2.0).count_where(|x| x == 1.0), 2);
+    /// }
+    /// ```
+    pub fn count_where(&self, predicate: impl Fn(f64) -> bool) -> usize {
+        self.data.iter().flatten().filter(|&&x| predicate(x)).count()
+    }
+
+    /// Whether some reordering of the rows of `other` equals `self` element-wise within `eps`.
+    ///
+    /// Every row of `self` must be paired with a different row of `other` that is within `eps` of it,
+    /// which is a bipartite matching problem: it is solved with augmenting paths (Kuhn's algorithm), so, unlike
+    /// a greedy pairing, an early choice is revisited when it blocks a later row. Building the compatible pairs
+    /// costs `O(rows^2 * cols)` and the matching `O(rows^3)` in the worst case. Different shapes return false.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0; 3.0, 4.0; 1.0, 2.0];
+    ///
+    ///     assert!(a.eq_up_to_row_permutation(&matrix![3.0, 4.0; 1.0, 2.0; 1.0, 2.0], 0.0));
+    ///     // Same set of distinct rows, but not the same number of copies.
+    ///     assert!(!a.eq_up_to_row_permutation(&matrix![3.0, 4.0; 1.0, 2.0; 3.0, 4.0], 0.0));
+    ///     assert!(!a.eq_up_to_row_permutation(&a.transpose(), 0.0));
+    ///
+    ///     // 0.05 is close to both 0.0 and 0.1: pairing it with 0.1 first would leave 0.15 without a partner.
+    ///     let b = matrix![0.05; 0.15];
+    ///     assert!(b.eq_up_to_row_permutation(&matrix![0.1; 0.0], 0.06));
+    ///     assert!(!b.eq_up_to_row_permutation(&matrix![0.1; 0.0], 0.04));
+    /// }
+    /// ```
+    pub fn eq_up_to_row_permutation(&self, other: &Matrix, eps: f64) -> bool {
+        if self.rows != other.rows || self.cols != other.cols {
+            return false;
+        }
+
+        let candidates: Vec<Vec<usize>> = self.data
+            .iter()
+            .map(|row| {
+                (0..other.rows)
+                    .filter(|&j| row.iter().zip(other.data[j].iter()).all(|(x, y)| (x - y).abs() <= eps))
+                    .collect()
+            })
+            .collect();
+
+        let mut matched: Vec<Option<usize>> = vec![None; other.rows];
+        (0..self.rows).all(|i| augment(i, &candidates, &mut matched, &mut vec![false; other.rows]))
+    }
+}
+
+/// Tries to pair row `i` with one of its candidates, moving previously paired rows along an augmenting path.
+fn augment(i: usize, candidates: &[Vec<usize>], matched: &mut [Option<usize>], visited: &mut [bool]) -> bool {
+    for &j in &candidates[i] {
+        if visited[j] {
+            continue;
+        }
+        visited[j] = true;
+        if matched[j].is_none_or(|k| augment(k, candidates, matched, visited)) {
+            matched[j] = Some(i);
+            return true;
+        }
+    }
+    false
+}
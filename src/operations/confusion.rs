@@ -0,0 +1,139 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+/// Classification metrics computed from a confusion matrix by `Matrix::confusion_metrics`.
+///
+/// A metric whose denominator is 0 is 0.0: the precision of a class that is never predicted, the recall of a class
+/// that never occurs, their F1 if the class is neither, and the accuracy of an empty confusion matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfusionMetrics {
+    /// Fraction of correct predictions, the trace over the sum of all the counts.
+    pub accuracy: f64,
+    /// For every class, the fraction of the predictions of that class that are correct.
+    pub precision: Vec<f64>,
+    /// For every class, the fraction of its samples that are predicted as such.
+    pub recall: Vec<f64>,
+    /// For every class, the harmonic mean of its precision and recall.
+    pub f1: Vec<f64>,
+}
+
+impl Matrix {
+    /// Builds the `num_classes x num_classes` confusion matrix of a classifier: element `(i, j)` counts the samples
+    /// of class `truth[k] = i` that were predicted as `pred[k] = j`.
+    ///
+    /// Returns `ShapeMismatch` if `truth` and `pred` don't have the same length, `IndexOutOfBounds` with the first
+    /// label that is not below `num_classes`, and `InvalidMatrixSize` if `num_classes` is 0.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let truth = [0, 1, 1, 2, 0, 2];
+    ///     let pred = [0, 1, 2, 2, 1, 2];
+    ///     assert_eq!(
+    ///         Matrix::from_predictions(&truth, &pred, 3).unwrap(),
+    ///         matrix![1.0, 1.0, 0.0; 0.0, 1.0, 1.0; 0.0, 0.0, 2.0]
+    ///     );
+    ///
+    ///     assert!(matches!(Matrix::from_predictions(&truth, &pred[1..], 3), Err(MatrixError::ShapeMismatch { .. })));
+    ///     assert_eq!(
+    ///         Matrix::from_predictions(&[0, 1], &[0, 3], 3),
+    ///         Err(MatrixError::IndexOutOfBounds { index: 3, bound: 3 })
+    ///     );
+    /// }
+    /// ```
+    pub fn from_predictions(truth: &[usize], pred: &[usize], num_classes: usize) -> Result<Matrix, MatrixError> {
+        if truth.len() != pred.len() {
+            return Err(MatrixError::ShapeMismatch {
+                operation: "from_predictions",
+                left: (truth.len(), 1),
+                right: (pred.len(), 1),
+            });
+        }
+
+        let mut confusion = Matrix::zeros(num_classes, num_classes)?;
+        for (&t, &p) in truth.iter().zip(pred.iter()) {
+            if let Some(&index) = [t, p].iter().find(|&&label| label >= num_classes) {
+                return Err(MatrixError::IndexOutOfBounds { index, bound: num_classes });
+            }
+            confusion.data[t][p] += 1.0;
+        }
+        Ok(confusion)
+    }
+
+    /// Computes the accuracy and the per-class precision, recall and F1 of a confusion matrix with the true classes
+    /// in rows and the predicted ones in columns, like the one `from_predictions` builds.
+    ///
+    /// The counts don't have to be integers, so weighted confusion matrices work too. A zero denominator gives 0.0,
+    /// see `ConfusionMetrics`. Returns `NotSquare` for non-square matrices, `NonFiniteValue` for a `NaN` or infinite
+    /// count and `InvalidOperation` for a negative one.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let confusion = matrix![
+    ///         5.0, 1.0, 0.0;
+    ///         2.0, 3.0, 1.0;
+    ///         0.0, 0.0, 4.0
+    ///     ];
+    ///     let metrics = confusion.confusion_metrics().unwrap();
+    ///
+    ///     let close = |a: &[f64], b: &[f64]| a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-12);
+    ///     assert_eq!(metrics.accuracy, 12.0 / 16.0);
+    ///     // Precision runs down the columns, recall along the rows.
+    ///     assert!(close(&metrics.precision, &[5.0 / 7.0, 3.0 / 4.0, 4.0 / 5.0]));
+    ///     assert!(close(&metrics.recall, &[5.0 / 6.0, 3.0 / 6.0, 4.0 / 4.0]));
+    ///     assert!(close(&metrics.f1, &[10.0 / 13.0, 0.6, 8.0 / 9.0]));
+    ///
+    ///     let truth: Vec<usize> = [(0, 6), (1, 6), (2, 4)].iter().flat_map(|&(class, n)| vec![class; n]).collect();
+    ///     let pred = [0, 0, 0, 0, 0, 1, 0, 0, 1, 1, 1, 2, 2, 2, 2, 2];
+    ///     assert_eq!(Matrix::from_predictions(&truth, &pred, 3).unwrap(), confusion);
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, confusion::ConfusionMetrics};
+    /// fn main() {
+    ///     // Class 1 is never predicted and class 2 never occurs nor is predicted.
+    ///     let metrics = matrix![2.0, 0.0, 0.0; 1.0, 0.0, 0.0; 0.0, 0.0, 0.0].confusion_metrics().unwrap();
+    ///     assert_eq!(metrics, ConfusionMetrics {
+    ///         accuracy: 2.0 / 3.0,
+    ///         precision: vec![2.0 / 3.0, 0.0, 0.0],
+    ///         recall: vec![1.0, 0.0, 0.0],
+    ///         f1: vec![0.8, 0.0, 0.0],
+    ///     });
+    ///     assert_eq!(Matrix::zeros(2, 2).unwrap().confusion_metrics().unwrap().accuracy, 0.0);
+    ///
+    ///     assert!(matches!(matrix![1.0, -1.0; 0.0, 1.0].confusion_metrics(), Err(MatrixError::InvalidOperation(_))));
+    ///     assert!(matches!(matrix![1.0, 2.0].confusion_metrics(), Err(MatrixError::NotSquare { .. })));
+    /// }
+    /// ```
+    pub fn confusion_metrics(&self) -> Result<ConfusionMetrics, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        for (row, values) in self.data.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                if !value.is_finite() {
+                    return Err(MatrixError::NonFiniteValue { row, col, value });
+                }
+                if value < 0.0 {
+                    return Err(MatrixError::InvalidOperation("confusion matrix counts must be non-negative"));
+                }
+            }
+        }
+
+        let ratio = |numerator: f64, denominator: f64| if denominator == 0.0 { 0.0 } else { numerator / denominator };
+        let n = self.rows;
+        let true_positives: Vec<f64> = (0..n).map(|i| self.data[i][i]).collect();
+        let predicted: Vec<f64> = (0..n).map(|j| self.data.iter().map(|row| row[j]).sum()).collect();
+        let actual: Vec<f64> = self.data.iter().map(|row| row.iter().sum()).collect();
+        let total: f64 = actual.iter().sum();
+
+        Ok(ConfusionMetrics {
+            accuracy: ratio(true_positives.iter().sum(), total),
+            precision: (0..n).map(|i| ratio(true_positives[i], predicted[i])).collect(),
+            recall: (0..n).map(|i| ratio(true_positives[i], actual[i])).collect(),
+            // 2 TP / (2 TP + FP + FN), the harmonic mean without going through precision and recall.
+            f1: (0..n).map(|i| ratio(2.0 * true_positives[i], predicted[i] + actual[i])).collect(),
+        })
+    }
+}
@@ -0,0 +1,208 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use crate::operations::lu::solve_tolerance;
+
+/// Number of scalar operations performed by one of the `*_counted` methods.
+///
+/// Subtractions count as additions. Comparisons, swaps and copies are not counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OpCount {
+    pub mults: u64,
+    pub adds: u64,
+    pub divs: u64,
+}
+
+impl OpCount {
+    /// Sum of all the counted operations.
+    pub fn total(&self) -> u64 {
+        self.mults + self.adds + self.divs
+    }
+}
+
+impl Matrix {
+    /// Multiplies like `Mul::mul` with the textbook triple loop, and counts its operations.
+    ///
+    /// Each of the `n x p` elements of an `n x m` by `m x p` product is a sum of `m` products,
+    /// so it takes `n * m * p` multiplications and `n * (m - 1) * p` additions.
+    /// Returns `DimensionMismatch` like `mul`.
+    /// # Example
+    /// ```
+    /// # use matrix::{Matrix, counting::OpCount, mul::Mul};
+    /// fn main() {
+    ///     let n = 10;
+    ///     let a = Matrix::from_seed(n, n, 1).unwrap();
+    ///     let b = Matrix::from_seed(n, n, 2).unwrap();
+    ///
+    ///     let (product, count) = a.mul_counted(&b).unwrap();
+    ///     assert_eq!(product, a.mul(&b).unwrap());
+    ///     assert_eq!(count, OpCount { mults: 1000, adds: 900, divs: 0 });
+    ///
+    ///     let (_, count) = Matrix::from_seed(2, 5, 3).unwrap().mul_counted(&Matrix::from_seed(5, 3, 4).unwrap()).unwrap();
+    ///     assert_eq!((count.mults, count.adds), (2 * 5 * 3, 2 * 4 * 3));
+    /// }
+    /// ```
+    pub fn mul_counted(&self, other: &Matrix) -> Result<(Matrix, OpCount), MatrixError> {
+        if self.cols != other.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let mut count = OpCount::default();
+        let data = self.data
+            .iter()
+            .map(|row| {
+                (0..other.cols)
+                    .map(|j| {
+                        let mut sum = row[0] * other.data[0][j];
+                        count.mults += 1;
+                        for (x, other_row) in row.iter().zip(other.data.iter()).skip(1) {
+                            sum += x * other_row[j];
+                            count.mults += 1;
+                            count.adds += 1;
+                        }
+                        sum
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok((Matrix { rows: self.rows, cols: other.cols, data }, count))
+    }
+
+    /// Computes the determinant by Gaussian elimination with partial pivoting, and counts its operations.
+    ///
+    /// Eliminating below pivot `k` takes `n - k - 1` divisions and `(n - k - 1)^2` multiplications and additions,
+    /// about `n^3 / 3` of each in total, then the `n` pivots are multiplied together. A zero pivot stops early
+    /// with a determinant of 0. Returns `NotSquare` for non-square matrices.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, counting::OpCount};
+    /// fn main() {
+    ///     let n = 10;
+    ///     let a = Matrix::from_seed(n, n, 5).unwrap();
+    ///
+    ///     let (det, count) = a.determinant_counted().unwrap();
+    ///     assert!((det - a.determinant().unwrap()).abs() < 1e-12);
+    ///     // sum of (n - k - 1)^2 for k in 0..n is (n - 1) n (2n - 1) / 6 = 285.
+    ///     assert_eq!(count, OpCount { mults: 285 + 10, adds: 285, divs: 45 });
+    ///     assert!(count.mults < 1000 / 3 + 10);
+    ///
+    ///     assert_eq!(matrix![1.0, 2.0; 2.0, 4.0].determinant_counted().unwrap().0, 0.0);
+    /// }
+    /// ```
+    pub fn determinant_counted(&self) -> Result<(f64, OpCount), MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        let mut a = self.data.clone();
+        let mut count = OpCount::default();
+        let Some(sign) = eliminate(&mut a, None, &mut count, 0.0) else {
+            return Ok((0.0, count));
+        };
+
+        let mut det = sign;
+        for (k, row) in a.iter().enumerate() {
+            det *= row[k];
+            count.mults += 1;
+        }
+        Ok((det, count))
+    }
+
+    /// Solves `A X = B` by Gaussian elimination with partial pivoting and back substitution, and counts its operations.
+    ///
+    /// On top of the elimination of `determinant_counted`, every column of `B` takes `n (n - 1)` multiplications
+    /// and additions and `n` divisions. Errors are the same as `solve`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, counting::OpCount};
+    /// fn main() {
+    ///     let a = matrix![2.0, 1.0, 1.0; 1.0, 3.0, 2.0; 1.0, 0.0, 0.0];
+    ///     let b = matrix![4.0; 5.0; 6.0];
+    ///
+    ///     let (x, count) = a.solve_counted(&b).unwrap();
+    ///     assert!(x.diff(&a.solve(&b).unwrap(), 1e-12).is_equal());
+    ///     // Elimination: 5 mults, 5 adds, 3 divs. Right-hand side: 3 + 3 mults and adds, 3 divs.
+    ///     assert_eq!(count, OpCount { mults: 11, adds: 11, divs: 6 });
+    ///
+    ///     assert_eq!(
+    ///         matrix![1.0, 2.0; 2.0, 4.0].solve_counted(&matrix![1.0; 2.0]),
+    ///         Err(MatrixError::Singular { operation: "solve" })
+    ///     );
+    /// }
+    /// ```
+    pub fn solve_counted(&self, b: &Matrix) -> Result<(Matrix, OpCount), MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        if b.rows != self.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let scale = self.data.iter().flatten().fold(0.0, |max: f64, v| v.abs().max(max));
+        let tol = solve_tolerance(self.rows).threshold(scale);
+        let mut a = self.data.clone();
+        let mut x = b.data.clone();
+        let mut count = OpCount::default();
+        if eliminate(&mut a, Some(&mut x), &mut count, tol).is_none() {
+            return Err(MatrixError::Singular { operation: "solve" });
+        }
+
+        let n = self.rows;
+        for i in (0..n).rev() {
+            let (head, solved) = x.split_at_mut(i + 1);
+            for (j, value) in head[i].iter_mut().enumerate() {
+                for (coefficient, solved_row) in a[i][i + 1..].iter().zip(solved.iter()) {
+                    *value -= coefficient * solved_row[j];
+                    count.mults += 1;
+                    count.adds += 1;
+                }
+                *value /= a[i][i];
+                count.divs += 1;
+            }
+        }
+        Ok((Matrix { rows: b.rows, cols: b.cols, data: x }, count))
+    }
+}
+
+/// Reduces `a` to upper triangular form in place, applying the same row operations to `rhs`.
+///
+/// Returns the sign of the row permutation, or `None` as soon as the best pivot is at most `tol` in absolute value.
+fn eliminate(a: &mut [Vec<f64>], mut rhs: Option<&mut Vec<Vec<f64>>>, count: &mut OpCount, tol: f64) -> Option<f64> {
+    let n = a.len();
+    let mut sign = 1.0;
+    for k in 0..n {
+        let pivot_row = (k..n).max_by(|&x, &y| a[x][k].abs().total_cmp(&a[y][k].abs())).unwrap_or(k);
+        if a[pivot_row][k].abs() <= tol {
+            return None;
+        }
+        if pivot_row != k {
+            a.swap(pivot_row, k);
+            if let Some(rhs) = rhs.as_deref_mut() {
+                rhs.swap(pivot_row, k);
+            }
+            sign = -sign;
+        }
+
+        let (top, bottom) = a.split_at_mut(k + 1);
+        let pivot = &top[k];
+        for (offset, row) in bottom.iter_mut().enumerate() {
+            let factor = row[k] / pivot[k];
+            count.divs += 1;
+            for (value, p) in row.iter_mut().zip(pivot.iter()).skip(k + 1) {
+                *value -= factor * p;
+                count.mults += 1;
+                count.adds += 1;
+            }
+            row[k] = 0.0;
+
+            if let Some(rhs) = rhs.as_deref_mut() {
+                let i = k + 1 + offset;
+                for j in 0..rhs[i].len() {
+                    rhs[i][j] -= factor * rhs[k][j];
+                    count.mults += 1;
+                    count.adds += 1;
+                }
+            }
+        }
+    }
+    Some(sign)
+}
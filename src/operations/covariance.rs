@@ -0,0 +1,154 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use crate::operations::summation::compensated_sum;
+
+impl Matrix {
+    /// Computes the sample covariance matrix of an observations-by-features matrix, with the `n - 1` denominator.
+    ///
+    /// The result is `cols x cols` and symmetric. Returns `InvalidOperation` with fewer than two observations.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let data = matrix![1.0, 2.0; 3.0, 6.0; 5.0, 4.0; 7.0, 8.0];
+    ///     let expected = matrix![20.0, 16.0; 16.0, 20.0].scale(1.0 / 3.0);
+    ///     assert!(data.covariance().unwrap().diff(&expected, 1e-12).is_equal());
+    ///
+    ///     assert!(matrix![1.0, 2.0].covariance().is_err());
+    /// }
+    /// ```
+    pub fn covariance(&self) -> Result<Matrix, MatrixError> {
+        if self.rows < 2 {
+            return Err(MatrixError::InvalidOperation("covariance requires at least two observations"));
+        }
+
+        let centered = self.centered();
+        let p = self.cols;
+        let mut covariance = Matrix::zeros(p, p)?;
+        for i in 0..p {
+            for j in i..p {
+                let c = compensated_sum(centered.data.iter().map(|row| row[i] * row[j])) / (self.rows - 1) as f64;
+                covariance.data[i][j] = c;
+                covariance.data[j][i] = c;
+            }
+        }
+        Ok(covariance)
+    }
+
+    /// Returns `self + lambda * I`, the usual fix for a covariance matrix that is singular or nearly so.
+    ///
+    /// Returns `NotSquare` for non-square matrices.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let singular = matrix![1.0, 1.0; 1.0, 1.0];
+    ///     assert_eq!(singular.regularize(0.5).unwrap(), matrix![1.5, 1.0; 1.0, 1.5]);
+    ///     assert!(singular.regularize(0.5).unwrap().sqrt_spd().is_ok());
+    /// }
+    /// ```
+    pub fn regularize(&self, lambda: f64) -> Result<Matrix, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        let mut result = self.clone();
+        result.data.iter_mut().enumerate().for_each(|(i, row)| row[i] += lambda);
+        Ok(result)
+    }
+
+    /// Whitens an observations-by-features matrix: centers every column and decorrelates the features, so the
+    /// result has zero mean and identity covariance.
+    ///
+    /// This is ZCA whitening, `(X - mean) C^(-1/2)` with `C^(1/2)` from `sqrt_spd`, applied by solving with it
+    /// rather than inverting it. Returns `InvalidOperation` if there are fewer than two observations or if the
+    /// covariance is singular (fewer independent observations than features, or linearly dependent features).
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let data = matrix![1.0, 2.0; 3.0, 6.0; 5.0, 4.0; 7.0, 8.0; 2.0, 1.0];
+    ///     let white = data.whiten().unwrap();
+    ///
+    ///     assert_eq!((white.rows, white.cols), (5, 2));
+    ///     assert!(white.covariance().unwrap().diff(&Matrix::identity(2).unwrap(), 1e-12).is_equal());
+    ///     assert!(white.mean().abs() < 1e-12);
+    ///
+    ///     // The second feature is twice the first.
+    ///     let dependent = matrix![1.0, 2.0; 2.0, 4.0; 3.0, 6.0];
+    ///     assert!(matches!(dependent.whiten(), Err(matrix::MatrixError::InvalidOperation(_))));
+    /// }
+    /// ```
+    pub fn whiten(&self) -> Result<Matrix, MatrixError> {
+        let root = self.covariance()?.sqrt_spd().map_err(|error| match error {
+            MatrixError::NotPositiveDefinite { .. } => MatrixError::InvalidOperation(
+                "whiten requires a non-singular covariance, drop dependent features or add observations",
+            ),
+            other => other,
+        })?;
+        // X C^(-1/2) = (C^(-1/2) X^T)^T, since C^(-1/2) is symmetric.
+        Ok(root.solve(&self.centered().transpose())?.transpose())
+    }
+
+    /// Computes the Mahalanobis distance `sqrt((x - mean)^T C^-1 (x - mean))` with `self` as the covariance `C`,
+    /// solving `C y = x - mean` instead of inverting `C`.
+    ///
+    /// Returns `NotSquare` if `self` is not square, `ShapeMismatch` if `x` or `mean` don't have one value per
+    /// row of `self`, `InvalidOperation` if the covariance is singular (`regularize` fixes that), and
+    /// `NotPositiveDefinite` if the quadratic form is negative.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let diagonal = matrix![4.0, 0.0; 0.0, 9.0];
+    ///     assert!((diagonal.mahalanobis(&[2.0, 3.0], &[0.0, 0.0]).unwrap() - 2f64.sqrt()).abs() < 1e-12);
+    ///
+    ///     // Along the direction of strong correlation, distances are short.
+    ///     let covariance = matrix![1.0, 2.0; 3.0, 6.0; 5.0, 4.0; 7.0, 8.0].covariance().unwrap();
+    ///     let along = covariance.mahalanobis(&[5.0, 6.0], &[4.0, 5.0]).unwrap();
+    ///     let across = covariance.mahalanobis(&[5.0, 4.0], &[4.0, 5.0]).unwrap();
+    ///     assert!((along - (1.0f64 / 6.0).sqrt()).abs() < 1e-12);
+    ///     assert!((across - 1.5f64.sqrt()).abs() < 1e-12);
+    ///
+    ///     let singular = matrix![1.0, 1.0; 1.0, 1.0];
+    ///     assert!(matches!(singular.mahalanobis(&[1.0, 0.0], &[0.0, 0.0]), Err(MatrixError::InvalidOperation(_))));
+    ///     assert!(singular.regularize(1e-3).unwrap().mahalanobis(&[1.0, 0.0], &[0.0, 0.0]).is_ok());
+    ///     assert!(matches!(diagonal.mahalanobis(&[1.0], &[0.0, 0.0]), Err(MatrixError::ShapeMismatch { .. })));
+    /// }
+    /// ```
+    pub fn mahalanobis(&self, x: &[f64], mean: &[f64]) -> Result<f64, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        for values in [x, mean] {
+            if values.len() != self.rows {
+                return Err(MatrixError::ShapeMismatch {
+                    operation: "mahalanobis",
+                    left: (self.rows, self.cols),
+                    right: (values.len(), 1),
+                });
+            }
+        }
+
+        let difference: Vec<f64> = x.iter().zip(mean.iter()).map(|(x, m)| x - m).collect();
+        let y = self.solve(&Matrix::from_flat(self.rows, 1, difference.clone())?).map_err(|error| match error {
+            MatrixError::Singular { .. } => MatrixError::InvalidOperation(
+                "mahalanobis requires a non-singular covariance, use `regularize` to add lambda * I",
+            ),
+            other => other,
+        })?;
+        let squared = compensated_sum(difference.iter().zip(y.data.iter()).map(|(d, y)| d * y[0]));
+        if squared < 0.0 {
+            return Err(MatrixError::NotPositiveDefinite { operation: "mahalanobis" });
+        }
+        Ok(squared.sqrt())
+    }
+
+    /// Subtracts the mean of every column from it.
+    fn centered(&self) -> Matrix {
+        let means: Vec<f64> = (0..self.cols)
+            .map(|j| compensated_sum(self.data.iter().map(|row| row[j])) / self.rows as f64)
+            .collect();
+        let data = self.data.iter().map(|row| row.iter().zip(means.iter()).map(|(x, m)| x - m).collect()).collect();
+        Matrix { rows: self.rows, cols: self.cols, data }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::matrix::*;
+
+/// Two rows are duplicates when every pair of elements is within `eps`. `NaN` is never a duplicate of anything.
+fn same_within(a: &[f64], b: &[f64], eps: f64) -> bool {
+    a.iter().zip(b.iter()).all(|(x, y)| (x - y).abs() <= eps)
+}
+
+fn duplicate_pairs(rows: &[Vec<f64>], eps: f64) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for (i, a) in rows.iter().enumerate() {
+        for (j, b) in rows.iter().enumerate().skip(i + 1) {
+            if same_within(a, b, eps) {
+                pairs.push((i, j));
+            }
+        }
+    }
+    pairs
+}
+
+/// Keeps a row unless it is a duplicate of a row that was already kept.
+fn dedup(rows: &[Vec<f64>], eps: f64) -> Vec<Vec<f64>> {
+    let mut kept: Vec<Vec<f64>> = Vec::new();
+    for row in rows {
+        if !kept.iter().any(|k| same_within(k, row, eps)) {
+            kept.push(row.clone());
+        }
+    }
+    kept
+}
+
+impl Matrix {
+    /// Lists every pair `(i, j)`, `i < j`, of rows whose elements all differ by at most `eps`.
+    ///
+    /// "Within `eps`" is not transitive, so every pair is tested on its own: with `a ≈ b` and `b ≈ c`
+    /// but not `a ≈ c`, only `(a, b)` and `(b, c)` are listed. Pairs are sorted by `i`, then `j`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0; 3.0, 4.0; 1.0, 2.0; 1.0, 2.0 + 1e-12];
+    ///
+    ///     assert_eq!(a.duplicate_rows(0.0), vec![(0, 2)]);
+    ///     assert_eq!(a.duplicate_rows(1e-9), vec![(0, 2), (0, 3), (2, 3)]);
+    ///     assert_eq!(a.duplicate_cols(0.0), vec![]);
+    /// }
+    /// ```
+    pub fn duplicate_rows(&self, eps: f64) -> Vec<(usize, usize)> {
+        duplicate_pairs(&self.data, eps)
+    }
+
+    /// Lists the pairs of duplicate columns, see `duplicate_rows`.
+    pub fn duplicate_cols(&self, eps: f64) -> Vec<(usize, usize)> {
+        duplicate_pairs(&self.transpose().data, eps)
+    }
+
+    /// Removes duplicate rows, keeping the first occurrence.
+    ///
+    /// Rows are scanned in order and a row is dropped if it is within `eps` of a row that was kept.
+    /// For a chain `a ≈ b ≈ c` where `a` and `c` are not within `eps`, `b` is dropped
+    /// (it duplicates `a`) but `c` is kept, since it is compared only against `a`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0; 3.0, 4.0; 1.0, 2.0];
+    ///     assert_eq!(a.dedup_rows(0.0), matrix![1.0, 2.0; 3.0, 4.0]);
+    ///
+    ///     // A chain of near duplicates, 0.6 apart with eps = 1.
+    ///     let chain = matrix![0.0; 0.6; 1.2];
+    ///     assert_eq!(chain.dedup_rows(1.0), matrix![0.0; 1.2]);
+    ///
+    ///     let cols = matrix![1.0, 1.0, 5.0; 2.0, 2.0 + 1e-12, 6.0];
+    ///     assert_eq!(cols.dedup_cols(1e-9), matrix![1.0, 5.0; 2.0, 6.0]);
+    /// }
+    /// ```
+    pub fn dedup_rows(&self, eps: f64) -> Matrix {
+        let data = dedup(&self.data, eps);
+        Matrix { rows: data.len(), cols: self.cols, data }
+    }
+
+    /// Removes duplicate columns, keeping the first occurrence, see `dedup_rows`.
+    pub fn dedup_cols(&self, eps: f64) -> Matrix {
+        self.transpose().dedup_rows(eps).transpose()
+    }
+}
@@ -0,0 +1,145 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+/// Algorithm used by `Matrix::determinant_with`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetMethod {
+    /// Closed forms up to 3x3, `LU` above.
+    Auto,
+    /// Cofactor expansion along the first row. Exact for small integer-valued matrices, but O(n!).
+    Laplace,
+    /// LU factorization with partial pivoting, O(n^3). The same as `Matrix::determinant`.
+    LU,
+    /// Bareiss fraction-free elimination, O(n^3). Every intermediate is a minor of the matrix,
+    /// so integer-valued matrices stay integer-valued and the result is exact while the minors fit in 2^53.
+    Bareiss,
+}
+
+/// Largest size for which `DetMethod::Auto` uses a closed form.
+const AUTO_LAPLACE_MAX: usize = 3;
+
+impl Matrix {
+    /// Computes the determinant with the chosen `method`, see `DetMethod`.
+    ///
+    /// Non-square matrices return `NotSquare` whatever the method.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, det::DetMethod};
+    /// fn main() {
+    ///     let a = matrix![2.0, 0.0, 1.0; 1.0, 3.0, 2.0; 1.0, 1.0, 2.0];
+    ///
+    ///     assert_eq!(a.determinant_with(DetMethod::Laplace).unwrap(), 6.0);
+    ///     assert_eq!(a.determinant_with(DetMethod::Bareiss).unwrap(), 6.0);
+    ///     assert_eq!(a.determinant_with(DetMethod::Auto).unwrap(), 6.0);
+    ///     assert!((a.determinant_with(DetMethod::LU).unwrap() - 6.0).abs() < 1e-12);
+    ///
+    ///     assert!(matrix![1.0, 2.0].determinant_with(DetMethod::Bareiss).is_err());
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{Matrix, det::DetMethod};
+    /// fn main() {
+    ///     // Integer matrices whose LU pivots are fractions: Bareiss and Laplace stay exact.
+    ///     let a = Matrix::from_flat(4, 4, vec![
+    ///         3.0, 7.0, 1.0, 4.0,
+    ///         2.0, 5.0, 9.0, 8.0,
+    ///         6.0, 1.0, 4.0, 2.0,
+    ///         5.0, 3.0, 8.0, 7.0,
+    ///     ]).unwrap();
+    ///
+    ///     let exact = a.determinant_with(DetMethod::Laplace).unwrap();
+    ///     assert_eq!(exact.fract(), 0.0);
+    ///     assert_eq!(a.determinant_with(DetMethod::Bareiss).unwrap(), exact);
+    ///
+    ///     // A zero leading entry needs a row swap.
+    ///     let swap = Matrix::from_flat(2, 2, vec![0.0, 1.0, 1.0, 0.0]).unwrap();
+    ///     assert_eq!(swap.determinant_with(DetMethod::Bareiss).unwrap(), -1.0);
+    ///     let singular = Matrix::from_flat(2, 2, vec![0.0, 1.0, 0.0, 2.0]).unwrap();
+    ///     assert_eq!(singular.determinant_with(DetMethod::Bareiss).unwrap(), 0.0);
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{Matrix, det::DetMethod};
+    /// fn main() {
+    ///     // All the methods agree on random 5x5 matrices.
+    ///     let mut state: u64 = 0x2545F4914F6CDD1D;
+    ///     let mut random = move || {
+    ///         state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    ///         (state >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+    ///     };
+    ///
+    ///     for _ in 0..50 {
+    ///         let a = Matrix::from_flat(5, 5, (0..25).map(|_| random()).collect()).unwrap();
+    ///         let lu = a.determinant_with(DetMethod::LU).unwrap();
+    ///
+    ///         for method in [DetMethod::Auto, DetMethod::Laplace, DetMethod::Bareiss] {
+    ///             let det = a.determinant_with(method).unwrap();
+    ///             assert!((det - lu).abs() <= 1e-10 * lu.abs().max(1.0), "{:?}: {} vs {}", method, det, lu);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn determinant_with(&self, method: DetMethod) -> Result<f64, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        match method {
+            DetMethod::Auto if self.rows <= AUTO_LAPLACE_MAX => Ok(laplace(&self.data)),
+            DetMethod::Auto | DetMethod::LU => self.determinant(),
+            DetMethod::Laplace => Ok(laplace(&self.data)),
+            DetMethod::Bareiss => Ok(bareiss(self.data.clone())),
+        }
+    }
+}
+
+/// Cofactor expansion along the first row, with closed forms for 1x1 and 2x2.
+fn laplace(a: &[Vec<f64>]) -> f64 {
+    match a.len() {
+        1 => a[0][0],
+        2 => a[0][0] * a[1][1] - a[0][1] * a[1][0],
+        n => (0..n)
+            .filter(|&j| a[0][j] != 0.0)
+            .map(|j| {
+                let minor: Vec<Vec<f64>> = a[1..]
+                    .iter()
+                    .map(|row| row.iter().enumerate().filter(|&(c, _)| c != j).map(|(_, &x)| x).collect())
+                    .collect();
+                let sign = if j % 2 == 0 { 1.0 } else { -1.0 };
+                sign * a[0][j] * laplace(&minor)
+            })
+            .sum(),
+    }
+}
+
+/// Bareiss elimination, swapping in a non-zero pivot when needed.
+fn bareiss(mut a: Vec<Vec<f64>>) -> f64 {
+    let n = a.len();
+    let mut sign = 1.0;
+    let mut previous = 1.0;
+
+    for k in 0..n {
+        if a[k][k] == 0.0 {
+            match (k + 1..n).find(|&i| a[i][k] != 0.0) {
+                Some(i) => {
+                    a.swap(i, k);
+                    sign = -sign;
+                }
+                None => return 0.0,
+            }
+        }
+
+        let (top, bottom) = a.split_at_mut(k + 1);
+        let pivot_row = &top[k];
+        let pivot = pivot_row[k];
+        for row in bottom.iter_mut() {
+            let factor = row[k];
+            for (value, &p) in row.iter_mut().zip(pivot_row.iter()).skip(k + 1) {
+                *value = (*value * pivot - factor * p) / previous;
+            }
+        }
+        previous = pivot;
+    }
+
+    sign * a[n - 1][n - 1]
+}
@@ -0,0 +1,557 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use crate::operations::mul::Mul;
+use crate::tolerance::DEFAULT_TOLERANCE;
+
+impl Matrix {
+    /// Computes all eigenvalues and eigenvectors of a symmetric matrix with the cyclic Jacobi rotation method.
+    ///
+    /// - `tol` is the target for the off-diagonal norm, and is also the tolerance of the symmetry check.
+    /// - `max_sweeps` is the maximum number of full sweeps over the upper triangle.
+    ///
+    /// Returns the eigenvalues sorted in descending order and a matrix whose columns are the
+    /// corresponding unit eigenvectors.
+    ///
+    /// Non-square input returns `NotSquare`, and non-symmetric input returns `InvalidOperation`
+    /// (use `dominant_eigen` for general matrices).
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// # use matrix::mul::Mul;
+    /// fn main() {
+    ///     let a = Matrix::builder()
+    ///         .rows(3)
+    ///         .cols(3)
+    ///         .data(vec![
+    ///             vec![4.0, 1.0, 2.0],
+    ///             vec![1.0, 3.0, 0.0],
+    ///             vec![2.0, 0.0, 5.0],
+    ///         ])
+    ///         .done()
+    ///         .unwrap();
+    ///
+    ///     let (values, vectors) = a.eigen_symmetric(1e-12, 50).unwrap();
+    ///     let av = a.mul(&vectors).unwrap();
+    ///
+    ///     // A * v = λ * v for every eigenpair.
+    ///     for (j, lambda) in values.iter().enumerate() {
+    ///         for i in 0..3 {
+    ///             assert!((av.data[i][j] - lambda * vectors.data[i][j]).abs() < 1e-9);
+    ///         }
+    ///     }
+    ///     assert!(values[0] >= values[1] && values[1] >= values[2]);
+    ///
+    ///     let b = Matrix::builder().rows(2).cols(2).data(vec![vec![1.0, 2.0], vec![0.0, 1.0]]).done().unwrap();
+    ///     assert!(b.eigen_symmetric(1e-12, 50).is_err());
+    /// }
+    /// ```
+    pub fn eigen_symmetric(&self, tol: f64, max_sweeps: usize) -> Result<(Vec<f64>, Matrix), MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        if !self.is_symmetric(tol) {
+            return Err(MatrixError::InvalidOperation("eigen_symmetric requires a symmetric matrix, use dominant_eigen for general matrices"));
+        }
+
+        let n = self.rows;
+        let mut a = self.data.clone();
+        let mut v = Matrix::identity(n)?;
+        let mut sweeps = 0;
+
+        loop {
+            let off = off_diagonal_norm(&a);
+            if off <= tol {
+                break;
+            }
+            if sweeps == max_sweeps {
+                return Err(MatrixError::DidNotConverge { iterations: sweeps, residual: off });
+            }
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    if a[p][q] == 0.0 {
+                        continue;
+                    }
+
+                    // Rotation angle that zeroes a[p][q], computed in the numerically stable way.
+                    let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    for row in a.iter_mut() {
+                        let (akp, akq) = (row[p], row[q]);
+                        row[p] = c * akp - s * akq;
+                        row[q] = s * akp + c * akq;
+                    }
+                    let (head, tail) = a.split_at_mut(q);
+                    for (apk, aqk) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                        let (x, y) = (*apk, *aqk);
+                        *apk = c * x - s * y;
+                        *aqk = s * x + c * y;
+                    }
+                    for row in v.data.iter_mut() {
+                        let (vkp, vkq) = (row[p], row[q]);
+                        row[p] = c * vkp - s * vkq;
+                        row[q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+            sweeps += 1;
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[j][j].total_cmp(&a[i][i]));
+
+        let values = order.iter().map(|&i| a[i][i]).collect();
+        let vectors = Matrix {
+            rows: n,
+            cols: n,
+            data: v.data
+                .iter()
+                .map(|row| order.iter().map(|&i| row[i]).collect())
+                .collect(),
+        };
+
+        Ok((values, vectors))
+    }
+
+    /// Finds the eigenvalue of largest magnitude and its eigenvector with power iteration.
+    ///
+    /// Works for general square matrices, as long as the dominant eigenvalue is real and strictly larger in
+    /// magnitude than the others. The eigenvector is returned as a unit `n x 1` column matrix.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let a = Matrix::builder()
+    ///         .rows(2)
+    ///         .cols(2)
+    ///         .data(vec![
+    ///             vec![2.0, 1.0],
+    ///             vec![0.0, 1.0],
+    ///         ])
+    ///         .done()
+    ///         .unwrap();
+    ///
+    ///     let (lambda, vector) = a.dominant_eigen(1e-12, 1000).unwrap();
+    ///
+    ///     assert!((lambda - 2.0).abs() < 1e-9);
+    ///     assert!((vector.data[1][0]).abs() < 1e-6);
+    /// }
+    /// ```
+    pub fn dominant_eigen(&self, tol: f64, max_iter: usize) -> Result<(f64, Matrix), MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        let n = self.rows;
+        // A non-uniform start vector, so it is unlikely to be orthogonal to the dominant eigenvector.
+        let mut v: Vec<f64> = (0..n).map(|i| 1.0 + i as f64 / n as f64).collect();
+        normalize(&mut v);
+        let mut lambda = 0.0;
+        let mut delta = f64::INFINITY;
+
+        for iteration in 0..max_iter {
+            let mut w: Vec<f64> = self.data
+                .iter()
+                .map(|row| row.iter().zip(v.iter()).map(|(a, b)| a * b).sum())
+                .collect();
+
+            let next: f64 = v.iter().zip(w.iter()).map(|(a, b)| a * b).sum();
+            if normalize(&mut w) == 0.0 {
+                return Ok((0.0, column(v)));
+            }
+
+            delta = (next - lambda).abs();
+            lambda = next;
+            v = w;
+
+            if iteration > 0 && delta <= tol * lambda.abs().max(1.0) {
+                return Ok((lambda, column(v)));
+            }
+        }
+
+        Err(MatrixError::DidNotConverge { iterations: max_iter, residual: delta })
+    }
+
+    /// Finds the eigenvalue closest to `shift` and its eigenvector with shifted inverse iteration: power iteration on
+    /// `(A - shift I)^-1`, whose dominant eigenvalue `1 / (lambda - shift)` belongs to that eigenvalue.
+    ///
+    /// Unlike `dominant_eigen` it reaches any real eigenvalue, given a shift closer to it than to the others;
+    /// `eigenvalue_bounds` tells where to look. Every step is a `solve` with `A - shift I`, and the eigenvalue is
+    /// the Rayleigh quotient of the current vector, so convergence is tested like `dominant_eigen`: successive
+    /// estimates within `tol` relative to the estimate (or absolute below 1). The eigenvector is a unit `n x 1`
+    /// column matrix.
+    ///
+    /// A shift on an eigenvalue, or close enough that `solve` reports `A - shift I` as `Singular`, is moved by
+    /// `1e-10` times the largest of `|shift|` and the absolute entries of `A`, up to 3 times with a step 100 times
+    /// larger each time. The eigenvalue is still estimated with the Rayleigh quotient of `A` itself, so the result
+    /// is as accurate as with the given shift. `Singular` is only returned if every perturbed shift fails too.
+    ///
+    /// Returns `NotSquare` for non-square matrices, `InvalidOperation` for a non-finite shift and `DidNotConverge`
+    /// with the last change of the estimate if `max_iter` steps are not enough.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     // Eigenvalues 2 - sqrt(2), 2 and 2 + sqrt(2).
+    ///     let a = matrix![2.0, -1.0, 0.0; -1.0, 2.0, -1.0; 0.0, -1.0, 2.0];
+    ///     let (values, _) = a.eigen_symmetric(1e-14, 100).unwrap();
+    ///
+    ///     // Power iteration only ever finds the largest one.
+    ///     let (dominant, _) = a.dominant_eigen(1e-12, 1000).unwrap();
+    ///     assert!((dominant - values[0]).abs() < 1e-9);
+    ///
+    ///     let (lambda, v) = a.eigen_near(1.8, 1e-14, 100).unwrap();
+    ///     assert!((lambda - 2.0).abs() < 1e-12);
+    ///     assert!((lambda - values[1]).abs() < 1e-12);
+    ///     // The eigenvector is (1, 0, -1) / sqrt(2), up to its sign.
+    ///     let sign = v.data[0][0].signum();
+    ///     let expected = [1.0, 0.0, -1.0].map(|x: f64| sign * x / 2f64.sqrt());
+    ///     assert!(v.data.iter().zip(expected).all(|(row, x)| (row[0] - x).abs() < 1e-8));
+    ///
+    ///     // The two others, from shifts on either side.
+    ///     assert!((a.eigen_near(0.0, 1e-14, 100).unwrap().0 - values[2]).abs() < 1e-12);
+    ///     assert!((a.eigen_near(4.0, 1e-14, 100).unwrap().0 - values[0]).abs() < 1e-12);
+    ///
+    ///     // A - 2 I is singular: the shift is moved off the eigenvalue, which is still found.
+    ///     let singular = matrix![0.0, -1.0, 0.0; -1.0, 0.0, -1.0; 0.0, -1.0, 0.0];
+    ///     assert_eq!(singular.solve(&matrix![1.0; 1.0; 1.0]), Err(MatrixError::Singular { operation: "solve" }));
+    ///     let (lambda, _) = a.eigen_near(2.0, 1e-14, 100).unwrap();
+    ///     assert!((lambda - 2.0).abs() < 1e-12);
+    ///
+    ///     assert!(matches!(a.eigen_near(1.8, 1e-14, 1), Err(MatrixError::DidNotConverge { iterations: 1, .. })));
+    ///     assert!(matches!(a.eigen_near(f64::NAN, 1e-14, 100), Err(MatrixError::InvalidOperation(_))));
+    ///     assert_eq!(matrix![1.0, 2.0].eigen_near(0.0, 1e-12, 10), Err(MatrixError::NotSquare { rows: 1, cols: 2 }));
+    /// }
+    /// ```
+    pub fn eigen_near(&self, shift: f64, tol: f64, max_iter: usize) -> Result<(f64, Matrix), MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        if !shift.is_finite() {
+            return Err(MatrixError::InvalidOperation("shift must be finite"));
+        }
+
+        let n = self.rows;
+        let mut v: Vec<f64> = (0..n).map(|i| 1.0 + i as f64 / n as f64).collect();
+        normalize(&mut v);
+
+        let scale = self.data.iter().flatten().fold(shift.abs(), |max, x| x.abs().max(max)).max(f64::MIN_POSITIVE);
+        let mut shifted = self.clone();
+        let mut w = Err(MatrixError::Singular { operation: "eigen_near" });
+        for attempt in 0..4 {
+            let perturbation = if attempt == 0 { 0.0 } else { 1e-10 * 100f64.powi(attempt - 1) * scale };
+            (0..n).for_each(|i| shifted.data[i][i] = self.data[i][i] - (shift + perturbation));
+            w = shifted.solve(&column(v.clone()));
+            if !matches!(w, Err(MatrixError::Singular { .. })) {
+                break;
+            }
+        }
+        let own_singular = |error| match error {
+            MatrixError::Singular { .. } => MatrixError::Singular { operation: "eigen_near" },
+            error => error,
+        };
+        let mut w = w.map_err(own_singular)?;
+
+        let mut lambda = 0.0;
+        let mut delta = f64::INFINITY;
+        for iteration in 0..max_iter {
+            if iteration > 0 {
+                w = shifted.solve(&column(v.clone())).map_err(own_singular)?;
+            }
+            v = w.data.iter().map(|row| row[0]).collect();
+            normalize(&mut v);
+
+            let next: f64 = self.data
+                .iter()
+                .zip(v.iter())
+                .map(|(row, vi)| vi * row.iter().zip(v.iter()).map(|(a, b)| a * b).sum::<f64>())
+                .sum();
+            delta = (next - lambda).abs();
+            lambda = next;
+
+            if iteration > 0 && delta <= tol * lambda.abs().max(1.0) {
+                return Ok((lambda, column(v)));
+            }
+        }
+
+        Err(MatrixError::DidNotConverge { iterations: max_iter, residual: delta })
+    }
+
+    /// Returns the spectral radius, the largest absolute value of the eigenvalues.
+    ///
+    /// Symmetric matrices use `eigen_symmetric`, other matrices fall back to `dominant_eigen`,
+    /// which can fail to converge when the dominant eigenvalues are complex.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = Matrix::builder()
+    ///         .rows(2)
+    ///         .cols(2)
+    ///         .data(vec![
+    ///             vec![1.0, 2.0],
+    ///             vec![2.0, 1.0],
+    ///         ])
+    ///         .done()
+    ///         .unwrap();
+    ///
+    ///     // The eigenvalues are 3 and -1.
+    ///     assert!((a.spectral_radius().unwrap() - 3.0).abs() < 1e-9);
+    ///
+    ///     // The Jacobi tolerance is relative to the size of the entries.
+    ///     let b = matrix![4.0, 1.0, 2.0; 1.0, 3.0, 0.0; 2.0, 0.0, 5.0];
+    ///     let radius = b.spectral_radius().unwrap();
+    ///     let large = b.scale(1e8).spectral_radius().unwrap();
+    ///     assert!((large - 1e8 * radius).abs() <= 1e-12 * large);
+    /// }
+    /// ```
+    pub fn spectral_radius(&self) -> Result<f64, MatrixError> {
+        if self.is_symmetric_with_tol(DEFAULT_TOLERANCE) {
+            let (values, _) = self.eigen_symmetric(DEFAULT_TOLERANCE.threshold(self.frobenius_norm()), 100)?;
+            return Ok(values.iter().fold(0.0, |max, v| v.abs().max(max)));
+        }
+
+        let (lambda, _) = self.dominant_eigen(1e-12, 10_000)?;
+        Ok(lambda.abs())
+    }
+
+    /// Returns the Gershgorin disc of every row, as `(center, radius)`: the center is the diagonal element and the
+    /// radius the sum of the absolute values of the rest of the row.
+    ///
+    /// Every eigenvalue, real or complex, lies in the union of the discs, which makes them a cheap bound on the
+    /// spectrum, see `eigenvalue_bounds`. Returns `NotSquare` for non-square matrices.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let a = matrix![4.0, -1.0, 0.5; 1.0, -2.0, 0.0; 0.0, 2.0, 7.0];
+    ///     assert_eq!(a.gershgorin_discs().unwrap(), vec![(4.0, 1.5), (-2.0, 1.0), (7.0, 2.0)]);
+    ///     assert_eq!(matrix![1.0, 2.0].gershgorin_discs(), Err(MatrixError::NotSquare { rows: 1, cols: 2 }));
+    /// }
+    /// ```
+    pub fn gershgorin_discs(&self) -> Result<Vec<(f64, f64)>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        Ok(self.data
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let radius = row.iter().enumerate().filter(|&(j, _)| j != i).map(|(_, x)| x.abs()).sum();
+                (row[i], radius)
+            })
+            .collect())
+    }
+
+    /// Returns `(low, high)` such that the real part of every eigenvalue is in `[low, high]`: the leftmost and
+    /// rightmost points of the union of the `gershgorin_discs`.
+    ///
+    /// The bounds are exact for diagonal matrices and loosen as the off-diagonal elements grow. Returns `NotSquare`
+    /// for non-square matrices.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixOps, add::Add};
+    /// fn main() {
+    ///     // Eigenvalues 3 and -1, discs [0, 2] around 1 twice.
+    ///     assert_eq!(matrix![1.0, 2.0; 2.0, 1.0].eigenvalue_bounds().unwrap(), (-1.0, 3.0));
+    ///     assert_eq!(matrix![2.0, 0.0; 0.0, -5.0].eigenvalue_bounds().unwrap(), (-5.0, 2.0));
+    ///
+    ///     for seed in 0..20 {
+    ///         let b = Matrix::from_seed(5, 5, seed).unwrap();
+    ///         // Symmetric, with elements in [-1, 1).
+    ///         let a = b.add(b.transpose()).unwrap().map_elements(|x| x - 1.0);
+    ///         let (low, high) = a.eigenvalue_bounds().unwrap();
+    ///         let (values, _) = a.eigen_symmetric(1e-12, 100).unwrap();
+    ///         assert!(values.iter().all(|&lambda| low - 1e-12 <= lambda && lambda <= high + 1e-12));
+    ///     }
+    /// }
+    /// ```
+    pub fn eigenvalue_bounds(&self) -> Result<(f64, f64), MatrixError> {
+        let discs = self.gershgorin_discs()?;
+        Ok(discs.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), &(center, radius)| {
+            (low.min(center - radius), high.max(center + radius))
+        }))
+    }
+
+    /// Returns true if the matrix is square and strictly diagonally dominant by rows: every diagonal element is
+    /// larger in absolute value than the sum of the absolute values of the rest of its row.
+    ///
+    /// Equivalently, no Gershgorin disc contains 0, so such a matrix is invertible, and `solve_jacobi` and
+    /// `solve_gauss_seidel` converge on it.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     assert!(matrix![4.0, -1.0, 2.0; 1.0, -3.0, 1.0; 0.0, 2.0, 3.0].is_diagonally_dominant());
+    ///     // Equality is not enough.
+    ///     assert!(!matrix![2.0, 1.0, 1.0; 0.0, 1.0, 0.0; 0.0, 0.0, 1.0].is_diagonally_dominant());
+    ///     assert!(!matrix![1.0, 2.0; 2.0, 1.0].is_diagonally_dominant());
+    ///     assert!(!matrix![1.0, 0.0].is_diagonally_dominant());
+    /// }
+    /// ```
+    pub fn is_diagonally_dominant(&self) -> bool {
+        self.gershgorin_discs().is_ok_and(|discs| discs.iter().all(|&(center, radius)| center.abs() > radius))
+    }
+
+    /// Computes the principal square root of a symmetric positive-definite matrix: the unique symmetric
+    /// positive-definite `S` with `S * S = A`.
+    ///
+    /// It is `V diag(sqrt(λ)) V^T` from the Jacobi eigendecomposition (`eigen_symmetric`), computed as `W W^T` with
+    /// `W = V diag(λ^(1/4))` so the result is exactly symmetric. Unlike a Cholesky factor it is symmetric, which
+    /// suits whitening transforms.
+    ///
+    /// Returns `NotSquare` for non-square matrices, and `NotPositiveDefinite` if the matrix is not symmetric under
+    /// `DEFAULT_TOLERANCE` or if its smallest eigenvalue is within `DEFAULT_TOLERANCE` of 0 (relative to the largest)
+    /// or negative.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, mul::Mul};
+    /// fn main() {
+    ///     // B B^T + I is symmetric positive-definite.
+    ///     let b = Matrix::from_seed(6, 6, 11).unwrap();
+    ///     let mut a = b.mul(&b.transpose()).unwrap();
+    ///     (0..6).for_each(|i| a.data[i][i] += 1.0);
+    ///
+    ///     let s = a.sqrt_spd().unwrap();
+    ///     assert!(s.mul(&s).unwrap().diff(&a, 1e-8).is_equal());
+    ///     assert_eq!(s, s.transpose());
+    ///
+    ///     assert!(matrix![4.0, 0.0; 0.0, 9.0].sqrt_spd().unwrap().diff(&matrix![2.0, 0.0; 0.0, 3.0], 1e-12).is_equal());
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let not_spd = MatrixError::NotPositiveDefinite { operation: "sqrt_spd" };
+    ///     // Eigenvalues 3 and -1.
+    ///     assert_eq!(matrix![1.0, 2.0; 2.0, 1.0].sqrt_spd(), Err(not_spd));
+    ///     // Positive semi-definite only.
+    ///     assert!(matrix![1.0, 1.0; 1.0, 1.0].sqrt_spd().is_err());
+    ///     // Positive eigenvalues, but not symmetric.
+    ///     assert!(matrix![2.0, 1.0; 0.0, 2.0].sqrt_spd().is_err());
+    ///     assert_eq!(matrix![1.0, 2.0].sqrt_spd(), Err(MatrixError::NotSquare { rows: 1, cols: 2 }));
+    /// }
+    /// ```
+    pub fn sqrt_spd(&self) -> Result<Matrix, MatrixError> {
+        const OPERATION: &str = "sqrt_spd";
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        if !self.is_symmetric_with_tol(DEFAULT_TOLERANCE) {
+            return Err(MatrixError::NotPositiveDefinite { operation: OPERATION });
+        }
+
+        // Averaging with the transpose removes the asymmetry that the tolerance let through.
+        let n = self.rows;
+        let data = (0..n).map(|i| (0..n).map(|j| 0.5 * (self.data[i][j] + self.data[j][i])).collect()).collect();
+        let symmetric = Matrix { rows: n, cols: n, data };
+        let (values, vectors) = symmetric.eigen_symmetric(DEFAULT_TOLERANCE.threshold(self.frobenius_norm()), 100)?;
+
+        let (largest, smallest) = (values[0], values[n - 1]);
+        if smallest <= DEFAULT_TOLERANCE.threshold(largest.abs()) {
+            return Err(MatrixError::NotPositiveDefinite { operation: OPERATION });
+        }
+
+        let mut w = vectors;
+        for row in w.data.iter_mut() {
+            row.iter_mut().zip(values.iter()).for_each(|(x, lambda)| *x *= lambda.sqrt().sqrt());
+        }
+        w.mul_transposed(&w)
+    }
+
+    /// Computes the coefficients of the characteristic polynomial with the Faddeev-LeVerrier algorithm, highest degree first.
+    ///
+    /// The polynomial is normalized to be monic, `det(λI - A)`, so the first coefficient is always `1`.
+    /// It only differs from `det(A - λI)` by a factor of `(-1)^n`, and has the same roots (the eigenvalues).
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let a = Matrix::builder()
+    ///         .rows(2)
+    ///         .cols(2)
+    ///         .data(vec![
+    ///             vec![2.0, 1.0],
+    ///             vec![1.0, 3.0],
+    ///         ])
+    ///         .done()
+    ///         .unwrap();
+    ///
+    ///     // [1, -trace, det]
+    ///     assert_eq!(a.characteristic_polynomial().unwrap(), vec![1.0, -5.0, 5.0]);
+    ///
+    ///     let b = Matrix::builder()
+    ///         .rows(3)
+    ///         .cols(3)
+    ///         .data(vec![
+    ///             vec![2.0, 0.0, 0.0],
+    ///             vec![1.0, 3.0, 0.0],
+    ///             vec![4.0, 5.0, 6.0],
+    ///         ])
+    ///         .done()
+    ///         .unwrap();
+    ///
+    ///     // 3 is an eigenvalue of the triangular matrix, so the polynomial vanishes there.
+    ///     let p = b.characteristic_polynomial().unwrap();
+    ///     let at_three = p.iter().fold(0.0, |acc, c| acc * 3.0 + c);
+    ///     assert!(at_three.abs() < 1e-9);
+    /// }
+    /// ```
+    pub fn characteristic_polynomial(&self) -> Result<Vec<f64>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        let n = self.rows;
+        let mut coefficients = vec![1.0];
+        let mut m = Matrix::builder().rows(n).cols(n).done()?;
+
+        for k in 1..=n {
+            // M_k = A * M_(k-1) + c_(n-k+1) * I
+            let mut next = self.mul(&m)?;
+            let previous = coefficients[k - 1];
+            for (i, row) in next.data.iter_mut().enumerate() {
+                row[i] += previous;
+            }
+
+            let am = self.mul(&next)?;
+            coefficients.push(-am.trace()? / k as f64);
+            m = next;
+        }
+
+        Ok(coefficients)
+    }
+}
+
+fn off_diagonal_norm(a: &[Vec<f64>]) -> f64 {
+    let mut sum = 0.0;
+    for (i, row) in a.iter().enumerate() {
+        for (j, value) in row.iter().enumerate() {
+            if i != j {
+                sum += value * value;
+            }
+        }
+    }
+    sum.sqrt()
+}
+
+/// Scales `v` to unit length and returns its previous norm. Zero vectors are left untouched.
+fn normalize(v: &mut [f64]) -> f64 {
+    let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm != 0.0 {
+        v.iter_mut().for_each(|x| *x /= norm);
+    }
+    norm
+}
+
+fn column(v: Vec<f64>) -> Matrix {
+    Matrix {
+        rows: v.len(),
+        cols: 1,
+        data: v.into_iter().map(|x| vec![x]).collect(),
+    }
+}
@@ -0,0 +1,88 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+impl Matrix {
+    /// Returns `ShapeMismatch` for `operation` if `other` doesn't have the same shape as `self`.
+    pub(crate) fn check_same_shape(&self, other: &Matrix, operation: &'static str) -> Result<(), MatrixError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MatrixError::ShapeMismatch {
+                operation,
+                left: (self.rows, self.cols),
+                right: (other.rows, other.cols),
+            });
+        }
+        Ok(())
+    }
+
+    /// Combines two matrices of the same shape element by element with `f`.
+    ///
+    /// Different shapes return `ShapeMismatch` with both shapes.
+    /// # Examples
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     // Hadamard (element-wise) product.
+    ///     let a = matrix![1.0, 2.0; 3.0, 4.0];
+    ///     let b = matrix![5.0, 6.0; 7.0, 8.0];
+    ///
+    ///     assert_eq!(a.zip_with(&b, |x, y| x * y).unwrap(), matrix![5.0, 12.0; 21.0, 32.0]);
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     // Element-wise maximum, and the error on mismatched shapes.
+    ///     let a = matrix![1.0, 7.0; -3.0, 4.0];
+    ///     let b = matrix![2.0, 6.0; -5.0, 4.0];
+    ///
+    ///     assert_eq!(a.zip_with(&b, f64::max).unwrap(), matrix![2.0, 7.0; -3.0, 4.0]);
+    ///
+    ///     let c = matrix![1.0, 2.0, 3.0];
+    ///     let error = a.zip_with(&c, f64::max).unwrap_err();
+    ///     assert_eq!(error, MatrixError::ShapeMismatch { operation: "zip_with", left: (2, 2), right: (1, 3) });
+    ///     assert_eq!(error.to_string(), "ShapeMismatch: `zip_with` got incompatible shapes 2x2 and 1x3");
+    /// }
+    /// ```
+    pub fn zip_with(&self, other: &Matrix, f: impl Fn(f64, f64) -> f64) -> Result<Matrix, MatrixError> {
+        self.check_same_shape(other, "zip_with")?;
+
+        let data = self.data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(a, b)| a.iter().zip(b.iter()).map(|(&x, &y)| f(x, y)).collect())
+            .collect();
+        Ok(Matrix { rows: self.rows, cols: self.cols, data })
+    }
+
+    /// Combines three matrices of the same shape element by element with `f`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     // Blend: a * mask + b * (1 - mask)
+    ///     let a = matrix![1.0, 1.0; 1.0, 1.0];
+    ///     let b = matrix![9.0, 9.0; 9.0, 9.0];
+    ///     let mask = matrix![1.0, 0.0; 0.5, 0.0];
+    ///
+    ///     let blended = a.zip_with3(&b, &mask, |x, y, m| x * m + y * (1.0 - m)).unwrap();
+    ///
+    ///     assert_eq!(blended, matrix![1.0, 9.0; 5.0, 9.0]);
+    /// }
+    /// ```
+    pub fn zip_with3(&self, second: &Matrix, third: &Matrix, f: impl Fn(f64, f64, f64) -> f64) -> Result<Matrix, MatrixError> {
+        self.check_same_shape(second, "zip_with3")?;
+        self.check_same_shape(third, "zip_with3")?;
+
+        let data = self.data
+            .iter()
+            .zip(second.data.iter().zip(third.data.iter()))
+            .map(|(a, (b, c))| {
+                a.iter()
+                    .zip(b.iter().zip(c.iter()))
+                    .map(|(&x, (&y, &z))| f(x, y, z))
+                    .collect()
+            })
+            .collect();
+        Ok(Matrix { rows: self.rows, cols: self.cols, data })
+    }
+}
@@ -0,0 +1,200 @@
+use crate::matrix::*;
+use crate::imatrix::IMatrix;
+use crate::errors::MatrixError;
+use crate::tolerance::{approx_eq_scalar, Tolerance};
+
+/// How far from an integer an element may be and still be read as that integer by the exact methods.
+const INTEGER_TOLERANCE: Tolerance = Tolerance { absolute: 1e-9, relative: 0.0 };
+
+impl Matrix {
+    /// Computes the determinant exactly with fraction-free (Bareiss) elimination, as a `(numerator, denominator)`
+    /// fraction.
+    ///
+    /// Every element must be within `1e-9` of an integer, otherwise it returns `NotInteger` with the first
+    /// offending element; for such inputs the determinant is an integer and the denominator is always 1.
+    /// Every intermediate value of Bareiss elimination is itself a minor of the matrix, so nothing is rounded,
+    /// and an intermediate product that does not fit in an `i128` returns `Overflow`.
+    /// Returns `NotSquare` for non-square matrices.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let a = matrix![2.0, -1.0, 0.0; -1.0, 2.0, -1.0; 0.0, -1.0, 2.0];
+    ///     assert_eq!(a.determinant_exact(), Ok((4, 1)));
+    ///
+    ///     // No rounding residue, where float elimination can leave one.
+    ///     let singular = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 9.0];
+    ///     assert_eq!(singular.determinant_exact(), Ok((0, 1)));
+    ///     assert_eq!(matrix![0.0, 1.0; 1.0, 0.0].determinant_exact(), Ok((-1, 1)));
+    ///     assert_eq!(matrix![3.0 + 1e-12, 1.0; 1.0, 1.0].determinant_exact(), Ok((2, 1)));
+    ///
+    ///     assert_eq!(matrix![1.0, 0.5; 0.0, 1.0].determinant_exact(), Err(MatrixError::NotInteger { row: 0, col: 1, value: 0.5 }));
+    ///     let huge = Matrix::identity(3).unwrap().scale(1e18);
+    ///     assert_eq!(huge.determinant_exact(), Err(MatrixError::Overflow { operation: "determinant_exact" }));
+    /// }
+    /// ```
+    pub fn determinant_exact(&self) -> Result<(i128, i128), MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        let mut a = self.to_integers("determinant_exact")?;
+        let n = self.rows;
+        let mut sign = 1;
+        let mut previous = 1;
+        for k in 0..n {
+            let Some(pivot_row) = (k..n).find(|&i| a[i][k] != 0) else {
+                return Ok((0, 1));
+            };
+            if pivot_row != k {
+                a.swap(pivot_row, k);
+                sign = -sign;
+            }
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    a[i][j] = bareiss_step(a[k][k], a[i][j], a[i][k], a[k][j], previous, "determinant_exact")?;
+                }
+                a[i][k] = 0;
+            }
+            previous = a[k][k];
+        }
+        Ok((sign * a[n - 1][n - 1], 1))
+    }
+
+    /// Computes the inverse exactly as a `(numerators, denominator)` pair: `A^-1 = numerators / denominator`,
+    /// with a positive denominator and the fraction in lowest terms.
+    ///
+    /// This is fraction-free Gauss-Jordan (Bareiss) elimination on `[A | I]`, which ends with `[d I | d A^-1]`
+    /// for `d = ±det(A)`, so `A * numerators == denominator * I` holds exactly. Errors are the same as
+    /// `determinant_exact`, plus `Singular` if the determinant is 0 and `Overflow` if a numerator or the
+    /// denominator does not fit in an `i64` (the type of `IMatrix`).
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, IMatrix, Matrix, MatrixError, mul::Mul};
+    /// fn main() {
+    ///     let a = matrix![2.0, -1.0, 0.0; -1.0, 2.0, -1.0; 0.0, -1.0, 2.0];
+    ///     let (numerators, denominator) = a.inverse_exact().unwrap();
+    ///     assert_eq!(denominator, 4);
+    ///     assert_eq!(numerators.data, vec![vec![3, 2, 1], vec![2, 4, 2], vec![1, 2, 3]]);
+    ///
+    ///     let a_int = IMatrix::builder().rows(3).cols(3).data(vec![vec![2, -1, 0], vec![-1, 2, -1], vec![0, -1, 2]]).done().unwrap();
+    ///     let mut scaled_identity = IMatrix::identity(3).unwrap();
+    ///     scaled_identity.data.iter_mut().enumerate().for_each(|(i, row)| row[i] = denominator as i64);
+    ///     assert_eq!(a_int.mul(numerators).unwrap(), scaled_identity);
+    ///
+    ///     // Lowest terms: the inverse of 2 I is I / 2, not 2 I / 4.
+    ///     let (numerators, denominator) = Matrix::identity(2).unwrap().scale(2.0).inverse_exact().unwrap();
+    ///     assert_eq!((numerators, denominator), (IMatrix::identity(2).unwrap(), 2));
+    ///
+    ///     assert_eq!(
+    ///         matrix![1.0, 2.0; 2.0, 4.0].inverse_exact(),
+    ///         Err(MatrixError::Singular { operation: "inverse_exact" })
+    ///     );
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{IMatrix, Matrix, mul::Mul};
+    /// fn main() {
+    ///     // Random integer matrices, which need pivoting more often than not.
+    ///     for seed in 0..20 {
+    ///         let a = Matrix::from_seed(5, 5, seed).unwrap().scale(5.0).rounded(0);
+    ///         let Ok((numerators, denominator)) = a.inverse_exact() else { continue };
+    ///         assert!(denominator > 0);
+    ///
+    ///         let data = a.data.iter().map(|row| row.iter().map(|&x| x as i64).collect()).collect();
+    ///         let a_int = IMatrix::builder().rows(5).cols(5).data(data).done().unwrap();
+    ///         let product = a_int.mul(numerators).unwrap();
+    ///         for (i, row) in product.data.iter().enumerate() {
+    ///             for (j, &value) in row.iter().enumerate() {
+    ///                 assert_eq!(value, if i == j { denominator as i64 } else { 0 });
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn inverse_exact(&self) -> Result<(IMatrix, i128), MatrixError> {
+        const OPERATION: &str = "inverse_exact";
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        let n = self.rows;
+        let mut a = self.to_integers(OPERATION)?;
+        for (i, row) in a.iter_mut().enumerate() {
+            row.extend((0..n).map(|j| i128::from(i == j)));
+        }
+
+        let mut previous = 1;
+        for k in 0..n {
+            let Some(pivot_row) = (k..n).find(|&i| a[i][k] != 0) else {
+                return Err(MatrixError::Singular { operation: OPERATION });
+            };
+            a.swap(pivot_row, k);
+            let pivot = a[k].clone();
+            for (i, row) in a.iter_mut().enumerate() {
+                if i == k {
+                    continue;
+                }
+                for j in (0..2 * n).filter(|&j| j != k) {
+                    row[j] = bareiss_step(pivot[k], row[j], row[k], pivot[j], previous, OPERATION)?;
+                }
+                row[k] = 0;
+            }
+            previous = pivot[k];
+        }
+
+        // Every diagonal element is now `d`, and the right half is `d A^-1`.
+        let d = a[0][0];
+        let sign = d.signum();
+        let divisor = a.iter().flat_map(|row| row[n..].iter()).fold(d.abs(), |g, &x| gcd(g, x.abs()));
+        let overflow = |_| MatrixError::Overflow { operation: OPERATION };
+        let data = a
+            .iter()
+            .map(|row| row[n..].iter().map(|&x| i64::try_from(sign * x / divisor).map_err(overflow)).collect())
+            .collect::<Result<_, _>>()?;
+        let denominator = d.abs() / divisor;
+        i64::try_from(denominator).map_err(overflow)?;
+        Ok((IMatrix { rows: n, cols: n, data }, denominator))
+    }
+
+    /// Converts every element to the integer it is within `INTEGER_TOLERANCE` of.
+    fn to_integers(&self, operation: &'static str) -> Result<Vec<Vec<i128>>, MatrixError> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(row, values)| {
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(col, &value)| {
+                        let rounded = value.round();
+                        if !value.is_finite() || !approx_eq_scalar(value, rounded, INTEGER_TOLERANCE) {
+                            return Err(MatrixError::NotInteger { row, col, value });
+                        }
+                        if rounded.abs() >= i64::MAX as f64 {
+                            return Err(MatrixError::Overflow { operation });
+                        }
+                        Ok(rounded as i128)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// One Bareiss update, `(pivot * x - below * right) / previous`, where the division is always exact.
+fn bareiss_step(pivot: i128, x: i128, below: i128, right: i128, previous: i128, operation: &'static str) -> Result<i128, MatrixError> {
+    pivot
+        .checked_mul(x)
+        .zip(below.checked_mul(right))
+        .and_then(|(a, b)| a.checked_sub(b))
+        .map(|numerator| numerator / previous)
+        .ok_or(MatrixError::Overflow { operation })
+}
+
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
@@ -0,0 +1,90 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+impl Matrix {
+    fn require_square(&self) -> Result<(), MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        Ok(())
+    }
+
+    /// Checks if the matrix is an adjacency matrix: square, with every element within `eps` of 0 or 1.
+    ///
+    /// Self-loops (1 on the diagonal) and directed graphs (non-symmetric matrices) are allowed.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     assert!(matrix![0.0, 1.0; 1.0, 0.0].is_adjacency(1e-12));
+    ///     assert!(!matrix![0.0, 2.0; 1.0, 0.0].is_adjacency(1e-12));
+    ///     assert!(!matrix![0.0, 1.0].is_adjacency(1e-12));
+    /// }
+    /// ```
+    pub fn is_adjacency(&self, eps: f64) -> bool {
+        self.rows == self.cols
+            && self.data.iter().flatten().all(|&x| x.abs() <= eps || (x - 1.0).abs() <= eps)
+    }
+
+    /// Returns the degree matrix, a diagonal matrix of the row sums (out-degrees).
+    ///
+    /// Returns `NotSquare` for non-square matrices.
+    pub fn degree_matrix(&self) -> Result<Matrix, MatrixError> {
+        self.require_square()?;
+        let degrees: Vec<f64> = self.data.iter().map(|row| row.iter().sum()).collect();
+        Matrix::from_diagonal(&degrees)
+    }
+
+    /// Returns the graph Laplacian `D - A`, where `D` is the degree matrix.
+    ///
+    /// Returns `NotSquare` for non-square matrices. Every row of the Laplacian sums to zero.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let triangle = matrix![0.0, 1.0, 1.0; 1.0, 0.0, 1.0; 1.0, 1.0, 0.0];
+    ///
+    ///     let laplacian = triangle.laplacian().unwrap();
+    ///     assert_eq!(laplacian, matrix![2.0, -1.0, -1.0; -1.0, 2.0, -1.0; -1.0, -1.0, 2.0]);
+    ///     assert!(laplacian.fold_rows(|row| row.iter().sum()).iter().all(|&s| s == 0.0));
+    /// }
+    /// ```
+    pub fn laplacian(&self) -> Result<Matrix, MatrixError> {
+        let mut result = self.degree_matrix()?;
+        for (row, a_row) in result.data.iter_mut().zip(self.data.iter()) {
+            for (value, a) in row.iter_mut().zip(a_row.iter()) {
+                *value -= a;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Counts the walks of exactly `length` edges from vertex `from` to vertex `to`, as `A^length[from][to]`.
+    ///
+    /// Returns `NotSquare` for non-square matrices and `IndexOutOfBounds` if a vertex is not in the graph.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let triangle = matrix![0.0, 1.0, 1.0; 1.0, 0.0, 1.0; 1.0, 1.0, 0.0];
+    ///
+    ///     // Between adjacent vertices there is the direct edge and the walk through the third vertex.
+    ///     assert_eq!(triangle.count_paths(0, 1, 1).unwrap(), 1.0);
+    ///     assert_eq!(triangle.count_paths(0, 1, 2).unwrap(), 1.0);
+    ///     // From a vertex back to itself in 2 steps, through either neighbour.
+    ///     assert_eq!(triangle.count_paths(0, 0, 2).unwrap(), 2.0);
+    ///
+    ///     assert_eq!(triangle.count_paths(0, 3, 2), Err(MatrixError::IndexOutOfBounds { index: 3, bound: 3 }));
+    /// }
+    /// ```
+    pub fn count_paths(&self, from: usize, to: usize, length: u32) -> Result<f64, MatrixError> {
+        self.require_square()?;
+        for index in [from, to] {
+            if index >= self.rows {
+                return Err(MatrixError::IndexOutOfBounds { index, bound: self.rows });
+            }
+        }
+
+        Ok(self.pow(length)?.data[from][to])
+    }
+}
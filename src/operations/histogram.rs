@@ -0,0 +1,174 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+/// What `Matrix::histogram_with` does with the values outside the histogram range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutOfRange {
+    /// Values outside the range are not counted, like numpy.
+    #[default]
+    Drop,
+    /// Values below the range are counted in the first bin and values above it in the last one.
+    Clamp,
+}
+
+impl Matrix {
+    /// Counts the elements in `bins` equal-width bins, returning the `bins + 1` edges and the `bins` counts.
+    ///
+    /// Values outside the range are dropped, see `histogram_with`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let a = matrix![0.0, 1.0, 2.0; 2.5, 3.0, 4.0];
+    ///
+    ///     let (edges, counts) = a.histogram(4, None).unwrap();
+    ///     assert_eq!(edges, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+    ///     // Bins are [0, 1), [1, 2), [2, 3) and [3, 4]: a value on an edge goes to the bin on its right,
+    ///     // except the maximum which stays in the last bin.
+    ///     assert_eq!(counts, vec![1, 1, 2, 2]);
+    ///
+    ///     let (edges, counts) = a.histogram(2, Some((1.0, 3.0))).unwrap();
+    ///     assert_eq!(edges, vec![1.0, 2.0, 3.0]);
+    ///     assert_eq!(counts, vec![1, 3]);
+    ///
+    ///     // A constant matrix gets a range of width 1 around its value.
+    ///     assert_eq!(matrix![5.0, 5.0].histogram(2, None).unwrap(), (vec![4.5, 5.0, 5.5], vec![0, 2]));
+    ///
+    ///     assert!(matches!(a.histogram(0, None), Err(MatrixError::InvalidOperation(_))));
+    ///     assert!(matches!(a.histogram(3, Some((2.0, 1.0))), Err(MatrixError::InvalidOperation(_))));
+    /// }
+    /// ```
+    pub fn histogram(&self, bins: usize, range: Option<(f64, f64)>) -> Result<(Vec<f64>, Vec<usize>), MatrixError> {
+        self.histogram_with(bins, range, OutOfRange::Drop)
+    }
+
+    /// Counts the elements in `bins` equal-width bins over `range`, or over the smallest and largest finite
+    /// elements if it is `None`, with `out_of_range` deciding where the values outside it go.
+    ///
+    /// Every bin is half-open, `[edges[i], edges[i + 1])`, except the last one which also contains its upper edge.
+    /// `NaN` elements are never counted. Returns `InvalidOperation` if `bins` is 0, if `range` is not finite with
+    /// `min < max`, or if there is no finite element to compute the range from.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, histogram::OutOfRange};
+    /// fn main() {
+    ///     let a = matrix![-10.0, 0.0, 0.5; 1.0, 7.0, f64::NAN];
+    ///
+    ///     let (_, dropped) = a.histogram_with(2, Some((0.0, 1.0)), OutOfRange::Drop).unwrap();
+    ///     assert_eq!(dropped, vec![1, 2]);
+    ///     let (_, clamped) = a.histogram_with(2, Some((0.0, 1.0)), OutOfRange::Clamp).unwrap();
+    ///     assert_eq!(clamped, vec![2, 3]);
+    ///
+    ///     // Without a range, infinite values fall outside the one computed from the finite elements.
+    ///     let b = matrix![f64::NEG_INFINITY, 0.0, 2.0];
+    ///     assert_eq!(b.histogram_with(2, None, OutOfRange::Drop).unwrap().1, vec![1, 1]);
+    ///     assert_eq!(b.histogram_with(2, None, OutOfRange::Clamp).unwrap().1, vec![2, 1]);
+    ///
+    ///     // Edges that are not exact in binary still get the values equal to them on the right side.
+    ///     let c = matrix![0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0];
+    ///     let (edges, counts) = c.histogram(10, None).unwrap();
+    ///     assert_eq!(counts, vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 2]);
+    ///     assert_eq!(c.digitize(&edges).unwrap().data[0][..10], [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]);
+    ///
+    ///     // A range wider than `f64::MAX` still has finite edges.
+    ///     let (edges, counts) = matrix![-1e308, 0.0, 1e308].histogram(4, None).unwrap();
+    ///     assert_eq!(edges, vec![-1e308, -5e307, 0.0, 5e307, 1e308]);
+    ///     assert_eq!(counts, vec![1, 0, 1, 1]);
+    /// }
+    /// ```
+    pub fn histogram_with(
+        &self,
+        bins: usize,
+        range: Option<(f64, f64)>,
+        out_of_range: OutOfRange,
+    ) -> Result<(Vec<f64>, Vec<usize>), MatrixError> {
+        if bins == 0 {
+            return Err(MatrixError::InvalidOperation("histogram requires at least one bin"));
+        }
+        let (min, max) = match range {
+            Some(range) => range,
+            None => {
+                let finite = self.data.iter().flatten().copied().filter(|x| x.is_finite());
+                let (min, max) = finite.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| (min.min(x), max.max(x)));
+                if min > max {
+                    return Err(MatrixError::InvalidOperation("histogram requires a finite element or an explicit range"));
+                }
+                if min == max { (min - 0.5, max + 0.5) } else { (min, max) }
+            }
+        };
+        if !(min.is_finite() && max.is_finite() && min < max) {
+            return Err(MatrixError::InvalidOperation("histogram range must be finite with min < max"));
+        }
+
+        // `max - min` overflows for ranges wider than `f64::MAX`, halves and interpolation never do.
+        let half_width = (max / 2.0 - min / 2.0) / bins as f64;
+        let span = max - min;
+        let mut edges: Vec<f64> = (0..bins)
+            .map(|i| {
+                let t = i as f64 / bins as f64;
+                if span.is_finite() { min + span * t } else { min * (1.0 - t) + max * t }
+            })
+            .collect();
+        edges.push(max);
+
+        let mut counts = vec![0; bins];
+        for &x in self.data.iter().flatten() {
+            let bin = if x.is_nan() {
+                None
+            } else if x < min {
+                (out_of_range == OutOfRange::Clamp).then_some(0)
+            } else if x > max {
+                (out_of_range == OutOfRange::Clamp).then_some(bins - 1)
+            } else {
+                // The division can be off by one next to an edge, the comparisons with the edges settle it.
+                let mut bin = (((x / 2.0 - min / 2.0) / half_width) as usize).min(bins - 1);
+                if x < edges[bin] {
+                    bin -= 1;
+                } else if bin + 1 < bins && x >= edges[bin + 1] {
+                    bin += 1;
+                }
+                Some(bin)
+            };
+            if let Some(bin) = bin {
+                counts[bin] += 1;
+            }
+        }
+        Ok((edges, counts))
+    }
+
+    /// Replaces every element by the index of its bin in `edges`, as `f64`, like `numpy.digitize`.
+    ///
+    /// The result is `i` such that `edges[i - 1] <= x < edges[i]`: 0 below the first edge and `edges.len()` from the
+    /// last one on. `NaN` elements stay `NaN`. Returns `InvalidOperation` if `edges` is empty or not strictly increasing.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let edges = [0.0, 1.0, 2.5];
+    ///     let a = matrix![-1.0, 0.0, 0.5; 1.0, 2.5, f64::NAN];
+    ///
+    ///     let bins = a.digitize(&edges).unwrap();
+    ///     assert_eq!(bins.data[0], vec![0.0, 1.0, 1.0]);
+    ///     assert_eq!(bins.data[1][..2], [2.0, 3.0]);
+    ///     assert!(bins.data[1][2].is_nan());
+    ///
+    ///     assert!(matches!(a.digitize(&[]), Err(MatrixError::InvalidOperation(_))));
+    ///     assert!(matches!(a.digitize(&[1.0, 1.0]), Err(MatrixError::InvalidOperation(_))));
+    /// }
+    /// ```
+    pub fn digitize(&self, edges: &[f64]) -> Result<Matrix, MatrixError> {
+        if edges.is_empty() || !edges.windows(2).all(|pair| pair[0] < pair[1]) {
+            return Err(MatrixError::InvalidOperation("digitize requires strictly increasing edges"));
+        }
+
+        let data = self.data
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&x| if x.is_nan() { x } else { edges.partition_point(|&edge| edge <= x) as f64 })
+                    .collect()
+            })
+            .collect();
+        Ok(Matrix { rows: self.rows, cols: self.cols, data })
+    }
+}
@@ -0,0 +1,336 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+/// Preconditioner for `Matrix::solve_cg_preconditioned`, an approximation `M` of `A` that is cheap to invert.
+///
+/// CG then works on `M^-1 A`, which needs fewer iterations when it is better conditioned than `A`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preconditioner {
+    /// No preconditioning, plain conjugate gradient.
+    None,
+    /// The diagonal of `A`, which fixes badly scaled rows and columns.
+    Jacobi,
+    /// `L L^T` where `L` is the Cholesky factor of `A` restricted to the non-zero pattern of `A` (IC(0)).
+    /// For a dense matrix it is the exact Cholesky factorization.
+    IncompleteCholesky,
+}
+
+/// A `Preconditioner` computed for a given matrix.
+enum PreconditionerFactor {
+    Identity,
+    Diagonal(Vec<f64>),
+    Cholesky(Vec<Vec<f64>>),
+}
+
+impl PreconditionerFactor {
+    fn new(a: &Matrix, precond: Preconditioner) -> Result<Self, MatrixError> {
+        match precond {
+            Preconditioner::None => Ok(Self::Identity),
+            Preconditioner::Jacobi => {
+                let diagonal: Vec<f64> = (0..a.rows).map(|i| a.data[i][i]).collect();
+                if diagonal.contains(&0.0) {
+                    return Err(MatrixError::InvalidOperation("Jacobi preconditioning requires a non-zero diagonal"));
+                }
+                Ok(Self::Diagonal(diagonal))
+            }
+            Preconditioner::IncompleteCholesky => incomplete_cholesky(a).map(Self::Cholesky),
+        }
+    }
+
+    /// Writes `M^-1 r` into `z`, both being `n x 1`.
+    fn apply(&self, r: &Matrix, z: &mut Matrix) {
+        match self {
+            Self::Identity => z.data.clone_from(&r.data),
+            Self::Diagonal(diagonal) => {
+                for ((z, r), d) in z.data.iter_mut().zip(r.data.iter()).zip(diagonal.iter()) {
+                    z[0] = r[0] / d;
+                }
+            }
+            Self::Cholesky(l) => {
+                let n = l.len();
+                for (i, row) in l.iter().enumerate() {
+                    let known: f64 = (0..i).map(|k| row[k] * z.data[k][0]).sum();
+                    z.data[i][0] = (r.data[i][0] - known) / row[i];
+                }
+                for i in (0..n).rev() {
+                    let known: f64 = (i + 1..n).map(|k| l[k][i] * z.data[k][0]).sum();
+                    z.data[i][0] = (z.data[i][0] - known) / l[i][i];
+                }
+            }
+        }
+    }
+}
+
+/// IC(0): Cholesky restricted to the lower non-zero pattern of `a`, failing with `Breakdown` on a non-positive pivot.
+fn incomplete_cholesky(a: &Matrix) -> Result<Vec<Vec<f64>>, MatrixError> {
+    let n = a.rows;
+    let mut l = vec![vec![0.0; n]; n];
+    for k in 0..n {
+        let pivot = a.data[k][k] - l[k][..k].iter().map(|x| x * x).sum::<f64>();
+        if pivot <= 0.0 {
+            return Err(MatrixError::Breakdown { operation: "incomplete_cholesky", index: k });
+        }
+        l[k][k] = pivot.sqrt();
+        for i in k + 1..n {
+            if a.data[i][k] != 0.0 {
+                let known: f64 = (0..k).map(|j| l[i][j] * l[k][j]).sum();
+                l[i][k] = (a.data[i][k] - known) / l[k][k];
+            }
+        }
+    }
+    Ok(l)
+}
+
+impl Matrix {
+    /// Solves `A X = B` with the Jacobi method, where `A` is `self` and `B` can have several columns.
+    ///
+    /// Starting from `X = 0`, every iteration recomputes each unknown from the previous iterate. It stops when the
+    /// relative residual `||B - A X|| / ||B||` (Frobenius norms) is at most `tol` and returns `X` with the number
+    /// of iterations used, or `DidNotConverge` with the last relative residual after `max_iter` iterations.
+    /// Convergence is guaranteed for strictly diagonally dominant matrices.
+    ///
+    /// Returns `NotSquare` and `DimensionMismatch` like `solve`, and `InvalidOperation` right away if a diagonal
+    /// element is zero.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let a = matrix![4.0, 1.0, 1.0; 1.0, 5.0, 2.0; 0.0, 1.0, 3.0];
+    ///     let b = matrix![6.0; 8.0; 4.0];
+    ///     let direct = a.solve(&b).unwrap();
+    ///
+    ///     let (jacobi, jacobi_iterations) = a.solve_jacobi(&b, 1e-12, 200).unwrap();
+    ///     let (seidel, seidel_iterations) = a.solve_gauss_seidel(&b, 1e-12, 200).unwrap();
+    ///     assert!(jacobi.diff(&direct, 1e-10).is_equal());
+    ///     assert!(seidel.diff(&direct, 1e-10).is_equal());
+    ///     // Gauss-Seidel uses the new values as soon as they are known, so it needs fewer iterations.
+    ///     assert!(seidel_iterations < jacobi_iterations);
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     // Not diagonally dominant: both methods diverge.
+    ///     let a = matrix![1.0, 2.0; 3.0, 1.0];
+    ///     let b = matrix![1.0; 1.0];
+    ///
+    ///     assert!(matches!(a.solve_jacobi(&b, 1e-10, 50), Err(MatrixError::DidNotConverge { iterations: 50, .. })));
+    ///     assert!(matches!(a.solve_gauss_seidel(&b, 1e-10, 50), Err(MatrixError::DidNotConverge { iterations: 50, .. })));
+    ///
+    ///     assert!(matches!(matrix![0.0, 1.0; 1.0, 1.0].solve_jacobi(&b, 1e-10, 50), Err(MatrixError::InvalidOperation(_))));
+    /// }
+    /// ```
+    pub fn solve_jacobi(&self, b: &Matrix, tol: f64, max_iter: usize) -> Result<(Matrix, usize), MatrixError> {
+        self.check_iterative_system(b, "solve_jacobi requires a non-zero diagonal")?;
+
+        let mut previous = Matrix::zeros(b.rows, b.cols)?;
+        self.iterate(b, tol, max_iter, |a, x| {
+            previous.data.clone_from(&x.data);
+            for (i, (row, x_row)) in a.data.iter().zip(x.data.iter_mut()).enumerate() {
+                for (j, value) in x_row.iter_mut().enumerate() {
+                    let off_diagonal: f64 = (0..a.cols).filter(|&k| k != i).map(|k| row[k] * previous.data[k][j]).sum();
+                    *value = (b.data[i][j] - off_diagonal) / row[i];
+                }
+            }
+        })
+    }
+
+    /// Solves `A X = B` with the Gauss-Seidel method, which updates the unknowns in place and so uses the new
+    /// values within the same iteration. Stopping criterion and errors are the same as `solve_jacobi`.
+    pub fn solve_gauss_seidel(&self, b: &Matrix, tol: f64, max_iter: usize) -> Result<(Matrix, usize), MatrixError> {
+        self.check_iterative_system(b, "solve_gauss_seidel requires a non-zero diagonal")?;
+
+        self.iterate(b, tol, max_iter, |a, x| {
+            for (i, row) in a.data.iter().enumerate() {
+                for j in 0..x.cols {
+                    let off_diagonal: f64 = (0..a.cols).filter(|&k| k != i).map(|k| row[k] * x.data[k][j]).sum();
+                    x.data[i][j] = (b.data[i][j] - off_diagonal) / row[i];
+                }
+            }
+        })
+    }
+
+    /// Solves `A X = B` with the conjugate gradient method, for a symmetric positive definite `A` (`self`).
+    ///
+    /// Each column of `B` is solved from `X = 0` until its relative residual `||b - A x|| / ||b||` is at most `tol`,
+    /// and the returned count is the largest number of iterations a column needed. In exact arithmetic it
+    /// converges in at most `n` iterations. The loop works on preallocated buffers with `gemm` and `axpy`.
+    ///
+    /// Returns `NotSquare` and `DimensionMismatch` like `solve`, `InvalidOperation` if `A` is not symmetric
+    /// (relative to its largest entry, like the singularity check of `solve`) or turns out not to be positive
+    /// definite, and `DidNotConverge` with the relative residual of the column that failed after `max_iter` iterations.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let n = 30;
+    ///     let a = Matrix::random_spd(n, 11).unwrap();
+    ///     let b = Matrix::from_seed(n, 2, 12).unwrap();
+    ///
+    ///     let (x, iterations) = a.solve_cg(&b, 1e-10, 100).unwrap();
+    ///     assert!(x.diff(&a.solve(&b).unwrap(), 1e-9).is_equal());
+    ///     assert!(iterations <= n);
+    ///
+    ///     assert!(matches!(a.solve_cg(&b, 1e-10, 2), Err(MatrixError::DidNotConverge { iterations: 2, .. })));
+    ///     assert!(matches!(matrix![1.0, 2.0; 0.0, 1.0].solve_cg(&matrix![1.0; 1.0], 1e-10, 10), Err(MatrixError::InvalidOperation(_))));
+    ///     // Symmetric but indefinite.
+    ///     assert!(matches!(matrix![1.0, 0.0; 0.0, -1.0].solve_cg(&matrix![1.0; 1.0], 1e-10, 10), Err(MatrixError::InvalidOperation(_))));
+    /// }
+    /// ```
+    pub fn solve_cg(&self, b: &Matrix, tol: f64, max_iter: usize) -> Result<(Matrix, usize), MatrixError> {
+        self.solve_cg_preconditioned(b, Preconditioner::None, tol, max_iter)
+    }
+
+    /// Solves `A X = B` with the preconditioned conjugate gradient method, see `solve_cg` and `Preconditioner`.
+    ///
+    /// On top of the errors of `solve_cg`, `Preconditioner::Jacobi` returns `InvalidOperation` if a diagonal
+    /// element is zero and `Preconditioner::IncompleteCholesky` returns `Breakdown` if the factorization meets
+    /// a non-positive pivot.
+    /// # Example
+    /// ```
+    /// # use matrix::{Matrix, iterative::Preconditioner, mul::Mul};
+    /// fn main() {
+    ///     // The Hilbert matrix, a classic ill-conditioned SPD matrix (condition number around 1e10 for n = 8).
+    ///     let n = 8;
+    ///     let hilbert = Matrix::from_rows(
+    ///         (0..n).map(|i| (0..n).map(|j| 1.0 / (i + j + 1) as f64).collect()).collect()
+    ///     ).unwrap();
+    ///     let b = Matrix::from_rows(vec![vec![1.0]; n]).unwrap();
+    ///
+    ///     // Rounding errors make plain CG need far more than `n` iterations.
+    ///     let (_, plain) = hilbert.solve_cg(&b, 1e-10, 1000).unwrap();
+    ///     let (_, jacobi) = hilbert.solve_cg_preconditioned(&b, Preconditioner::Jacobi, 1e-10, 1000).unwrap();
+    ///     let (x, cholesky) = hilbert.solve_cg_preconditioned(&b, Preconditioner::IncompleteCholesky, 1e-10, 1000).unwrap();
+    ///
+    ///     assert!(plain > n);
+    ///     // The diagonal of the Hilbert matrix is not what makes it ill-conditioned, so Jacobi barely helps.
+    ///     assert!(jacobi <= plain);
+    ///     assert!(cholesky * 5 < plain);
+    ///     assert!(hilbert.mul(&x).unwrap().diff(&b, 1e-6).is_equal());
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, iterative::Preconditioner};
+    /// fn main() {
+    ///     let b = matrix![1.0; 1.0];
+    ///
+    ///     assert!(matches!(
+    ///         matrix![0.0, 1.0; 1.0, 2.0].solve_cg_preconditioned(&b, Preconditioner::Jacobi, 1e-10, 10),
+    ///         Err(MatrixError::InvalidOperation(_))
+    ///     ));
+    ///     assert_eq!(
+    ///         matrix![1.0, 2.0; 2.0, 1.0].solve_cg_preconditioned(&b, Preconditioner::IncompleteCholesky, 1e-10, 10),
+    ///         Err(MatrixError::Breakdown { operation: "incomplete_cholesky", index: 1 })
+    ///     );
+    /// }
+    /// ```
+    pub fn solve_cg_preconditioned(&self, b: &Matrix, precond: Preconditioner, tol: f64, max_iter: usize) -> Result<(Matrix, usize), MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        if b.rows != self.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let scale = self.data.iter().flatten().fold(0.0, |max: f64, v| v.abs().max(max));
+        if !self.is_symmetric(self.rows as f64 * f64::EPSILON * scale) {
+            return Err(MatrixError::InvalidOperation("solve_cg requires a symmetric matrix"));
+        }
+        let factor = PreconditionerFactor::new(self, precond)?;
+
+        let n = self.rows;
+        let mut x = Matrix::zeros(n, b.cols)?;
+        let [mut x_col, mut r, mut z, mut p, mut ap] = [(); 5].map(|_| Matrix { rows: n, cols: 1, data: vec![vec![0.0]; n] });
+        let mut most_iterations = 0;
+        for j in 0..b.cols {
+            x_col.data.iter_mut().for_each(|row| row[0] = 0.0);
+            r.data.iter_mut().zip(b.data.iter()).for_each(|(row, b_row)| row[0] = b_row[j]);
+            factor.apply(&r, &mut z);
+            p.data.clone_from(&z.data);
+
+            let b_norm = frobenius(&r.data);
+            let mut rz = column_dot(&r, &z);
+            let mut residual = b_norm;
+            let mut iterations = 0;
+            while residual > tol * b_norm {
+                if iterations == max_iter {
+                    return Err(MatrixError::DidNotConverge { iterations, residual: residual / b_norm });
+                }
+                iterations += 1;
+
+                ap.gemm(1.0, self, &p, 0.0)?;
+                let curvature = column_dot(&p, &ap);
+                if curvature <= 0.0 {
+                    return Err(MatrixError::InvalidOperation("solve_cg requires a positive definite matrix"));
+                }
+                let alpha = rz / curvature;
+                x_col.axpy(alpha, &p)?;
+                r.axpy(-alpha, &ap)?;
+                residual = column_dot(&r, &r).sqrt();
+
+                factor.apply(&r, &mut z);
+                let rz_next = column_dot(&r, &z);
+                let beta = rz_next / rz;
+                for (p_row, z_row) in p.data.iter_mut().zip(z.data.iter()) {
+                    p_row[0] = z_row[0] + beta * p_row[0];
+                }
+                rz = rz_next;
+            }
+
+            x.data.iter_mut().zip(x_col.data.iter()).for_each(|(row, value)| row[j] = value[0]);
+            most_iterations = most_iterations.max(iterations);
+        }
+        Ok((x, most_iterations))
+    }
+
+    fn check_iterative_system(&self, b: &Matrix, zero_diagonal: &'static str) -> Result<(), MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        if b.rows != self.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        if (0..self.rows).any(|i| self.data[i][i] == 0.0) {
+            return Err(MatrixError::InvalidOperation(zero_diagonal));
+        }
+        Ok(())
+    }
+
+    /// Runs `step` from `X = 0` until the relative residual of `A X = B` is at most `tol`.
+    fn iterate(&self, b: &Matrix, tol: f64, max_iter: usize, mut step: impl FnMut(&Matrix, &mut Matrix)) -> Result<(Matrix, usize), MatrixError> {
+        let mut x = Matrix::zeros(b.rows, b.cols)?;
+        let b_norm = frobenius(&b.data);
+        if b_norm == 0.0 {
+            return Ok((x, 0));
+        }
+
+        let mut residual = f64::INFINITY;
+        for iteration in 1..=max_iter {
+            step(self, &mut x);
+            residual = self.residual_norm(b, &x) / b_norm;
+            if residual <= tol {
+                return Ok((x, iteration));
+            }
+        }
+        Err(MatrixError::DidNotConverge { iterations: max_iter, residual })
+    }
+
+    /// `||B - A X||` in Frobenius norm, without allocating.
+    fn residual_norm(&self, b: &Matrix, x: &Matrix) -> f64 {
+        let mut sum = 0.0;
+        for (row, b_row) in self.data.iter().zip(b.data.iter()) {
+            for (j, target) in b_row.iter().enumerate() {
+                let ax: f64 = row.iter().zip(x.data.iter()).map(|(a, x_row)| a * x_row[j]).sum();
+                sum += (target - ax).powi(2);
+            }
+        }
+        sum.sqrt()
+    }
+}
+
+fn frobenius(data: &[Vec<f64>]) -> f64 {
+    data.iter().flatten().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+fn column_dot(a: &Matrix, b: &Matrix) -> f64 {
+    a.data.iter().zip(b.data.iter()).map(|(x, y)| x[0] * y[0]).sum()
+}
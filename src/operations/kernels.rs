@@ -0,0 +1,41 @@
+//! Loops shared by `Matrix` (f64) and `Matrix32` (f32), written once over the element type so the two don't drift.
+use std::ops::{Add, Mul};
+
+/// Combines two equally-shaped grids element by element.
+pub(crate) fn zip_rows<T: Copy>(a: &[Vec<T>], b: &[Vec<T>], f: impl Fn(T, T) -> T) -> Vec<Vec<T>> {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| x.iter().zip(y.iter()).map(|(&x, &y)| f(x, y)).collect())
+        .collect()
+}
+
+/// Applies `f` to every element of a grid.
+pub(crate) fn map_rows<T: Copy>(a: &[Vec<T>], f: impl Fn(T) -> T) -> Vec<Vec<T>> {
+    a.iter().map(|row| row.iter().map(|&x| f(x)).collect()).collect()
+}
+
+/// Multiplies an `n x k` grid by a `k x cols` grid, in i-k-j order so the inner loop scans contiguous rows.
+pub(crate) fn mul_rows<T>(a: &[Vec<T>], b: &[Vec<T>], cols: usize) -> Vec<Vec<T>>
+where
+    T: Copy + Default + Add<Output = T> + Mul<Output = T>,
+{
+    a.iter()
+        .map(|row| {
+            let mut result = vec![T::default(); cols];
+            for (x, b_row) in row.iter().zip(b.iter()) {
+                for (value, &y) in result.iter_mut().zip(b_row.iter()) {
+                    *value = *value + *x * y;
+                }
+            }
+            result
+        })
+        .collect()
+}
+
+/// Like `zip_rows`, but stops at the first element for which `f` returns `None`.
+pub(crate) fn try_zip_rows<T: Copy>(a: &[Vec<T>], b: &[Vec<T>], f: impl Fn(T, T) -> Option<T>) -> Option<Vec<Vec<T>>> {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| x.iter().zip(y.iter()).map(|(&x, &y)| f(x, y)).collect())
+        .collect()
+}
@@ -0,0 +1,444 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use crate::tolerance::{Tolerance, DEFAULT_TOLERANCE};
+
+/// Packed LU factorization with partial pivoting: `P * A = L * U`.
+///
+/// `L` (unit diagonal, not stored) and `U` share the same storage,
+/// row `i` of `P * A` is row `perm[i]` of `A`, and `sign` is the sign of the permutation.
+pub(crate) struct Lu {
+    pub(crate) lu: Vec<Vec<f64>>,
+    pub(crate) perm: Vec<usize>,
+    pub(crate) sign: f64,
+}
+
+impl Lu {
+    /// Returns the diagonal of `U`.
+    pub(crate) fn pivots(&self) -> impl Iterator<Item = f64> + '_ {
+        self.lu.iter().enumerate().map(|(i, row)| row[i])
+    }
+
+    /// Solves `A x = b` for one right-hand side, with forward then back substitution.
+    fn solve_column(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.lu.len();
+        let mut x: Vec<f64> = self.perm.iter().map(|&i| b[i]).collect();
+        for i in 0..n {
+            let sum: f64 = self.lu[i][..i].iter().zip(x[..i].iter()).map(|(l, x)| l * x).sum();
+            x[i] -= sum;
+        }
+        for i in (0..n).rev() {
+            let sum: f64 = self.lu[i][i + 1..].iter().zip(x[i + 1..].iter()).map(|(u, x)| u * x).sum();
+            x[i] = (x[i] - sum) / self.lu[i][i];
+        }
+        x
+    }
+}
+
+/// Singularity cutoff of `solve` and `solve_counted` for an `n x n` system: pivots within `n * f64::EPSILON` of 0
+/// relative to the largest entry.
+pub(crate) fn solve_tolerance(n: usize) -> Tolerance {
+    Tolerance::relative(n as f64 * f64::EPSILON)
+}
+
+/// Pivoting strategy of `Matrix::lu_with_options`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Pivoting {
+    /// No row or column exchanges: fails on the first negligible pivot even if the matrix is invertible.
+    None,
+    /// Row exchanges bringing the largest element of the column to the diagonal, like `solve` and `determinant`.
+    #[default]
+    Partial,
+    /// Row and column exchanges bringing the largest element of the remaining submatrix to the diagonal.
+    Full,
+}
+
+/// Result of `Matrix::lu_with_options`: `P * A * Q = L * U`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LuResult {
+    /// Row permutation matrix.
+    pub p: Matrix,
+    /// Column permutation matrix, only for `Pivoting::Full`; otherwise `Q` is the identity.
+    pub q: Option<Matrix>,
+    /// Unit lower triangular factor.
+    pub l: Matrix,
+    /// Upper triangular factor.
+    pub u: Matrix,
+    /// Sign of the permutations, `1.0` or `-1.0`, so the determinant is `sign` times the product of the diagonal of `U`.
+    pub sign: f64,
+    /// Largest absolute element of `U` over the largest absolute element of `A` (1 for the zero matrix).
+    /// Backward error bounds of LU grow with it: values far above 1 mean the factorization can't be trusted.
+    pub growth_factor: f64,
+}
+
+/// Packed factorization shared by `lu_decompose` and `lu_with_options`: row `i` of `P * A * Q` is row `rows[i]`
+/// of `A` and its column `j` is column `cols[j]` of `A`.
+struct Packed {
+    lu: Vec<Vec<f64>>,
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    sign: f64,
+}
+
+impl Matrix {
+    /// Factorizes the matrix with partial pivoting. Zero pivot columns are skipped instead of failing,
+    /// so callers can inspect the pivots of singular matrices.
+    pub(crate) fn lu_decompose(&self) -> Result<Lu, MatrixError> {
+        let Packed { lu, rows, sign, .. } = self.factorize(Pivoting::Partial)?;
+        Ok(Lu { lu, perm: rows, sign })
+    }
+
+    fn factorize(&self, pivoting: Pivoting) -> Result<Packed, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        let n = self.rows;
+        let mut lu = self.data.clone();
+        let mut rows: Vec<usize> = (0..n).collect();
+        let mut cols: Vec<usize> = (0..n).collect();
+        let mut sign = 1.0;
+        let scale = self.data.iter().flatten().fold(0.0, |max: f64, v| v.abs().max(max));
+
+        for k in 0..n {
+            let (pivot_row, pivot_col) = match pivoting {
+                Pivoting::None => (k, k),
+                Pivoting::Partial => {
+                    let row = (k..n).max_by(|&a, &b| lu[a][k].abs().total_cmp(&lu[b][k].abs())).unwrap_or(k);
+                    (row, k)
+                }
+                Pivoting::Full => (k..n)
+                    .flat_map(|i| (k..n).map(move |j| (i, j)))
+                    .max_by(|&(a, b), &(c, d)| lu[a][b].abs().total_cmp(&lu[c][d].abs()))
+                    .unwrap_or((k, k)),
+            };
+
+            if pivot_row != k {
+                lu.swap(pivot_row, k);
+                rows.swap(pivot_row, k);
+                sign = -sign;
+            }
+            if pivot_col != k {
+                lu.iter_mut().for_each(|row| row.swap(pivot_col, k));
+                cols.swap(pivot_col, k);
+                sign = -sign;
+            }
+
+            let pivot = lu[k][k];
+            if pivoting == Pivoting::None && pivot.abs() <= DEFAULT_TOLERANCE.threshold(scale) {
+                return Err(MatrixError::Singular { operation: "lu_with_options" });
+            }
+            if pivot == 0.0 {
+                continue;
+            }
+
+            let (head, tail) = lu.split_at_mut(k + 1);
+            let pivot_values = &head[k];
+            for row in tail.iter_mut() {
+                let factor = row[k] / pivot;
+                row[k] = factor;
+                for (value, p) in row.iter_mut().zip(pivot_values.iter()).skip(k + 1) {
+                    *value -= factor * p;
+                }
+            }
+        }
+
+        Ok(Packed { lu, rows, cols, sign })
+    }
+
+    /// Factorizes a square matrix as `P * A * Q = L * U` with the given `pivoting`, returning the explicit factors
+    /// and the growth factor.
+    ///
+    /// With `Partial` and `Full`, singular matrices are factorized too, with zeros on the diagonal of `U`.
+    /// With `None`, a pivot within `DEFAULT_TOLERANCE` of 0 (relative to the largest absolute entry) returns
+    /// `Singular`, like the negligible pivots of `solve`; negative pivots are fine. Returns `NotSquare` for
+    /// non-square matrices.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, mul::Mul, lu::Pivoting};
+    /// fn main() {
+    ///     // Invertible, but its first pivot is 0.
+    ///     let a = matrix![0.0, 2.0, 1.0; 1.0, 1.0, 0.0; 3.0, 0.0, 1.0];
+    ///     assert_eq!(
+    ///         a.lu_with_options(Pivoting::None),
+    ///         Err(MatrixError::Singular { operation: "lu_with_options" })
+    ///     );
+    ///
+    ///     let lu = a.lu_with_options(Pivoting::Full).unwrap();
+    ///     let q = lu.q.clone().unwrap();
+    ///     let paq = lu.p.mul(&a).unwrap().mul(&q).unwrap();
+    ///     assert!(paq.diff(&lu.l.mul(&lu.u).unwrap(), 1e-12).is_equal());
+    ///     let det = lu.sign * (0..3).map(|i| lu.u.data[i][i]).product::<f64>();
+    ///     assert!((det - a.determinant().unwrap()).abs() < 1e-12);
+    ///
+    ///     let lu = a.lu_with_options(Pivoting::Partial).unwrap();
+    ///     assert_eq!(lu.q, None);
+    ///     assert!(lu.p.mul(&a).unwrap().diff(&lu.l.mul(&lu.u).unwrap(), 1e-12).is_equal());
+    ///
+    ///     // A tiny pivot without pivoting: no error, but the growth factor gives it away.
+    ///     let b = matrix![1e-10, 1.0; 1.0, 1.0];
+    ///     assert!(b.lu_with_options(Pivoting::None).unwrap().growth_factor > 1e9);
+    ///     assert!(b.lu_with_options(Pivoting::Partial).unwrap().growth_factor <= 1.0 + 1e-12);
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{Matrix, lu::Pivoting};
+    /// fn main() {
+    ///     // A variant of Wilkinson's matrix: 1 on the diagonal and in the last column, -0.999 below the diagonal.
+    ///     // Partial pivoting almost doubles the last column at every step, full pivoting does not.
+    ///     let n = 12;
+    ///     let mut w = Matrix::identity(n).unwrap();
+    ///     for i in 0..n {
+    ///         w.data[i][n - 1] = 1.0;
+    ///         for j in 0..i {
+    ///             w.data[i][j] = -0.999;
+    ///         }
+    ///     }
+    ///
+    ///     let partial = w.lu_with_options(Pivoting::Partial).unwrap();
+    ///     assert!(partial.growth_factor > 1e3);
+    ///     let full = w.lu_with_options(Pivoting::Full).unwrap();
+    ///     assert!(full.growth_factor <= 2.0);
+    ///
+    ///     // Singular matrices still factorize with pivoting.
+    ///     let singular = Matrix::from_rows(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+    ///     assert_eq!(singular.lu_with_options(Pivoting::Full).unwrap().u.data[1][1], 0.0);
+    ///     assert!(Matrix::zeros(2, 3).unwrap().lu_with_options(Pivoting::Partial).is_err());
+    /// }
+    /// ```
+    pub fn lu_with_options(&self, pivoting: Pivoting) -> Result<LuResult, MatrixError> {
+        let Packed { lu, rows, cols, sign } = self.factorize(pivoting)?;
+        let n = self.rows;
+
+        let mut l = Matrix::identity(n)?;
+        let mut u = Matrix::zeros(n, n)?;
+        for (i, row) in lu.iter().enumerate() {
+            l.data[i][..i].copy_from_slice(&row[..i]);
+            u.data[i][i..].copy_from_slice(&row[i..]);
+        }
+
+        let mut p = Matrix::zeros(n, n)?;
+        rows.iter().enumerate().for_each(|(i, &r)| p.data[i][r] = 1.0);
+        let q = (pivoting == Pivoting::Full).then(|| {
+            let mut q = Matrix::zeros(n, n).expect("n is not 0");
+            cols.iter().enumerate().for_each(|(j, &c)| q.data[c][j] = 1.0);
+            q
+        });
+
+        let max_abs = |m: &[Vec<f64>]| m.iter().flatten().fold(0.0, |max: f64, v| v.abs().max(max));
+        let scale = max_abs(&self.data);
+        let growth_factor = if scale == 0.0 { 1.0 } else { max_abs(&u.data) / scale };
+        Ok(LuResult { p, q, l, u, sign, growth_factor })
+    }
+
+    /// Computes the determinant with an LU factorization (partial pivoting).
+    ///
+    /// For large matrices the result can overflow to infinity or underflow to zero,
+    /// use `det_sign_log` in that case.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let matrix = Matrix::builder()
+    ///         .rows(2)
+    ///         .cols(2)
+    ///         .data(vec![
+    ///             vec![1.0, 2.0],
+    ///             vec![3.0, 4.0],
+    ///         ])
+    ///         .done()
+    ///         .unwrap();
+    ///
+    ///     assert!((matrix.determinant().unwrap() + 2.0).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn determinant(&self) -> Result<f64, MatrixError> {
+        let lu = self.lu_decompose()?;
+        Ok(lu.pivots().fold(lu.sign, |det, pivot| det * pivot))
+    }
+
+    /// Checks if the matrix is singular by looking at the LU pivots.
+    ///
+    /// The matrix is reported singular if any pivot has an absolute value of at most `eps` times the
+    /// largest absolute entry of the matrix, so the test does not depend on the scale of the matrix and
+    /// does not suffer from the overflow/underflow of the determinant.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let singular = Matrix::builder()
+    ///         .rows(2)
+    ///         .cols(2)
+    ///         .data(vec![
+    ///             vec![1.0, 2.0],
+    ///             vec![2.0, 4.0],
+    ///         ])
+    ///         .done()
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(singular.is_singular(1e-12), Ok(true));
+    ///     assert_eq!(Matrix::identity(3).unwrap().is_singular(1e-12), Ok(false));
+    /// }
+    /// ```
+    pub fn is_singular(&self, eps: f64) -> Result<bool, MatrixError> {
+        self.is_singular_with_tol(Tolerance::relative(eps))
+    }
+
+    /// Checks if the matrix is singular, reporting it singular if any LU pivot is within `tol` of 0
+    /// relative to the largest absolute entry of the matrix, see `Tolerance`. A zero matrix is always singular.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, Tolerance};
+    /// fn main() {
+    ///     // The last pivot of the elimination is -1e-8 for entries around 1.
+    ///     let a = matrix![1.0, 1.0; 1.0, 1.0 - 1e-8];
+    ///
+    ///     assert_eq!(a.is_singular_with_tol(Tolerance::relative(1e-10)), Ok(false));
+    ///     assert_eq!(a.is_singular_with_tol(Tolerance::relative(1e-6)), Ok(true));
+    ///     assert_eq!(a.is_singular_with_tol(Tolerance::absolute(1e-6)), Ok(true));
+    ///     // Scaled by 1e-9: the relative test gives the same answer, the absolute one sees only zeros.
+    ///     let small = a.scale(1e-9);
+    ///     assert_eq!(small.is_singular_with_tol(Tolerance::relative(1e-10)), Ok(false));
+    ///     assert_eq!(small.is_singular_with_tol(Tolerance::absolute(1e-6)), Ok(true));
+    /// }
+    /// ```
+    pub fn is_singular_with_tol(&self, tol: Tolerance) -> Result<bool, MatrixError> {
+        let lu = self.lu_decompose()?;
+        let scale = self.data.iter().flatten().fold(0.0, |max: f64, v| v.abs().max(max));
+        if scale == 0.0 {
+            return Ok(true);
+        }
+
+        let threshold = tol.threshold(scale);
+        let singular = lu.pivots().any(|pivot| pivot.abs() <= threshold);
+        Ok(singular)
+    }
+
+    /// Returns the sign (`-1`, `0` or `1`) and the natural logarithm of the absolute value of the determinant.
+    ///
+    /// The log-determinant stays finite for matrices whose determinant overflows `f64`.
+    /// A singular matrix gives `(0, f64::NEG_INFINITY)`.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     // 50x50, diagonally dominant with 1e10 on the diagonal: det is around 1e500.
+    ///     let data: Vec<Vec<f64>> = (0..50)
+    ///         .map(|i| (0..50).map(|j| if i == j { 1e10 } else { 1e8 * ((i * 7 + j * 3) % 5) as f64 / 5.0 }).collect())
+    ///         .collect();
+    ///     let matrix = Matrix::builder().rows(50).cols(50).data(data).done().unwrap();
+    ///
+    ///     let (sign, log_det) = matrix.det_sign_log().unwrap();
+    ///
+    ///     assert!(matrix.determinant().unwrap().is_infinite());
+    ///     assert_eq!(sign, 1);
+    ///     assert!(log_det.is_finite());
+    ///     assert!((log_det - 50.0 * 1e10_f64.ln()).abs() < 5.0);
+    /// }
+    /// ```
+    pub fn det_sign_log(&self) -> Result<(i8, f64), MatrixError> {
+        let lu = self.lu_decompose()?;
+        let mut sign = lu.sign;
+        let mut log_det = 0.0;
+
+        for pivot in lu.pivots() {
+            if pivot == 0.0 {
+                return Ok((0, f64::NEG_INFINITY));
+            }
+            sign *= pivot.signum();
+            log_det += pivot.abs().ln();
+        }
+
+        Ok((sign as i8, log_det))
+    }
+
+    /// Solves `A X = B` with an LU factorization, where `A` is `self` and `B` can have several columns.
+    ///
+    /// Returns `NotSquare` if `A` is not square, `DimensionMismatch` if `B` does not have as many rows as `A`,
+    /// and `Singular` if a pivot is within `n * f64::EPSILON` of 0 relative to the largest entry of `A`. That cutoff
+    /// predates `Tolerance` and is kept so existing systems keep solving; `solve_with_tol` takes any other one,
+    /// such as `DEFAULT_TOLERANCE`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, Tolerance, DEFAULT_TOLERANCE, mul::Mul};
+    /// fn main() {
+    ///     // The last pivot is 1e-13, below DEFAULT_TOLERANCE but well above 2 * f64::EPSILON.
+    ///     let nearly = matrix![1.0, 1.0; 1.0, 1.0 + 1e-13];
+    ///     assert!(nearly.solve(&matrix![2.0; 2.0]).is_ok());
+    ///     assert!(nearly.solve_with_tol(&matrix![2.0; 2.0], DEFAULT_TOLERANCE).is_err());
+    ///     assert!(nearly.solve_with_tol(&matrix![2.0; 2.0], Tolerance::relative(2.0 * f64::EPSILON)).is_ok());
+    ///
+    ///     let a = matrix![0.0, 2.0, 1.0; 1.0, 1.0, 0.0; 3.0, 0.0, 1.0];
+    ///     let b = matrix![5.0, 1.0; 3.0, 0.0; 5.0, 2.0];
+    ///
+    ///     let x = a.solve(&b).unwrap();
+    ///     assert!(a.mul(&x).unwrap().diff(&b, 1e-12).is_equal());
+    ///
+    ///     let singular = matrix![1.0, 2.0; 2.0, 4.0];
+    ///     assert_eq!(singular.solve(&matrix![1.0; 2.0]), Err(MatrixError::Singular { operation: "solve" }));
+    /// }
+    /// ```
+    pub fn solve(&self, b: &Matrix) -> Result<Matrix, MatrixError> {
+        self.solve_with_tol(b, solve_tolerance(self.rows))
+    }
+
+    /// Solves `A X = B` like `solve`, returning `Singular` if any LU pivot is within `tol` of 0 relative to the
+    /// largest absolute entry of `A`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, Tolerance};
+    /// fn main() {
+    ///     let a = matrix![1.0, 1.0; 1.0, 1.0 - 1e-8];
+    ///     let b = matrix![2.0; 2.0 - 1e-8];
+    ///
+    ///     assert!(a.solve(&b).unwrap().diff(&matrix![1.0; 1.0], 1e-6).is_equal());
+    ///     assert_eq!(
+    ///         a.solve_with_tol(&b, Tolerance::relative(1e-6)),
+    ///         Err(MatrixError::Singular { operation: "solve" })
+    ///     );
+    /// }
+    /// ```
+    pub fn solve_with_tol(&self, b: &Matrix, tol: Tolerance) -> Result<Matrix, MatrixError> {
+        let lu = self.lu_decompose()?;
+        if b.rows != self.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let scale = self.data.iter().flatten().fold(0.0, |max: f64, v| v.abs().max(max));
+        let threshold = tol.threshold(scale);
+        if lu.pivots().any(|pivot| pivot.abs() <= threshold) {
+            return Err(MatrixError::Singular { operation: "solve" });
+        }
+
+        let columns: Vec<Vec<f64>> = (0..b.cols)
+            .map(|j| lu.solve_column(&b.data.iter().map(|row| row[j]).collect::<Vec<f64>>()))
+            .collect();
+        Ok(Matrix::from_rows(columns)?.transpose())
+    }
+
+    /// Returns the inverse, found by solving `A X = I` with `solve`.
+    ///
+    /// Returns `NotSquare` for non-square matrices and `Singular` if `solve` deems the matrix singular.
+    /// Prefer `solve` to multiplying by the inverse when the goal is solving a system.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, mul::Mul};
+    /// fn main() {
+    ///     let a = matrix![2.0, 1.0; 1.0, 3.0];
+    ///     let inverse = a.inverse().unwrap();
+    ///     assert!(inverse.diff(&matrix![0.6, -0.2; -0.2, 0.4], 1e-12).is_equal());
+    ///     assert!(a.mul(&inverse).unwrap().diff(&Matrix::identity(2).unwrap(), 1e-12).is_equal());
+    ///
+    ///     assert_eq!(matrix![1.0, 2.0; 2.0, 4.0].inverse(), Err(MatrixError::Singular { operation: "inverse" }));
+    ///     assert_eq!(matrix![1.0, 2.0, 3.0].inverse(), Err(MatrixError::NotSquare { rows: 1, cols: 3 }));
+    /// }
+    /// ```
+    pub fn inverse(&self) -> Result<Matrix, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        self.solve(&Matrix::identity(self.rows)?).map_err(|error| match error {
+            MatrixError::Singular { .. } => MatrixError::Singular { operation: "inverse" },
+            other => other,
+        })
+    }
+}
@@ -0,0 +1,97 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+impl Matrix {
+    /// Checks if the matrix is row-stochastic: every element is non-negative and every row sums to 1 within `eps`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     assert!(matrix![0.9, 0.1; 0.5, 0.5].is_row_stochastic(1e-12));
+    ///     assert!(!matrix![0.9, 0.2; 0.5, 0.5].is_row_stochastic(1e-12));
+    ///     assert!(!matrix![1.5, -0.5; 0.5, 0.5].is_row_stochastic(1e-12));
+    /// }
+    /// ```
+    pub fn is_row_stochastic(&self, eps: f64) -> bool {
+        self.data.iter().all(|row| {
+            row.iter().all(|&x| x >= 0.0) && (row.iter().sum::<f64>() - 1.0).abs() <= eps
+        })
+    }
+
+    /// Divides every row by its sum, so that each row sums to 1.
+    ///
+    /// Returns `InvalidOperation` if a row sums to zero (an all-zero row, for example).
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let counts = matrix![3.0, 1.0; 0.0, 2.0];
+    ///
+    ///     assert_eq!(counts.normalize_rows_to_stochastic().unwrap(), matrix![0.75, 0.25; 0.0, 1.0]);
+    ///     assert!(matrix![1.0, 1.0; 0.0, 0.0].normalize_rows_to_stochastic().is_err());
+    /// }
+    /// ```
+    pub fn normalize_rows_to_stochastic(&self) -> Result<Matrix, MatrixError> {
+        let data = self.data
+            .iter()
+            .map(|row| {
+                let sum: f64 = row.iter().sum();
+                if sum == 0.0 {
+                    return Err(MatrixError::InvalidOperation("normalize_rows_to_stochastic found a row that sums to zero"));
+                }
+                Ok(row.iter().map(|x| x / sum).collect())
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Matrix { rows: self.rows, cols: self.cols, data })
+    }
+
+    /// Finds the stationary distribution `pi` of a row-stochastic transition matrix, such that `pi P = pi`.
+    ///
+    /// It starts from the uniform distribution and multiplies by `P` until the L1 change of an iteration is
+    /// below `tol`, returning `DidNotConverge` after `max_iter` iterations (periodic chains, for example).
+    /// Non-square matrices return `NotSquare`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     // Sunny stays sunny 90% of the time, rainy stays rainy 50% of the time.
+    ///     let weather = matrix![0.9, 0.1; 0.5, 0.5];
+    ///
+    ///     let pi = weather.stationary_distribution(1e-12, 1000).unwrap();
+    ///     assert!((pi[0] - 5.0 / 6.0).abs() < 1e-10);
+    ///     assert!((pi[1] - 1.0 / 6.0).abs() < 1e-10);
+    ///
+    ///     // Slow convergence is reported instead of returning a poor estimate.
+    ///     let absorbing = matrix![0.5, 0.5; 0.0, 1.0];
+    ///     assert!(matches!(
+    ///         absorbing.stationary_distribution(1e-12, 3),
+    ///         Err(MatrixError::DidNotConverge { iterations: 3, .. })
+    ///     ));
+    /// }
+    /// ```
+    pub fn stationary_distribution(&self, tol: f64, max_iter: usize) -> Result<Vec<f64>, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        let n = self.rows;
+        let mut pi = vec![1.0 / n as f64; n];
+        let mut residual = f64::INFINITY;
+        for _ in 0..max_iter {
+            let mut next = vec![0.0; n];
+            for (p, row) in pi.iter().zip(self.data.iter()) {
+                for (value, a) in next.iter_mut().zip(row.iter()) {
+                    *value += p * a;
+                }
+            }
+
+            residual = next.iter().zip(pi.iter()).map(|(a, b)| (a - b).abs()).sum();
+            pi = next;
+            if residual < tol {
+                return Ok(pi);
+            }
+        }
+
+        Err(MatrixError::DidNotConverge { iterations: max_iter, residual })
+    }
+}
@@ -1,2 +1,44 @@
 pub mod add;
-pub mod mul;
\ No newline at end of file
+pub mod sub;
+pub mod mul;
+pub mod eigen;
+pub mod svd;
+pub mod lu;
+pub mod det;
+pub mod shape;
+pub mod compare;
+pub mod axis;
+pub mod elementwise;
+pub mod scale;
+pub mod graph;
+pub mod markov;
+pub mod pool;
+pub mod batch;
+pub mod pipeline;
+pub mod projection;
+pub mod ode;
+pub mod unchecked;
+pub mod dedup;
+pub mod ops;
+pub mod random;
+pub mod activations;
+pub mod blocks;
+pub mod banded;
+pub mod iterative;
+pub mod counting;
+pub mod select;
+pub mod rref;
+pub mod histogram;
+pub mod summation;
+pub mod covariance;
+pub mod raster;
+pub mod timeseries;
+pub mod outliers;
+pub mod quantile;
+pub mod confusion;
+#[cfg(feature = "exact")]
+pub mod exact;
+
+pub(crate) mod kernels;
+
+pub use ops::MatrixOps;
\ No newline at end of file
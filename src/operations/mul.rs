@@ -0,0 +1,380 @@
+use crate::matrix::*;
+use crate::matrix32::Matrix32;
+use crate::imatrix::IMatrix;
+use crate::cmatrix::CMatrix;
+use crate::errors::MatrixError;
+use crate::operations::kernels::mul_rows;
+pub trait Mul<Other = Self> {
+    type Output;
+
+    /// Multiplies two matrices together.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The left matrix, with `n` columns.
+    /// * `other` - The right matrix, with `n` rows.
+    ///
+    /// # Returns
+    ///
+    /// A new matrix with the rows of `self` and the columns of `other`.
+    fn mul(&self, other: Other) -> Result<Self::Output, MatrixError>;
+}
+
+/// Above this size (largest dimension of the right operand), `mul` transposes the right operand
+/// first and uses `mul_transposed`, so both inner loops scan contiguous rows.
+const TRANSPOSE_THRESHOLD: usize = 64;
+
+/// From this size (largest dimension of either operand) on, `mul` first checks whether an operand is exactly
+/// the identity or zero and skips the multiplication if so. Below it the product is cheap enough to just compute.
+const SPECIAL_OPERAND_THRESHOLD: usize = 16;
+
+impl Mul for Matrix {
+    type Output = Matrix;
+
+    fn mul(&self, other: Self) -> Result<Self::Output, MatrixError> {
+        self.mul(&other)
+    }
+}
+
+impl Mul<&Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn mul(&self, other: &Matrix) -> Result<Self::Output, MatrixError> {
+        if self.cols != other.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        if self.rows.max(self.cols).max(other.cols) >= SPECIAL_OPERAND_THRESHOLD {
+            if let Some(product) = special_product(self, other) {
+                return Ok(product);
+            }
+        }
+
+        if other.rows.max(other.cols) > TRANSPOSE_THRESHOLD {
+            return self.mul_transposed(&other.transpose());
+        }
+
+        let data = mul_rows(&self.data, &other.data, other.cols);
+        Ok(Matrix { rows: self.rows, cols: other.cols, data })
+    }
+}
+
+/// Returns `a * b` without multiplying when `a` or `b` is exactly the identity or zero, or `None` otherwise.
+///
+/// Both checks stop at the first element that doesn't match, so they cost next to nothing for other operands.
+/// The shortcut is only taken when every element of the other operand is finite, since `0 * inf` is `NaN`;
+/// the result is then equal to the computed product (only the sign of a zero may differ).
+/// # Example
+/// ```
+/// # use matrix::{Matrix, mul::Mul};
+/// fn main() {
+///     let n = 40;
+///     let identity = Matrix::identity(n).unwrap();
+///     let zero = Matrix::zeros(n, n).unwrap();
+///     let a = Matrix::from_seed(n, n, 3).unwrap();
+///     let computed = |a: &Matrix, b: &Matrix| a.mul_transposed(&b.transpose()).unwrap();
+///
+///     for (left, right) in [(&identity, &a), (&a, &identity), (&zero, &a), (&a, &zero), (&a, &a)] {
+///         assert_eq!(left.mul(right).unwrap(), computed(left, right));
+///     }
+///     // Almost the identity: no shortcut, the same result as before.
+///     let mut near = identity.clone();
+///     near.data[n - 1][n - 1] = 1.0 + f64::EPSILON;
+///     assert_eq!(near.mul(&a).unwrap(), computed(&near, &a));
+///
+///     // Non-finite values propagate like in the computed product.
+///     let mut b = a.clone();
+///     b.data[0][0] = f64::INFINITY;
+///     assert!(zero.mul(&b).unwrap().data[0][0].is_nan());
+///     assert_eq!(identity.mul(&b).unwrap().data[0][1], computed(&identity, &b).data[0][1]);
+/// }
+/// ```
+fn special_product(a: &Matrix, b: &Matrix) -> Option<Matrix> {
+    let finite = |m: &Matrix| m.data.iter().flatten().all(|x| x.is_finite());
+    if a.is_zero(0.0) || b.is_zero(0.0) {
+        return (finite(a) && finite(b)).then(|| Matrix::zeros(a.rows, b.cols).ok()).flatten();
+    }
+    if a.is_identity(0.0) && finite(b) {
+        return Some(b.clone());
+    }
+    if b.is_identity(0.0) && finite(a) {
+        return Some(a.clone());
+    }
+    None
+}
+
+impl Mul for Matrix32 {
+    type Output = Matrix32;
+
+    fn mul(&self, other: Self) -> Result<Self::Output, MatrixError> {
+        if self.cols != other.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let data = mul_rows(&self.data, &other.data, other.cols);
+        Ok(Matrix32 { rows: self.rows, cols: other.cols, data })
+    }
+}
+
+impl Mul for CMatrix {
+    type Output = CMatrix;
+
+    fn mul(&self, other: Self) -> Result<Self::Output, MatrixError> {
+        if self.cols != other.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let data = mul_rows(&self.data, &other.data, other.cols);
+        Ok(CMatrix { rows: self.rows, cols: other.cols, data })
+    }
+}
+
+impl Mul for IMatrix {
+    type Output = IMatrix;
+
+    /// Multiplies two integer matrices, returning `Overflow` if any product or partial sum does not fit in an `i64`.
+    fn mul(&self, other: Self) -> Result<Self::Output, MatrixError> {
+        if self.cols != other.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let mut data = vec![vec![0i64; other.cols]; self.rows];
+        for (row, result) in self.data.iter().zip(data.iter_mut()) {
+            for (&a, b_row) in row.iter().zip(other.data.iter()) {
+                for (value, &b) in result.iter_mut().zip(b_row.iter()) {
+                    *value = a
+                        .checked_mul(b)
+                        .and_then(|p| value.checked_add(p))
+                        .ok_or(MatrixError::Overflow { operation: "mul" })?;
+                }
+            }
+        }
+        Ok(IMatrix { rows: self.rows, cols: other.cols, data })
+    }
+}
+
+
+impl Matrix {
+    /// Computes `A * B` given `B` already transposed (`other_t = B^T`).
+    ///
+    /// Each element of the result is the dot product of a row of `self` and a row of `other_t`,
+    /// which is cache friendly with row-based storage. `other_t` must have as many columns as `self`.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// # use matrix::mul::Mul;
+    /// fn main() {
+    ///     let n = 80;
+    ///     let a = Matrix::from_rows((0..n).map(|i| (0..n).map(|j| ((i * 3 + j) % 7) as f64 - 3.0).collect()).collect()).unwrap();
+    ///     let b = Matrix::from_rows((0..n).map(|i| (0..n).map(|j| ((i + j * 5) % 11) as f64 * 0.5).collect()).collect()).unwrap();
+    ///
+    ///     let mut naive = Matrix::builder().rows(n).cols(n).done().unwrap();
+    ///     for i in 0..n {
+    ///         for j in 0..n {
+    ///             for k in 0..n {
+    ///                 naive.data[i][j] += a.data[i][k] * b.data[k][j];
+    ///             }
+    ///         }
+    ///     }
+    ///
+    ///     assert_eq!(a.mul_transposed(&b.transpose()).unwrap(), naive);
+    ///     assert_eq!(a.mul(&b).unwrap(), naive);
+    ///     assert!(a.mul_transposed(&Matrix::identity(2).unwrap()).is_err());
+    /// }
+    /// ```
+    pub fn mul_transposed(&self, other_t: &Matrix) -> Result<Matrix, MatrixError> {
+        if self.cols != other_t.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let data = self.data
+            .iter()
+            .map(|row| {
+                other_t.data
+                    .iter()
+                    .map(|col| row.iter().zip(col.iter()).map(|(a, b)| a * b).sum())
+                    .collect()
+            })
+            .collect();
+
+        Ok(Matrix { rows: self.rows, cols: other_t.rows, data })
+    }
+
+    /// Raises a square matrix to the `n`-th power by repeated squaring.
+    ///
+    /// Returns `NotSquare` for non-square matrices. `pow(0)` is the identity.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 1.0; 0.0, 1.0];
+    ///
+    ///     assert_eq!(a.pow(5).unwrap(), matrix![1.0, 5.0; 0.0, 1.0]);
+    ///     assert_eq!(a.pow(0).unwrap(), Matrix::identity(2).unwrap());
+    /// }
+    /// ```
+    pub fn pow(&self, mut n: u32) -> Result<Matrix, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        let mut result = Matrix::identity(self.rows)?;
+        let mut base = self.clone();
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.mul(&base)?;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.mul(&base)?;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Evaluates the polynomial `coeffs[0] * I + coeffs[1] * A + coeffs[2] * A^2 + ...` at the matrix, with
+    /// Horner's method (one product per coefficient after the first).
+    ///
+    /// Coefficients are lowest degree first, the reverse of `characteristic_polynomial`. Returns `NotSquare` for
+    /// non-square matrices and `InvalidOperation` if `coeffs` is empty.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, add::Add};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0; 0.0, 3.0];
+    ///
+    ///     // 1 + 2A + 3A^2, lowest degree first.
+    ///     let expected = Matrix::identity(2).unwrap().add(a.scale(2.0)).unwrap().add(a.pow(2).unwrap().scale(3.0)).unwrap();
+    ///     assert_eq!(a.polyval(&[1.0, 2.0, 3.0]).unwrap(), expected);
+    ///     // Reversed, it is 3 + 2A + A^2: a different matrix.
+    ///     assert_ne!(a.polyval(&[3.0, 2.0, 1.0]).unwrap(), expected);
+    ///     assert_eq!(a.polyval(&[5.0]).unwrap(), matrix![5.0, 0.0; 0.0, 5.0]);
+    ///
+    ///     assert!(a.polyval(&[]).is_err());
+    ///     assert!(matrix![1.0, 2.0].polyval(&[1.0]).is_err());
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     // Cayley-Hamilton: every matrix is a root of its characteristic polynomial.
+    ///     let a = Matrix::from_seed(5, 5, 9).unwrap();
+    ///     let mut coeffs = a.characteristic_polynomial().unwrap();
+    ///     coeffs.reverse();
+    ///     assert!(a.polyval(&coeffs).unwrap().is_zero(1e-10));
+    /// }
+    /// ```
+    pub fn polyval(&self, coeffs: &[f64]) -> Result<Matrix, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        let Some((&highest, rest)) = coeffs.split_last() else {
+            return Err(MatrixError::InvalidOperation("polyval requires at least one coefficient"));
+        };
+
+        let mut result = Matrix::identity(self.rows)?.scale(highest);
+        for &c in rest.iter().rev() {
+            result = result.mul(self)?;
+            result.data.iter_mut().enumerate().for_each(|(i, row)| row[i] += c);
+        }
+        Ok(result)
+    }
+
+    /// Computes `self = alpha * a * b + beta * self` in place, like BLAS `gemm`, without allocating the product.
+    ///
+    /// `a` must have as many columns as `b` has rows (`ShapeMismatch` with their shapes otherwise), and `self`
+    /// must be `a.rows x b.cols` (`ShapeMismatch` with `self` and the product shape otherwise).
+    /// When `beta` is 0 the previous content of `self` is ignored, so NaN or infinities in it don't leak into the result.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, add::Add, mul::Mul};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0, 0.5; -1.0, 0.0, 3.0];
+    ///     let b = matrix![2.0, 1.0; 0.0, -1.0; 4.0, 0.25];
+    ///     let mut c = matrix![1.0, 1.0; -2.0, 0.5];
+    ///
+    ///     let expected = a.mul(&b).unwrap().scale(0.5).add(c.scale(2.0)).unwrap();
+    ///     c.gemm(0.5, &a, &b, 2.0).unwrap();
+    ///     for (x, y) in c.data.iter().flatten().zip(expected.data.iter().flatten()) {
+    ///         assert!((x - y).abs() < 1e-12);
+    ///     }
+    ///
+    ///     // With beta = 0 the old content is overwritten, even if it is NaN.
+    ///     let mut garbage = matrix![f64::NAN, f64::INFINITY; f64::NAN, f64::NAN];
+    ///     garbage.gemm(1.0, &a, &b, 0.0).unwrap();
+    ///     assert_eq!(garbage, a.mul(&b).unwrap());
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0; 3.0, 4.0];
+    ///     let mut c = matrix![0.0, 0.0; 0.0, 0.0];
+    ///
+    ///     assert_eq!(
+    ///         c.gemm(1.0, &a, &matrix![1.0, 2.0, 3.0], 1.0),
+    ///         Err(MatrixError::ShapeMismatch { operation: "gemm", left: (2, 2), right: (1, 3) })
+    ///     );
+    ///     assert_eq!(
+    ///         c.gemm(1.0, &a, &matrix![1.0; 2.0], 1.0),
+    ///         Err(MatrixError::ShapeMismatch { operation: "gemm", left: (2, 2), right: (2, 1) })
+    ///     );
+    ///     assert_eq!(c, matrix![0.0, 0.0; 0.0, 0.0]);
+    /// }
+    /// ```
+    pub fn gemm(&mut self, alpha: f64, a: &Matrix, b: &Matrix, beta: f64) -> Result<(), MatrixError> {
+        if a.cols != b.rows {
+            return Err(MatrixError::ShapeMismatch { operation: "gemm", left: (a.rows, a.cols), right: (b.rows, b.cols) });
+        }
+        if self.rows != a.rows || self.cols != b.cols {
+            return Err(MatrixError::ShapeMismatch { operation: "gemm", left: (self.rows, self.cols), right: (a.rows, b.cols) });
+        }
+
+        for (row, a_row) in self.data.iter_mut().zip(a.data.iter()) {
+            if beta == 0.0 {
+                row.fill(0.0);
+            } else if beta != 1.0 {
+                row.iter_mut().for_each(|value| *value *= beta);
+            }
+            for (x, b_row) in a_row.iter().zip(b.data.iter()) {
+                let factor = alpha * x;
+                for (value, y) in row.iter_mut().zip(b_row.iter()) {
+                    *value += factor * y;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes `self * other` into `out`, like `mul` but reusing the allocations of `out`.
+    ///
+    /// `out` is resized to `self.rows x other.cols` first, then filled with `gemm`, so a buffer of the right shape
+    /// is reused without allocating. Returns `DimensionMismatch` like `mul`, leaving `out` unchanged.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, mul::Mul};
+    /// fn main() {
+    ///     let a = Matrix::from_seed(3, 70, 1).unwrap();
+    ///     let b = Matrix::from_seed(70, 80, 2).unwrap();
+    ///
+    ///     let mut c = Matrix::new();
+    ///     a.mul_into(&b, &mut c).unwrap();
+    ///     assert_eq!(c, a.mul(&b).unwrap());
+    ///
+    ///     let small = matrix![1.0, 2.0; 3.0, 4.0];
+    ///     small.mul_into(&small, &mut c).unwrap();
+    ///     assert_eq!(c, small.mul(&small).unwrap());
+    ///
+    ///     assert_eq!(small.mul_into(&a, &mut c), Err(MatrixError::DimensionMismatch));
+    ///     assert_eq!(c, matrix![7.0, 10.0; 15.0, 22.0]);
+    /// }
+    /// ```
+    pub fn mul_into(&self, other: &Matrix, out: &mut Matrix) -> Result<(), MatrixError> {
+        if self.cols != other.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        out.resize_for_output(self.rows, other.cols);
+        out.gemm(1.0, self, other, 0.0)
+    }
+}
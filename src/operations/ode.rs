@@ -0,0 +1,94 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use crate::operations::mul::Mul;
+
+/// Integration scheme used by `Matrix::evolve`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OdeMethod {
+    /// Explicit Euler, first order: `x + dt * A x`.
+    Euler,
+    /// Classic fourth-order Runge-Kutta.
+    RK4,
+}
+
+/// Returns `x + k * y` for two `n x 1` states.
+fn axpy(x: &Matrix, k: f64, y: &Matrix) -> Matrix {
+    let data = x.data.iter().zip(y.data.iter()).map(|(a, b)| vec![a[0] + k * b[0]]).collect();
+    Matrix { rows: x.rows, cols: 1, data }
+}
+
+impl Matrix {
+    /// Integrates the linear system `x' = A x`, where `A` is `self`, from `x0` for `steps` steps of size `dt`.
+    ///
+    /// Returns the trajectory of `steps + 1` states, starting with `x0`.
+    /// `A` must be square (`NotSquare`) and `x0` an `n x 1` column (`ShapeMismatch`);
+    /// a non-positive `dt` or zero `steps` return `InvalidOperation`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, ode::OdeMethod};
+    /// fn main() {
+    ///     // Harmonic oscillator: the state rotates and its norm is conserved.
+    ///     let a = matrix![0.0, 1.0; -1.0, 0.0];
+    ///     let x0 = matrix![1.0; 0.0];
+    ///     let norm = |x: &Matrix| x.data.iter().map(|r| r[0] * r[0]).sum::<f64>().sqrt();
+    ///
+    ///     let rk4 = a.evolve(&x0, 0.01, 500, OdeMethod::RK4).unwrap();
+    ///     assert_eq!(rk4.len(), 501);
+    ///     assert_eq!(rk4[0], x0);
+    ///     assert!(rk4.iter().all(|x| (norm(x) - 1.0).abs() < 1e-9));
+    ///     // After t = 5, x = (cos 5, -sin 5).
+    ///     assert!((rk4[500].data[0][0] - 5f64.cos()).abs() < 1e-9);
+    ///     assert!((rk4[500].data[1][0] + 5f64.sin()).abs() < 1e-9);
+    ///
+    ///     // Euler spirals outwards: each step multiplies the norm by sqrt(1 + dt^2).
+    ///     let euler = a.evolve(&x0, 0.01, 500, OdeMethod::Euler).unwrap();
+    ///     assert!(norm(&euler[500]) > 1.02);
+    ///
+    ///     assert!(a.evolve(&x0, 0.0, 10, OdeMethod::RK4).is_err());
+    ///     assert!(a.evolve(&x0, 0.1, 0, OdeMethod::RK4).is_err());
+    ///     assert!(a.evolve(&matrix![1.0, 0.0], 0.1, 10, OdeMethod::RK4).is_err());
+    /// }
+    /// ```
+    pub fn evolve(&self, x0: &Matrix, dt: f64, steps: usize, method: OdeMethod) -> Result<Vec<Matrix>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        if x0.rows != self.rows || x0.cols != 1 {
+            return Err(MatrixError::ShapeMismatch {
+                operation: "evolve",
+                left: (self.rows, self.cols),
+                right: (x0.rows, x0.cols),
+            });
+        }
+        if dt <= 0.0 || !dt.is_finite() {
+            return Err(MatrixError::InvalidOperation("evolve requires a positive, finite time step"));
+        }
+        if steps == 0 {
+            return Err(MatrixError::InvalidOperation("evolve requires at least one step"));
+        }
+
+        let mut trajectory = Vec::with_capacity(steps + 1);
+        trajectory.push(x0.clone());
+        for _ in 0..steps {
+            let x = &trajectory[trajectory.len() - 1];
+            let next = match method {
+                OdeMethod::Euler => axpy(x, dt, &self.mul(x)?),
+                OdeMethod::RK4 => {
+                    let k1 = self.mul(x)?;
+                    let k2 = self.mul(&axpy(x, dt / 2.0, &k1))?;
+                    let k3 = self.mul(&axpy(x, dt / 2.0, &k2))?;
+                    let k4 = self.mul(&axpy(x, dt, &k3))?;
+                    let data = (0..x.rows)
+                        .map(|i| {
+                            let slope = k1.data[i][0] + 2.0 * k2.data[i][0] + 2.0 * k3.data[i][0] + k4.data[i][0];
+                            vec![x.data[i][0] + dt / 6.0 * slope]
+                        })
+                        .collect();
+                    Matrix { rows: x.rows, cols: 1, data }
+                }
+            };
+            trajectory.push(next);
+        }
+        Ok(trajectory)
+    }
+}
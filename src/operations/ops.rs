@@ -0,0 +1,218 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use crate::static_matrix::StaticMatrix;
+use crate::view::{MatrixLike, MatrixView, MatrixViewMut, TransposedView};
+use crate::operations::summation::compensated_sum;
+
+/// Reads an element that is known to be inside the shape.
+fn at<M: MatrixLike + ?Sized>(m: &M, row: usize, col: usize) -> f64 {
+    m.get(row, col).expect("index is within the shape")
+}
+
+/// Iterates over row `row`, from `row_slice` when the representation has it, element by element otherwise.
+fn row_values<M: MatrixLike + ?Sized>(m: &M, row: usize) -> Box<dyn Iterator<Item = f64> + '_> {
+    match m.row_slice(row) {
+        Some(values) => Box::new(values.iter().copied()),
+        None => Box::new((0..m.shape().1).map(move |j| at(m, row, j))),
+    }
+}
+
+/// Builds a `rows x cols` matrix from `f(row, col)`.
+fn from_fn(rows: usize, cols: usize, f: impl Fn(usize, usize) -> f64) -> Matrix {
+    let data = (0..rows).map(|i| (0..cols).map(|j| f(i, j)).collect()).collect();
+    Matrix { rows, cols, data }
+}
+
+/// Operations shared by every matrix representation, so generic code can accept any of them.
+///
+/// Everything has a default implementation on top of `MatrixLike`, which is all a new representation
+/// needs to provide; `Matrix` overrides the methods that it can do faster. Results are always an owned `Matrix`,
+/// and element-wise operations accept any other `MatrixLike` as the right-hand side.
+/// The inherent methods of each type (like `Matrix::transpose`) are unchanged, and take precedence with method syntax.
+/// # Example
+/// ```
+/// # use matrix::{matrix, Matrix, MatrixOps, StaticMatrix};
+/// fn energy<M: MatrixOps>(m: &M) -> f64 {
+///     m.map_elements(|x| x * x).sum()
+/// }
+///
+/// fn main() {
+///     let a = matrix![1.0, 2.0, 0.0; 3.0, 4.0, 0.0];
+///     let view = a.view(0..2, 0..2).unwrap();
+///
+///     assert_eq!(energy(&a), 30.0);
+///     assert_eq!(energy(&view), 30.0);
+///     assert_eq!(energy(&a.t()), 30.0);
+///     assert_eq!(energy(&StaticMatrix::new([[1.0, 2.0], [3.0, 4.0]])), 30.0);
+///
+///     assert_eq!(view.matmul(&view).unwrap(), matrix![7.0, 10.0; 15.0, 22.0]);
+///     assert_eq!(a.elementwise_add(&a.t().transpose()).unwrap(), a.scale(2.0));
+///     assert_eq!(MatrixOps::trace(&view), Ok(5.0));
+/// }
+/// ```
+/// ```
+/// # use matrix::{BandedMatrix, Matrix, MatrixOps};
+/// // Written once, for every representation.
+/// fn spectral_radius<M: MatrixOps>(m: &M, iterations: usize) -> f64 {
+///     let n = m.shape().0;
+///     let mut x = Matrix::from_flat(n, 1, (1..=n).map(|i| i as f64).collect()).unwrap();
+///     let mut lambda = 0.0;
+///     for _ in 0..iterations {
+///         let y = m.matmul(&x).unwrap();
+///         lambda = MatrixOps::frobenius_norm(&y) / MatrixOps::frobenius_norm(&x);
+///         x = y.scale(1.0 / MatrixOps::frobenius_norm(&y));
+///     }
+///     lambda
+/// }
+///
+/// fn main() {
+///     let n = 20;
+///     let mut band = BandedMatrix::zeros(n, 1, 1).unwrap();
+///     for i in 0..n {
+///         band.set(i, i, 2.0).unwrap();
+///         if i > 0 {
+///             band.set(i, i - 1, -1.0).unwrap();
+///             band.set(i - 1, i, -1.0).unwrap();
+///         }
+///     }
+///     let dense = band.to_dense();
+///     let padded = Matrix::from_rows((0..n + 1).map(|i| (0..n + 1).map(|j| dense.get(i, j).unwrap_or(9.0)).collect()).collect()).unwrap();
+///
+///     // Dense, banded, view and transposed view give the same results.
+///     let expected = spectral_radius(&dense, 500);
+///     assert!(expected > 3.9 && expected < 4.0);
+///     assert_eq!(spectral_radius(&band, 500), expected);
+///     assert_eq!(spectral_radius(&padded.view(0..n, 0..n).unwrap(), 500), expected);
+///     assert_eq!(spectral_radius(&dense.t(), 500), expected);
+///
+///     assert_eq!(band.sum(), dense.sum());
+///     assert_eq!(MatrixOps::frobenius_norm(&band), dense.frobenius_norm());
+///     assert_eq!(band.to_matrix(), dense);
+///     assert_eq!(band.matmul(&band).unwrap(), dense.matmul(&dense).unwrap());
+///     assert_eq!(band.map_elements(f64::abs), dense.map_elements(f64::abs));
+/// }
+/// ```
+pub trait MatrixOps: MatrixLike {
+    /// Copies the elements into an owned `Matrix`.
+    fn to_matrix(&self) -> Matrix {
+        let (rows, cols) = self.shape();
+        Matrix { rows, cols, data: (0..rows).map(|i| row_values(self, i).collect()).collect() }
+    }
+
+    /// Returns the sum of all the elements, with Neumaier's compensated summation.
+    ///
+    /// The error is at most about `2 * EPSILON` times the result plus a second-order term, whatever the number
+    /// of elements, where naive summation can lose every small element next to a large one.
+    fn sum(&self) -> f64 {
+        compensated_sum((0..self.shape().0).flat_map(|i| row_values(self, i)))
+    }
+
+    /// Returns the Frobenius norm, with the squares summed like `sum`.
+    fn frobenius_norm(&self) -> f64 {
+        compensated_sum((0..self.shape().0).flat_map(|i| row_values(self, i)).map(|x| x * x)).sqrt()
+    }
+
+    /// Applies `f` to every element.
+    fn map_elements(&self, f: impl Fn(f64) -> f64) -> Matrix {
+        let (rows, cols) = self.shape();
+        Matrix { rows, cols, data: (0..rows).map(|i| row_values(self, i).map(&f).collect()).collect() }
+    }
+
+    /// Multiplies every element by `k`.
+    fn scale(&self, k: f64) -> Matrix {
+        self.map_elements(|x| x * k)
+    }
+
+    /// Returns the transpose as an owned matrix.
+    fn transpose(&self) -> Matrix {
+        let (rows, cols) = self.shape();
+        from_fn(cols, rows, |i, j| at(self, j, i))
+    }
+
+    /// Combines two same-shaped matrices element by element, returning `ShapeMismatch` otherwise.
+    fn zip_elements<M: MatrixLike>(&self, other: &M, operation: &'static str, f: impl Fn(f64, f64) -> f64) -> Result<Matrix, MatrixError> {
+        let (rows, cols) = self.shape();
+        if other.shape() != (rows, cols) {
+            return Err(MatrixError::ShapeMismatch { operation, left: (rows, cols), right: other.shape() });
+        }
+        Ok(from_fn(rows, cols, |i, j| f(at(self, i, j), at(other, i, j))))
+    }
+
+    /// Element-wise `self + other`, see `zip_elements`.
+    fn elementwise_add<M: MatrixLike>(&self, other: &M) -> Result<Matrix, MatrixError> {
+        self.zip_elements(other, "elementwise_add", |a, b| a + b)
+    }
+
+    /// Element-wise `self - other`, see `zip_elements`.
+    fn elementwise_sub<M: MatrixLike>(&self, other: &M) -> Result<Matrix, MatrixError> {
+        self.zip_elements(other, "elementwise_sub", |a, b| a - b)
+    }
+
+    /// Matrix product `self * other`, returning `ShapeMismatch` unless `self` has as many columns as `other` has rows.
+    fn matmul<M: MatrixLike>(&self, other: &M) -> Result<Matrix, MatrixError> {
+        let (rows, inner) = self.shape();
+        let (other_rows, cols) = other.shape();
+        if inner != other_rows {
+            return Err(MatrixError::ShapeMismatch { operation: "matmul", left: (rows, inner), right: (other_rows, cols) });
+        }
+        // Row `i` of the product accumulates row `k` of `other` scaled by `self[i][k]`, so both are read by rows.
+        let mut data = vec![vec![0.0; cols]; rows];
+        for (i, result) in data.iter_mut().enumerate() {
+            for (k, a) in row_values(self, i).enumerate() {
+                result.iter_mut().zip(row_values(other, k)).for_each(|(value, b)| *value += a * b);
+            }
+        }
+        Ok(Matrix { rows, cols, data })
+    }
+
+    /// Sum of the diagonal, returning `NotSquare` for non-square matrices.
+    fn trace(&self) -> Result<f64, MatrixError> {
+        let (rows, cols) = self.shape();
+        if rows != cols {
+            return Err(MatrixError::NotSquare { rows, cols });
+        }
+        Ok((0..rows).map(|i| at(self, i, i)).sum())
+    }
+}
+
+impl MatrixOps for Matrix {
+    fn to_matrix(&self) -> Matrix {
+        self.clone()
+    }
+
+    fn sum(&self) -> f64 {
+        compensated_sum(self.data.iter().flatten().copied())
+    }
+
+    fn frobenius_norm(&self) -> f64 {
+        Matrix::frobenius_norm(self)
+    }
+
+    fn scale(&self, k: f64) -> Matrix {
+        Matrix::scale(self, k)
+    }
+
+    fn transpose(&self) -> Matrix {
+        Matrix::transpose(self)
+    }
+
+    fn matmul<M: MatrixLike>(&self, other: &M) -> Result<Matrix, MatrixError> {
+        if self.cols != other.shape().0 {
+            let right = other.shape();
+            return Err(MatrixError::ShapeMismatch { operation: "matmul", left: (self.rows, self.cols), right });
+        }
+        self.mul_view(other)
+    }
+
+    fn trace(&self) -> Result<f64, MatrixError> {
+        Matrix::trace(self)
+    }
+}
+
+impl MatrixOps for MatrixView<'_> {}
+
+impl MatrixOps for MatrixViewMut<'_> {}
+
+impl MatrixOps for TransposedView<'_> {}
+
+impl<const R: usize, const C: usize> MatrixOps for StaticMatrix<R, C> {}
@@ -0,0 +1,98 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use crate::operations::summation::compensated_sum;
+use crate::tolerance::DEFAULT_TOLERANCE;
+
+impl Matrix {
+    /// Returns a mask with 1.0 where the element is more than `threshold` standard deviations away from the mean
+    /// of its column, and 0.0 elsewhere.
+    ///
+    /// The z-scores use the population standard deviation of each column. A column whose standard deviation is
+    /// within `DEFAULT_TOLERANCE` of 0, relative to its largest absolute element, has no outliers. Returns
+    /// `InvalidOperation` if `threshold` is negative or `NaN`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let mut data = Matrix::from_seed(20, 3, 1).unwrap();
+    ///     data.data[7][1] = 25.0;
+    ///
+    ///     let mask = data.zscore_outlier_mask(3.0).unwrap();
+    ///     let mut expected = Matrix::zeros(20, 3).unwrap();
+    ///     expected.data[7][1] = 1.0;
+    ///     assert_eq!(mask, expected);
+    ///
+    ///     // Constant columns, even ones whose mean is not exact in binary, never divide by zero.
+    ///     let constant = matrix![0.1, 5.0; 0.1, 5.0; 0.1, 5.0];
+    ///     assert_eq!(constant.zscore_outlier_mask(0.0).unwrap(), Matrix::zeros(3, 2).unwrap());
+    ///
+    ///     assert!(data.zscore_outlier_mask(-1.0).is_err());
+    /// }
+    /// ```
+    pub fn zscore_outlier_mask(&self, threshold: f64) -> Result<Matrix, MatrixError> {
+        check_outlier_factor(threshold, "z-score threshold must be a non-negative number")?;
+
+        let n = self.rows as f64;
+        let mut mask = Matrix::zeros(self.rows, self.cols)?;
+        for j in 0..self.cols {
+            let mean = compensated_sum(self.data.iter().map(|row| row[j])) / n;
+            let std = (compensated_sum(self.data.iter().map(|row| (row[j] - mean).powi(2))) / n).sqrt();
+            let scale = self.data.iter().fold(0.0, |max: f64, row| row[j].abs().max(max));
+            if std <= DEFAULT_TOLERANCE.threshold(scale) {
+                continue;
+            }
+            for (row, mask_row) in self.data.iter().zip(mask.data.iter_mut()) {
+                if ((row[j] - mean) / std).abs() > threshold {
+                    mask_row[j] = 1.0;
+                }
+            }
+        }
+        Ok(mask)
+    }
+
+    /// Returns a mask with 1.0 where the element is outside `[Q1 - k * IQR, Q3 + k * IQR]` for its column, and 0.0
+    /// elsewhere, the usual box plot fences with `k = 1.5`.
+    ///
+    /// `Q1` and `Q3` are the 0.25 and 0.75 `col_quantiles` and `IQR = Q3 - Q1`; nothing is divided, so a column
+    /// with a zero IQR simply flags every element that differs from its quartiles. Returns `InvalidOperation` if `k`
+    /// is negative or `NaN`, and the errors of `col_quantiles`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let data = matrix![
+    ///         1.0, 10.0;
+    ///         2.0, 11.0;
+    ///         3.0, 12.0;
+    ///         4.0, -40.0;
+    ///         5.0, 13.0
+    ///     ];
+    ///     // Second column: Q1 = 10, Q3 = 12, so the fences are 7 and 15.
+    ///     assert_eq!(data.iqr_outlier_mask(1.5).unwrap(), matrix![0.0, 0.0; 0.0, 0.0; 0.0, 0.0; 0.0, 1.0; 0.0, 0.0]);
+    ///
+    ///     let constant = matrix![2.0; 2.0; 2.0; 2.0; 9.0];
+    ///     assert_eq!(constant.iqr_outlier_mask(1.5).unwrap(), matrix![0.0; 0.0; 0.0; 0.0; 1.0]);
+    ///
+    ///     assert!(data.iqr_outlier_mask(f64::NAN).is_err());
+    /// }
+    /// ```
+    pub fn iqr_outlier_mask(&self, k: f64) -> Result<Matrix, MatrixError> {
+        check_outlier_factor(k, "IQR factor must be a non-negative number")?;
+
+        let q1 = self.col_quantiles(0.25)?;
+        let q3 = self.col_quantiles(0.75)?;
+        let fences: Vec<(f64, f64)> = q1.iter().zip(q3.iter()).map(|(q1, q3)| (q1 - k * (q3 - q1), q3 + k * (q3 - q1))).collect();
+        let data = self.data
+            .iter()
+            .map(|row| row.iter().zip(fences.iter()).map(|(&x, &(low, high))| f64::from(u8::from(x < low || x > high))).collect())
+            .collect();
+        Ok(Matrix { rows: self.rows, cols: self.cols, data })
+    }
+}
+
+fn check_outlier_factor(factor: f64, message: &'static str) -> Result<(), MatrixError> {
+    if factor.is_nan() || factor < 0.0 {
+        return Err(MatrixError::InvalidOperation(message));
+    }
+    Ok(())
+}
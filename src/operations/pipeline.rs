@@ -0,0 +1,215 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use crate::operations::mul::Mul;
+
+#[derive(Debug, Clone, Copy)]
+enum Step<'a> {
+    Transpose,
+    Scale(f64),
+    Add(&'a Matrix),
+    Sub(&'a Matrix),
+    Mul(&'a Matrix),
+}
+
+/// A chain of operations on a matrix, recorded lazily and executed by `run`.
+///
+/// Shapes are checked as the steps are added, and the first error is returned by `run`.
+/// Consecutive element-wise steps (`scale`, `add`, `sub`), and a `transpose` before them,
+/// are fused into a single pass that allocates only the result.
+/// # Example
+/// ```
+/// # use matrix::{matrix, Matrix, add::Add, mul::Mul};
+/// fn main() {
+///     let m = matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0];
+///     let other = matrix![1.0, 1.0, 1.0; 0.0, 0.0, 0.0];
+///     let weights = matrix![1.0; 2.0; 3.0];
+///
+///     let lazy = m.pipeline().transpose().scale(2.0).add(&other).mul(&weights).run().unwrap();
+///
+///     let eager = m.transpose().scale(2.0).add(other.clone()).unwrap().mul(&weights).unwrap();
+///     assert_eq!(lazy, eager);
+///
+///     // The shape mismatch is caught when the step is added, and reported by `run`.
+///     assert!(m.pipeline().add(&other).scale(2.0).run().is_err());
+/// }
+/// ```
+/// ```
+/// # use matrix::{matrix, Matrix, add::Add};
+/// use std::alloc::{GlobalAlloc, Layout, System};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// struct Counting;
+/// static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+///
+/// unsafe impl GlobalAlloc for Counting {
+///     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+///         ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+///         unsafe { System.alloc(layout) }
+///     }
+///     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+///         unsafe { System.dealloc(ptr, layout) }
+///     }
+/// }
+///
+/// #[global_allocator]
+/// static GLOBAL: Counting = Counting;
+///
+/// fn main() {
+///     let a = Matrix::builder().rows(100).cols(100).done().unwrap();
+///     let b = a.clone();
+///     let c = a.clone();
+///     let pipeline = a.pipeline().transpose().scale(2.0).add(&b).sub(&c);
+///
+///     let before = ALLOCATIONS.load(Ordering::SeqCst);
+///     let result = pipeline.run().unwrap();
+///     let allocations = ALLOCATIONS.load(Ordering::SeqCst) - before;
+///
+///     // The four steps run in one pass: only the result is allocated (one Vec per row plus the outer Vec).
+///     assert_eq!(allocations, a.rows + 1);
+///     assert_eq!(result, a.transpose().scale(2.0).add(b.clone()).unwrap());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Pipeline<'a> {
+    source: &'a Matrix,
+    shape: (usize, usize),
+    steps: Vec<Step<'a>>,
+    error: Option<MatrixError>,
+}
+
+impl Matrix {
+    /// Starts a lazy `Pipeline` of operations on this matrix.
+    pub fn pipeline(&self) -> Pipeline<'_> {
+        Pipeline {
+            source: self,
+            shape: (self.rows, self.cols),
+            steps: Vec::new(),
+            error: None,
+        }
+    }
+}
+
+// `add`, `sub` and `mul` record steps instead of computing, so they deliberately aren't the `std::ops` traits.
+#[allow(clippy::should_implement_trait)]
+impl<'a> Pipeline<'a> {
+    fn push(mut self, step: Step<'a>) -> Self {
+        if self.error.is_some() {
+            return self;
+        }
+
+        let (rows, cols) = self.shape;
+        match step {
+            Step::Transpose => self.shape = (cols, rows),
+            Step::Scale(_) => {}
+            Step::Add(other) | Step::Sub(other) => {
+                if (other.rows, other.cols) != self.shape {
+                    self.error = Some(MatrixError::DimensionMismatch);
+                }
+            }
+            Step::Mul(other) => {
+                if other.rows != cols {
+                    self.error = Some(MatrixError::DimensionMismatch);
+                }
+                self.shape = (rows, other.cols);
+            }
+        }
+        self.steps.push(step);
+        self
+    }
+
+    /// Adds a transpose step.
+    pub fn transpose(self) -> Self {
+        self.push(Step::Transpose)
+    }
+
+    /// Adds a step multiplying every element by `k`.
+    pub fn scale(self, k: f64) -> Self {
+        self.push(Step::Scale(k))
+    }
+
+    /// Adds an element-wise addition step, `other` must have the current shape.
+    pub fn add(self, other: &'a Matrix) -> Self {
+        self.push(Step::Add(other))
+    }
+
+    /// Adds an element-wise subtraction step, `other` must have the current shape.
+    pub fn sub(self, other: &'a Matrix) -> Self {
+        self.push(Step::Sub(other))
+    }
+
+    /// Adds a matrix multiplication step, `other` must have as many rows as the current shape has columns.
+    pub fn mul(self, other: &'a Matrix) -> Self {
+        self.push(Step::Mul(other))
+    }
+
+    /// Returns the shape the result will have.
+    pub fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    /// Executes the pipeline, returning the first error found while adding the steps.
+    pub fn run(self) -> Result<Matrix, MatrixError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        // `current` is the last materialized result (`None` for the source), read transposed when `transposed` is set.
+        // The element-wise steps since `start` are pending and get fused into the next materialization.
+        let mut current: Option<Matrix> = None;
+        let mut transposed = false;
+        let mut start = 0;
+        for (i, step) in self.steps.iter().enumerate() {
+            match step {
+                Step::Transpose if start == i => {
+                    transposed = !transposed;
+                    start = i + 1;
+                }
+                Step::Transpose => {
+                    current = Some(materialize(current, self.source, transposed, &self.steps[start..i]));
+                    transposed = true;
+                    start = i + 1;
+                }
+                Step::Mul(other) => {
+                    let left = materialize(current, self.source, transposed, &self.steps[start..i]);
+                    current = Some(left.mul(*other)?);
+                    transposed = false;
+                    start = i + 1;
+                }
+                Step::Scale(_) | Step::Add(_) | Step::Sub(_) => {}
+            }
+        }
+
+        Ok(materialize(current, self.source, transposed, &self.steps[start..]))
+    }
+}
+
+/// Produces `current` (or `source`), possibly transposed, with the element-wise `steps` applied.
+///
+/// An already materialized matrix with nothing to apply is returned as is.
+fn materialize(current: Option<Matrix>, source: &Matrix, transposed: bool, steps: &[Step]) -> Matrix {
+    match current {
+        Some(matrix) if !transposed && steps.is_empty() => matrix,
+        current => fuse(current.as_ref().unwrap_or(source), transposed, steps),
+    }
+}
+
+/// Reads `base`, possibly transposed, and applies the element-wise `steps` in a single pass.
+fn fuse(base: &Matrix, transposed: bool, steps: &[Step]) -> Matrix {
+    let (rows, cols) = if transposed { (base.cols, base.rows) } else { (base.rows, base.cols) };
+    let data = (0..rows)
+        .map(|i| {
+            (0..cols)
+                .map(|j| {
+                    let value = if transposed { base.data[j][i] } else { base.data[i][j] };
+                    steps.iter().fold(value, |value, step| match step {
+                        Step::Scale(k) => value * k,
+                        Step::Add(other) => value + other.data[i][j],
+                        Step::Sub(other) => value - other.data[i][j],
+                        Step::Transpose | Step::Mul(_) => unreachable!("only element-wise steps are fused"),
+                    })
+                })
+                .collect()
+        })
+        .collect();
+    Matrix { rows, cols, data }
+}
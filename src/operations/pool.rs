@@ -0,0 +1,115 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+/// What pooling does with windows that would run past the edge of the matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolEdge {
+    /// Only full windows are used, the ragged edge is dropped.
+    Drop,
+    /// Windows at the edge are clipped to the matrix and reduced over the elements they cover.
+    Partial,
+}
+
+/// Number of window positions along one axis of length `len`.
+fn window_count(len: usize, window: usize, stride: usize, edge: PoolEdge) -> usize {
+    match edge {
+        PoolEdge::Drop => (len - window) / stride + 1,
+        PoolEdge::Partial => (len - window).div_ceil(stride) + 1,
+    }
+}
+
+impl Matrix {
+    /// Reduces every `window_rows x window_cols` window, moved by `stride` in both directions, with `f`.
+    fn pool(
+        &self,
+        window_rows: usize,
+        window_cols: usize,
+        stride: usize,
+        edge: PoolEdge,
+        operation: &'static str,
+        f: impl Fn(&mut dyn Iterator<Item = f64>) -> f64,
+    ) -> Result<Matrix, MatrixError> {
+        if window_rows == 0 || window_cols == 0 {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+        if stride == 0 {
+            return Err(MatrixError::InvalidOperation("pooling stride must be greater than zero"));
+        }
+        if window_rows > self.rows || window_cols > self.cols {
+            return Err(MatrixError::ShapeMismatch {
+                operation,
+                left: (self.rows, self.cols),
+                right: (window_rows, window_cols),
+            });
+        }
+
+        let rows = window_count(self.rows, window_rows, stride, edge);
+        let cols = window_count(self.cols, window_cols, stride, edge);
+        let data = (0..rows)
+            .map(|i| {
+                let top = i * stride;
+                let bottom = (top + window_rows).min(self.rows);
+                (0..cols)
+                    .map(|j| {
+                        let left = j * stride;
+                        let right = (left + window_cols).min(self.cols);
+                        let mut window = self.data[top..bottom].iter().flat_map(|row| row[left..right].iter().copied());
+                        f(&mut window)
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(Matrix { rows, cols, data })
+    }
+
+    /// Max pooling: the maximum of every `window_rows x window_cols` window, moving `stride` elements at a time.
+    ///
+    /// `edge` selects what happens when the windows don't divide the matrix evenly, see `PoolEdge`.
+    /// Returns `InvalidMatrixSize` for an empty window, `InvalidOperation` for a zero stride
+    /// and `ShapeMismatch` if the window is larger than the matrix.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, pool::PoolEdge};
+    /// fn main() {
+    ///     let image = matrix![
+    ///         1.0, 2.0, 5.0, 6.0;
+    ///         3.0, 4.0, 7.0, 8.0;
+    ///         9.0, 1.0, 0.0, 2.0;
+    ///         1.0, 1.0, 3.0, 1.0
+    ///     ];
+    ///
+    ///     assert_eq!(image.max_pool(2, 2, 2, PoolEdge::Drop).unwrap(), matrix![4.0, 8.0; 9.0, 3.0]);
+    ///     assert_eq!(image.avg_pool(2, 2, 2, PoolEdge::Drop).unwrap(), matrix![2.5, 6.5; 3.0, 1.5]);
+    ///
+    ///     // 3x3 windows with stride 2: only one full window, plus clipped ones at the edge.
+    ///     assert_eq!(image.max_pool(3, 3, 2, PoolEdge::Drop).unwrap(), matrix![9.0]);
+    ///     assert_eq!(image.max_pool(3, 3, 2, PoolEdge::Partial).unwrap(), matrix![9.0, 8.0; 9.0, 3.0]);
+    ///
+    ///     assert!(image.max_pool(5, 1, 1, PoolEdge::Drop).is_err());
+    ///     assert!(image.max_pool(2, 2, 0, PoolEdge::Drop).is_err());
+    /// }
+    /// ```
+    pub fn max_pool(&self, window_rows: usize, window_cols: usize, stride: usize, edge: PoolEdge) -> Result<Matrix, MatrixError> {
+        self.pool(window_rows, window_cols, stride, edge, "max_pool", |window| {
+            window.fold(f64::NEG_INFINITY, f64::max)
+        })
+    }
+
+    /// Average pooling, see `max_pool`. Clipped windows are averaged over the elements they cover.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, pool::PoolEdge};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0, 3.0];
+    ///
+    ///     assert_eq!(a.avg_pool(1, 2, 2, PoolEdge::Drop).unwrap(), matrix![1.5]);
+    ///     assert_eq!(a.avg_pool(1, 2, 2, PoolEdge::Partial).unwrap(), matrix![1.5, 3.0]);
+    /// }
+    /// ```
+    pub fn avg_pool(&self, window_rows: usize, window_cols: usize, stride: usize, edge: PoolEdge) -> Result<Matrix, MatrixError> {
+        self.pool(window_rows, window_cols, stride, edge, "avg_pool", |window| {
+            let (sum, count) = window.fold((0.0, 0), |(sum, count), x| (sum + x, count + 1));
+            sum / count as f64
+        })
+    }
+}
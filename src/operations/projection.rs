@@ -0,0 +1,93 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use crate::operations::mul::Mul;
+
+/// Returns `v v^T / (v . v)`, or `InvalidOperation` for an empty or zero `v`.
+fn outer_normalized(v: &[f64], operation: &'static str) -> Result<Matrix, MatrixError> {
+    let norm_sq: f64 = v.iter().map(|x| x * x).sum();
+    if norm_sq == 0.0 {
+        return Err(MatrixError::InvalidOperation(operation));
+    }
+
+    let data = v.iter().map(|a| v.iter().map(|b| a * b / norm_sq).collect()).collect();
+    Ok(Matrix { rows: v.len(), cols: v.len(), data })
+}
+
+impl Matrix {
+    /// Builds the matrix projecting onto the line spanned by `v`, `v v^T / (v . v)`.
+    ///
+    /// Returns `InvalidOperation` for the zero vector.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, mul::Mul};
+    /// fn main() {
+    ///     let p = Matrix::projection_onto(&[1.0, 2.0, 2.0]).unwrap();
+    ///
+    ///     // Idempotent: projecting twice is projecting once.
+    ///     assert!(p.mul(&p).unwrap().diff(&p, 1e-12).is_equal());
+    ///     assert!(p.mul(&matrix![1.0; 2.0; 2.0]).unwrap().diff(&matrix![1.0; 2.0; 2.0], 1e-12).is_equal());
+    ///
+    ///     assert!(Matrix::projection_onto(&[0.0, 0.0]).is_err());
+    /// }
+    /// ```
+    pub fn projection_onto(v: &[f64]) -> Result<Matrix, MatrixError> {
+        outer_normalized(v, "cannot project onto the zero vector")
+    }
+
+    /// Builds the Householder reflection across the hyperplane orthogonal to `v`, `I - 2 v v^T / (v . v)`.
+    ///
+    /// Returns `InvalidOperation` for the zero vector.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, mul::Mul};
+    /// fn main() {
+    ///     let h = Matrix::householder(&[1.0, -1.0, 3.0]).unwrap();
+    ///     let identity = Matrix::identity(3).unwrap();
+    ///
+    ///     // Orthogonal and involutory: H^T H = I and H H = I.
+    ///     assert!(h.transpose().mul(&h).unwrap().diff(&identity, 1e-12).is_equal());
+    ///     assert!(h.mul(&h).unwrap().diff(&identity, 1e-12).is_equal());
+    ///     assert!(h.is_symmetric(1e-12));
+    /// }
+    /// ```
+    pub fn householder(v: &[f64]) -> Result<Matrix, MatrixError> {
+        let mut result = outer_normalized(v, "cannot reflect across the zero vector")?;
+        for (i, row) in result.data.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = if i == j { 1.0 } else { 0.0 } - 2.0 * *value;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Builds the matrix projecting onto the column space of `basis`, `A (A^T A)^-1 A^T`.
+    ///
+    /// Returns `Singular` if the columns of `basis` are linearly dependent (the Gram matrix `A^T A` is singular).
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, mul::Mul};
+    /// fn main() {
+    ///     // The xy-plane in 3D, spanned by two non-orthogonal vectors.
+    ///     let basis = matrix![1.0, 1.0; 0.0, 1.0; 0.0, 0.0];
+    ///     let p = Matrix::projection_onto_subspace(&basis).unwrap();
+    ///
+    ///     assert!(p.diff(&matrix![1.0, 0.0, 0.0; 0.0, 1.0, 0.0; 0.0, 0.0, 0.0], 1e-12).is_equal());
+    ///     assert!(p.mul(&p).unwrap().diff(&p, 1e-12).is_equal());
+    ///
+    ///     let dependent = matrix![1.0, 2.0; 1.0, 2.0; 0.0, 0.0];
+    ///     assert_eq!(
+    ///         Matrix::projection_onto_subspace(&dependent),
+    ///         Err(MatrixError::Singular { operation: "projection_onto_subspace" })
+    ///     );
+    /// }
+    /// ```
+    pub fn projection_onto_subspace(basis: &Matrix) -> Result<Matrix, MatrixError> {
+        let basis_t = basis.transpose();
+        let gram = basis_t.mul(basis)?;
+        let coefficients = gram.solve(&basis_t).map_err(|error| match error {
+            MatrixError::Singular { .. } => MatrixError::Singular { operation: "projection_onto_subspace" },
+            other => other,
+        })?;
+        basis.mul(&coefficients)
+    }
+}
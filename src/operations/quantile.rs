@@ -0,0 +1,136 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+impl Matrix {
+    /// Returns the `q` quantile of all the elements, interpolating linearly between the two closest order
+    /// statistics like numpy's default `'linear'` method: with the `n` elements sorted, the result sits at position
+    /// `q * (n - 1)`.
+    ///
+    /// `NaN` elements are not skipped: the first one is returned in a `NonFiniteValue` error. Infinite elements are
+    /// ordered like any other. Returns `InvalidOperation` if `q` is not in `[0, 1]`, and `InvalidMatrixSize` if the
+    /// matrix has no elements.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     // Odd count: sorted 1, 2, 3, 4, 5.
+    ///     let odd = matrix![5.0, 1.0, 4.0, 2.0, 3.0];
+    ///     assert_eq!(odd.quantile(0.5).unwrap(), 3.0);
+    ///     assert_eq!(odd.quantile(0.25).unwrap(), 2.0);
+    ///     assert_eq!(odd.quantile(0.9).unwrap(), 4.6);
+    ///     assert_eq!(odd.quantile(1.0).unwrap(), 5.0);
+    ///
+    ///     // Even count: sorted 1, 2, 4, 8, position 1.5 for the median.
+    ///     let even = matrix![8.0, 1.0; 4.0, 2.0];
+    ///     assert_eq!(even.quantile(0.5).unwrap(), 3.0);
+    ///     assert_eq!(even.quantile(0.25).unwrap(), 1.75);
+    ///     assert_eq!(even.quantile(0.75).unwrap(), 5.0);
+    ///
+    ///     assert_eq!(matrix![7.0].quantile(0.3).unwrap(), 7.0);
+    ///     assert!(matches!(even.quantile(-0.1), Err(MatrixError::InvalidOperation(_))));
+    ///     assert!(matches!(even.quantile(f64::NAN), Err(MatrixError::InvalidOperation(_))));
+    ///     assert!(matches!(
+    ///         matrix![1.0, f64::NAN].quantile(0.5),
+    ///         Err(MatrixError::NonFiniteValue { row: 0, col: 1, .. })
+    ///     ));
+    /// }
+    /// ```
+    pub fn quantile(&self, q: f64) -> Result<f64, MatrixError> {
+        self.check_quantile_input(q)?;
+        let mut values: Vec<f64> = self.data.iter().flatten().copied().collect();
+        values.sort_unstable_by(f64::total_cmp);
+        Ok(quantile_sorted(&values, q))
+    }
+
+    /// Returns the median of all the elements, `quantile(0.5)`: the middle element for an odd count and the mean of
+    /// the two middle ones for an even count.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     assert_eq!(matrix![3.0, 1.0, 2.0].median().unwrap(), 2.0);
+    ///     assert_eq!(matrix![3.0, 1.0; 2.0, 10.0].median().unwrap(), 2.5);
+    ///     assert!(matrix![f64::NAN].median().is_err());
+    /// }
+    /// ```
+    pub fn median(&self) -> Result<f64, MatrixError> {
+        self.quantile(0.5)
+    }
+
+    /// Returns the `q` quantile of every column, with the interpolation and errors of `quantile`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let a = matrix![3.0, 1.0, 6.0; 1.0, 4.0, 6.0; 2.0, 2.0, 6.0; 5.0, 3.0, 6.0];
+    ///     assert_eq!(a.col_quantiles(0.0).unwrap(), vec![1.0, 1.0, 6.0]);
+    ///     assert_eq!(a.col_quantiles(0.5).unwrap(), vec![2.5, 2.5, 6.0]);
+    ///     // Position 0.25 * 3 = 0.75 between the sorted values 1 and 2.
+    ///     assert_eq!(a.col_quantiles(0.25).unwrap(), vec![1.75, 1.75, 6.0]);
+    ///     assert_eq!(a.col_quantiles(1.0).unwrap(), vec![5.0, 4.0, 6.0]);
+    ///
+    ///     // Three rows: the median is the middle element.
+    ///     assert_eq!(matrix![9.0; -1.0; 4.0].col_quantiles(0.5).unwrap(), vec![4.0]);
+    ///
+    ///     assert!(matches!(a.col_quantiles(1.5), Err(MatrixError::InvalidOperation(_))));
+    ///     assert!(matches!(
+    ///         matrix![1.0; f64::NAN].col_quantiles(0.5),
+    ///         Err(MatrixError::NonFiniteValue { row: 1, col: 0, .. })
+    ///     ));
+    /// }
+    /// ```
+    pub fn col_quantiles(&self, q: f64) -> Result<Vec<f64>, MatrixError> {
+        self.check_quantile_input(q)?;
+        Ok((0..self.cols)
+            .map(|j| {
+                let mut column: Vec<f64> = self.data.iter().map(|row| row[j]).collect();
+                column.sort_unstable_by(f64::total_cmp);
+                quantile_sorted(&column, q)
+            })
+            .collect())
+    }
+
+    /// Returns the `q` quantile of every column. It is `col_quantiles` under the name the outlier masks were
+    /// introduced with, and both are kept.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![3.0, 1.0; 1.0, 4.0; 2.0, 2.0; 5.0, 3.0];
+    ///     assert_eq!(a.col_quantile(0.25).unwrap(), vec![1.75, 1.75]);
+    ///     assert_eq!(a.col_quantile(0.25).unwrap(), a.col_quantiles(0.25).unwrap());
+    /// }
+    /// ```
+    pub fn col_quantile(&self, q: f64) -> Result<Vec<f64>, MatrixError> {
+        self.col_quantiles(q)
+    }
+
+    fn check_quantile_input(&self, q: f64) -> Result<(), MatrixError> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(MatrixError::InvalidOperation("quantile must be between 0 and 1"));
+        }
+        if self.rows == 0 || self.cols == 0 {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+        if let Some((row, col, &value)) = self
+            .data
+            .iter()
+            .enumerate()
+            .find_map(|(i, row)| row.iter().enumerate().find(|(_, x)| x.is_nan()).map(|(j, x)| (i, j, x)))
+        {
+            return Err(MatrixError::NonFiniteValue { row, col, value });
+        }
+        Ok(())
+    }
+}
+
+/// The `q` quantile of non-empty, sorted `values`, with numpy's `'linear'` interpolation.
+fn quantile_sorted(values: &[f64], q: f64) -> f64 {
+    let position = q * (values.len() - 1) as f64;
+    let below = position.floor() as usize;
+    let fraction = position - below as f64;
+    match values.get(below + 1) {
+        Some(&above) if fraction > 0.0 => values[below] + fraction * (above - values[below]),
+        _ => values[below],
+    }
+}
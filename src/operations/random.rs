@@ -0,0 +1,88 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use crate::operations::mul::Mul;
+
+/// The splitmix64 generator: tiny, fast and not cryptographic.
+pub(crate) struct SplitMix64(u64);
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `[0, 1)` from the top 53 bits.
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Fills a `rows x cols` grid in row-major order.
+    pub(crate) fn fill(&mut self, rows: usize, cols: usize) -> Vec<Vec<f64>> {
+        (0..rows).map(|_| (0..cols).map(|_| self.next_f64()).collect()).collect()
+    }
+}
+
+impl Matrix {
+    /// Creates a `rows x cols` matrix of pseudo-random values in `[0, 1)`, generated from `seed` in row-major order.
+    ///
+    /// The generator is splitmix64. It is not cryptographic, but it is part of the API: the same seed gives
+    /// the same matrix in every release, so examples and benchmarks can rely on exact values.
+    /// Returns `InvalidMatrixSize` for zero dimensions or if `rows * cols` overflows.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let a = Matrix::from_seed(2, 2, 42).unwrap();
+    ///
+    ///     assert_eq!(a.data, vec![
+    ///         vec![0.7415648787718233, 0.1599103928769201],
+    ///         vec![0.27860113025513866, 0.34419071652363753],
+    ///     ]);
+    ///     assert_eq!(Matrix::from_seed(2, 2, 42), Ok(a.clone()));
+    ///     assert_ne!(Matrix::from_seed(2, 2, 43), Ok(a));
+    ///
+    ///     let big = Matrix::from_seed(100, 100, 7).unwrap();
+    ///     assert!(big.data.iter().flatten().all(|&x| (0.0..1.0).contains(&x)));
+    /// }
+    /// ```
+    pub fn from_seed(rows: usize, cols: usize, seed: u64) -> Result<Matrix, MatrixError> {
+        if rows == 0 || cols == 0 {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+        dim_checked_mul(rows, cols)?;
+
+        Ok(Matrix { rows, cols, data: SplitMix64::new(seed).fill(rows, cols) })
+    }
+
+    /// Creates a pseudo-random `n x n` symmetric positive definite matrix from `seed`, for testing solvers.
+    ///
+    /// It is `M^T M + n I` where `M` has entries in `[-1, 1)` from `from_seed`, so its eigenvalues are at least `n`
+    /// and it is well conditioned. Returns `InvalidMatrixSize` if `n` is 0.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let a = Matrix::random_spd(6, 3).unwrap();
+    ///
+    ///     assert!(a.is_symmetric(0.0));
+    ///     assert!(a.eigen_symmetric(1e-12, 100).unwrap().0.iter().all(|&l| l >= 6.0 - 1e-9));
+    ///     assert_eq!(Matrix::random_spd(6, 3), Ok(a));
+    /// }
+    /// ```
+    pub fn random_spd(n: usize, seed: u64) -> Result<Matrix, MatrixError> {
+        let mut m = Matrix::from_seed(n, n, seed)?;
+        m.data.iter_mut().flatten().for_each(|x| *x = 2.0 * *x - 1.0);
+        let mut a = m.transpose().mul(&m)?;
+        for (i, row) in a.data.iter_mut().enumerate() {
+            row[i] += n as f64;
+        }
+        Ok(a)
+    }
+}
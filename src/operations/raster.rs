@@ -0,0 +1,102 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+/// How `Matrix::to_u8_grid` maps elements to bytes. Every policy rounds to the nearest byte, halves away from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ScalePolicy {
+    /// Elements are already in `0..=255`: they are rounded and anything outside is clamped.
+    Clamp0To255,
+    /// The smallest finite element becomes 0 and the largest 255. A constant matrix becomes all 0.
+    #[default]
+    MinMaxNormalize,
+    /// `min` becomes 0 and `max` 255, linearly, and elements outside the range are clamped.
+    Linear { min: f64, max: f64 },
+}
+
+impl Matrix {
+    /// Converts the matrix to rows of bytes under `policy`, for writing grayscale images.
+    ///
+    /// Infinities are clamped to 0 or 255 like any other value out of range. `NaN` has no sensible byte, so it
+    /// returns `NonFiniteValue` with the first one. `Linear` returns `InvalidOperation` unless `min < max`, both
+    /// finite.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, raster::ScalePolicy};
+    /// fn main() {
+    ///     let gradient = matrix![-10.0, 0.0, 100.4, 100.5, 300.0];
+    ///
+    ///     assert_eq!(gradient.to_u8_grid(ScalePolicy::Clamp0To255).unwrap(), vec![vec![0, 0, 100, 101, 255]]);
+    ///     assert_eq!(gradient.to_u8_grid(ScalePolicy::MinMaxNormalize).unwrap(), vec![vec![0, 8, 91, 91, 255]]);
+    ///     assert_eq!(
+    ///         gradient.to_u8_grid(ScalePolicy::Linear { min: 0.0, max: 200.0 }).unwrap(),
+    ///         vec![vec![0, 0, 128, 128, 255]]
+    ///     );
+    ///
+    ///     // No range to normalize: all 0, not a division by zero.
+    ///     assert_eq!(matrix![7.0, 7.0; 7.0, 7.0].to_u8_grid(ScalePolicy::MinMaxNormalize).unwrap(), vec![vec![0, 0], vec![0, 0]]);
+    ///     assert_eq!(
+    ///         matrix![f64::NEG_INFINITY, 1.0, 3.0, f64::INFINITY].to_u8_grid(ScalePolicy::MinMaxNormalize).unwrap(),
+    ///         vec![vec![0, 0, 255, 255]]
+    ///     );
+    ///
+    ///     assert!(matches!(
+    ///         matrix![1.0, f64::NAN].to_u8_grid(ScalePolicy::Clamp0To255),
+    ///         Err(MatrixError::NonFiniteValue { row: 0, col: 1, .. })
+    ///     ));
+    ///     assert!(gradient.to_u8_grid(ScalePolicy::Linear { min: 1.0, max: 1.0 }).is_err());
+    /// }
+    /// ```
+    pub fn to_u8_grid(&self, policy: ScalePolicy) -> Result<Vec<Vec<u8>>, MatrixError> {
+        if let Some((row, col, &value)) = self
+            .data
+            .iter()
+            .enumerate()
+            .find_map(|(i, row)| row.iter().enumerate().find(|(_, x)| x.is_nan()).map(|(j, x)| (i, j, x)))
+        {
+            return Err(MatrixError::NonFiniteValue { row, col, value });
+        }
+
+        let (min, max) = match policy {
+            ScalePolicy::Clamp0To255 => (0.0, 255.0),
+            ScalePolicy::Linear { min, max } => {
+                if !(min.is_finite() && max.is_finite() && min < max) {
+                    return Err(MatrixError::InvalidOperation("linear scale range must be finite with min < max"));
+                }
+                (min, max)
+            }
+            ScalePolicy::MinMaxNormalize => {
+                let finite = self.data.iter().flatten().copied().filter(|x| x.is_finite());
+                finite.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| (min.min(x), max.max(x)))
+            }
+        };
+
+        let byte = |x: f64| -> u8 {
+            if min >= max {
+                // Constant (or all infinite) under `MinMaxNormalize`.
+                return if x > max { 255 } else { 0 };
+            }
+            ((x - min) / (max - min) * 255.0).round().clamp(0.0, 255.0) as u8
+        };
+        Ok(self.data.iter().map(|row| row.iter().map(|&x| byte(x)).collect()).collect())
+    }
+
+    /// Builds a matrix from rows of bytes, each byte becoming the element of the same value, `0.0..=255.0`.
+    ///
+    /// Returns `InvalidMatrixSize` for an empty grid and `DataMismatch` for rows of different lengths, like `from_rows`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, raster::ScalePolicy};
+    /// fn main() {
+    ///     let grid = vec![vec![0, 128], vec![255, 7]];
+    ///     let a = Matrix::from_u8_grid(&grid).unwrap();
+    ///
+    ///     assert_eq!(a, matrix![0.0, 128.0; 255.0, 7.0]);
+    ///     assert_eq!(a.to_u8_grid(ScalePolicy::Clamp0To255).unwrap(), grid);
+    ///     assert!(Matrix::from_u8_grid(&[vec![1, 2], vec![3]]).is_err());
+    ///     assert!(Matrix::from_u8_grid(&[]).is_err());
+    /// }
+    /// ```
+    pub fn from_u8_grid(grid: &[Vec<u8>]) -> Result<Matrix, MatrixError> {
+        Matrix::from_rows(grid.iter().map(|row| row.iter().map(|&b| f64::from(b)).collect()).collect())
+    }
+}
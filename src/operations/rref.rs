@@ -0,0 +1,168 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use crate::tolerance::DEFAULT_TOLERANCE;
+
+/// Solution set of `A X = B`, as returned by `Matrix::solve_general`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinearSolution {
+    /// `A` has full column rank and the system is consistent: exactly one solution.
+    Unique(Matrix),
+    /// Every `particular + null_basis * c` is a solution, for any `c` with as many rows as `null_basis` has columns.
+    /// The columns of `null_basis` are a basis of the null space of `A`.
+    Infinite { particular: Matrix, null_basis: Matrix },
+    /// No `X` satisfies the system.
+    Inconsistent,
+}
+
+impl Matrix {
+    /// Returns the reduced row echelon form of the matrix and its pivot columns, in increasing order.
+    ///
+    /// Gauss-Jordan elimination with partial pivoting: every pivot is 1 and is the only non-zero element of
+    /// its column. The number of pivots is the rank of the matrix. Elements within `DEFAULT_TOLERANCE` of 0,
+    /// relative to the largest absolute entry, are treated as 0 and set to exactly 0 in the result.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![
+    ///         1.0, 2.0, 1.0, 4.0;
+    ///         2.0, 4.0, 0.0, 6.0;
+    ///         3.0, 6.0, 1.0, 10.0
+    ///     ];
+    ///
+    ///     let (r, pivots) = a.rref();
+    ///     assert_eq!(pivots, vec![0, 2]);
+    ///     assert!(r.diff(&matrix![1.0, 2.0, 0.0, 3.0; 0.0, 0.0, 1.0, 1.0; 0.0, 0.0, 0.0, 0.0], 1e-12).is_equal());
+    ///
+    ///     assert_eq!(Matrix::identity(3).unwrap().rref(), (Matrix::identity(3).unwrap(), vec![0, 1, 2]));
+    ///     assert!(Matrix::zeros(2, 3).unwrap().rref().1.is_empty());
+    /// }
+    /// ```
+    pub fn rref(&self) -> (Matrix, Vec<usize>) {
+        let mut r = self.clone();
+        let scale = self.data.iter().flatten().fold(0.0, |max: f64, v| v.abs().max(max));
+        let tol = DEFAULT_TOLERANCE.threshold(scale);
+        let mut pivots = Vec::new();
+
+        for j in 0..self.cols {
+            let k = pivots.len();
+            if k == self.rows {
+                break;
+            }
+            let best = (k..self.rows).max_by(|&x, &y| r.data[x][j].abs().total_cmp(&r.data[y][j].abs())).unwrap_or(k);
+            if r.data[best][j].abs() <= tol {
+                r.data[k..].iter_mut().for_each(|row| row[j] = 0.0);
+                continue;
+            }
+            r.data.swap(k, best);
+
+            let pivot = r.data[k][j];
+            r.data[k].iter_mut().for_each(|x| *x /= pivot);
+            let pivot_row = r.data[k].clone();
+            for (i, row) in r.data.iter_mut().enumerate() {
+                let factor = row[j];
+                if i != k && factor != 0.0 {
+                    row.iter_mut().zip(pivot_row.iter()).for_each(|(x, p)| *x -= factor * p);
+                    row[j] = 0.0;
+                }
+            }
+            pivots.push(j);
+        }
+        (r, pivots)
+    }
+
+    /// Solves `A X = B` for any shape of `A` (`self`), describing the whole solution set with `LinearSolution`.
+    ///
+    /// The augmented matrix `[A | B]` is reduced with `rref`: a pivot in the `B` part means the system is
+    /// `Inconsistent`, otherwise the free columns of `A` give the null basis and the particular solution is the
+    /// one with every free variable at 0. An inconsistent system is a legitimate result, not an error.
+    /// Returns `DimensionMismatch` if `B` does not have as many rows as `A`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, mul::Mul, add::Add, rref::LinearSolution};
+    /// fn main() {
+    ///     // 2 equations, 3 unknowns: a line of solutions.
+    ///     let a = matrix![1.0, 2.0, 3.0; 0.0, 1.0, 1.0];
+    ///     let b = matrix![6.0; 2.0];
+    ///
+    ///     let LinearSolution::Infinite { particular, null_basis } = a.solve_general(&b).unwrap() else {
+    ///         panic!("expected infinitely many solutions");
+    ///     };
+    ///     assert_eq!((null_basis.rows, null_basis.cols), (3, 1));
+    ///     assert!(a.mul(&particular).unwrap().diff(&b, 1e-12).is_equal());
+    ///     assert!(a.mul(&null_basis).unwrap().diff(&Matrix::zeros(2, 1).unwrap(), 1e-12).is_equal());
+    ///     for c in [-2.5, 0.0, 1.0, 100.0] {
+    ///         let x = particular.add(null_basis.scale(c)).unwrap();
+    ///         assert!(a.mul(&x).unwrap().diff(&b, 1e-9).is_equal());
+    ///     }
+    ///
+    ///     // Square and invertible: the same answer as `solve`.
+    ///     let a = matrix![2.0, 1.0; 1.0, 3.0];
+    ///     let b = matrix![3.0, 1.0; 5.0, 0.0];
+    ///     let LinearSolution::Unique(x) = a.solve_general(&b).unwrap() else { panic!("expected a unique solution") };
+    ///     assert!(x.diff(&a.solve(&b).unwrap(), 1e-12).is_equal());
+    ///
+    ///     // x + y = 1 and 2x + 2y = 3 contradict each other.
+    ///     assert_eq!(matrix![1.0, 1.0; 2.0, 2.0].solve_general(&matrix![1.0; 3.0]), Ok(LinearSolution::Inconsistent));
+    ///     assert!(a.solve_general(&matrix![1.0]).is_err());
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{matrix, Matrix, mul::Mul, add::Add, rref::LinearSolution};
+    /// fn main() {
+    ///     // Rank 2 in 4 unknowns, 3 equations: a plane of solutions.
+    ///     let a = matrix![
+    ///         1.0, 2.0, 1.0, 4.0;
+    ///         2.0, 4.0, 0.0, 6.0;
+    ///         3.0, 6.0, 1.0, 10.0
+    ///     ];
+    ///     let b = matrix![5.0; 6.0; 11.0];
+    ///
+    ///     let LinearSolution::Infinite { particular, null_basis } = a.solve_general(&b).unwrap() else { panic!() };
+    ///     assert_eq!(null_basis.cols, 2);
+    ///     for c in [matrix![1.0; 0.0], matrix![0.0; 1.0], matrix![-3.0; 0.5]] {
+    ///         let x = particular.add(null_basis.mul(&c).unwrap()).unwrap();
+    ///         assert!(a.mul(&x).unwrap().diff(&b, 1e-9).is_equal());
+    ///     }
+    ///
+    ///     // Tall and consistent, with full column rank.
+    ///     let tall = matrix![1.0, 0.0; 0.0, 1.0; 1.0, 1.0];
+    ///     assert_eq!(tall.solve_general(&matrix![1.0; 2.0; 3.0]), Ok(LinearSolution::Unique(matrix![1.0; 2.0])));
+    ///     assert_eq!(tall.solve_general(&matrix![1.0; 2.0; 4.0]), Ok(LinearSolution::Inconsistent));
+    /// }
+    /// ```
+    pub fn solve_general(&self, b: &Matrix) -> Result<LinearSolution, MatrixError> {
+        if b.rows != self.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let augmented = Matrix {
+            rows: self.rows,
+            cols: self.cols + b.cols,
+            data: self.data.iter().zip(b.data.iter()).map(|(a, b)| a.iter().chain(b.iter()).copied().collect()).collect(),
+        };
+        let (r, pivots) = augmented.rref();
+        if pivots.last().is_some_and(|&j| j >= self.cols) {
+            return Ok(LinearSolution::Inconsistent);
+        }
+
+        let mut particular = Matrix::zeros(self.cols, b.cols)?;
+        for (row, &j) in r.data.iter().zip(pivots.iter()) {
+            particular.data[j].copy_from_slice(&row[self.cols..]);
+        }
+
+        let free: Vec<usize> = (0..self.cols).filter(|j| !pivots.contains(j)).collect();
+        if free.is_empty() {
+            return Ok(LinearSolution::Unique(particular));
+        }
+
+        let mut null_basis = Matrix::zeros(self.cols, free.len())?;
+        for (k, &f) in free.iter().enumerate() {
+            null_basis.data[f][k] = 1.0;
+            for (row, &j) in r.data.iter().zip(pivots.iter()) {
+                null_basis.data[j][k] = -row[f];
+            }
+        }
+        Ok(LinearSolution::Infinite { particular, null_basis })
+    }
+}
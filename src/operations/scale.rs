@@ -0,0 +1,169 @@
+use crate::matrix::*;
+use crate::matrix32::Matrix32;
+use crate::errors::MatrixError;
+use crate::operations::kernels::{map_rows, zip_rows};
+
+impl Matrix {
+    /// Multiplies every element by `k`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     assert_eq!(matrix![1.0, -2.0].scale(3.0), matrix![3.0, -6.0]);
+    /// }
+    /// ```
+    pub fn scale(&self, k: f64) -> Matrix {
+        Matrix { rows: self.rows, cols: self.cols, data: map_rows(&self.data, |x| x * k) }
+    }
+
+    /// Adds `alpha * x` to `self` in place, in a single pass and without temporaries (BLAS `axpy`).
+    ///
+    /// Returns `ShapeMismatch` and leaves `self` unchanged if `x` has a different shape.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, sub::Sub};
+    /// fn main() {
+    ///     // A gradient descent step, `w = w - lr * g`.
+    ///     let mut w = matrix![0.5, -1.0; 2.0, 0.25];
+    ///     let g = matrix![1.0, -2.0; 0.5, 4.0];
+    ///     let two_step = w.sub(g.scale(0.1)).unwrap();
+    ///
+    ///     w.axpy(-0.1, &g).unwrap();
+    ///     assert_eq!(w, two_step);
+    ///
+    ///     let error = w.axpy(1.0, &matrix![1.0, 2.0]).unwrap_err();
+    ///     assert_eq!(error, MatrixError::ShapeMismatch { operation: "axpy", left: (2, 2), right: (1, 2) });
+    ///     assert_eq!(w, two_step);
+    /// }
+    /// ```
+    pub fn axpy(&mut self, alpha: f64, x: &Matrix) -> Result<(), MatrixError> {
+        self.check_same_shape(x, "axpy")?;
+
+        for (row, x_row) in self.data.iter_mut().zip(x.data.iter()) {
+            for (value, x) in row.iter_mut().zip(x_row.iter()) {
+                *value += alpha * x;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `self + alpha * x`, computed in a single loop, see `axpy` for the in-place version.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, add::Add};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0, 3.0];
+    ///     let x = matrix![0.5, 0.25, -1.0];
+    ///
+    ///     assert_eq!(a.scaled_add(2.0, &x).unwrap(), matrix![2.0, 2.5, 1.0]);
+    ///     assert_eq!(a.scaled_add(3.0, &x).unwrap(), a.add(x.scale(3.0)).unwrap());
+    ///     assert!(a.scaled_add(1.0, &matrix![1.0]).is_err());
+    /// }
+    /// ```
+    pub fn scaled_add(&self, alpha: f64, x: &Matrix) -> Result<Matrix, MatrixError> {
+        self.check_same_shape(x, "scaled_add")?;
+
+        let data = zip_rows(&self.data, &x.data, |a, b| a + alpha * b);
+        Ok(Matrix { rows: self.rows, cols: self.cols, data })
+    }
+
+    /// Adds `alpha * u * v^T` to `self` in place, without building the outer product (BLAS `ger`).
+    ///
+    /// `u` must have one value per row and `v` one per column, otherwise it returns `ShapeMismatch`
+    /// with `(u.len(), v.len())` and leaves `self` unchanged.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, add::Add, mul::Mul};
+    /// fn main() {
+    ///     let mut a = Matrix::from_seed(3, 4, 1).unwrap();
+    ///     let (u, v) = ([1.0, -2.0, 0.5], [3.0, 0.0, 1.0, -1.0]);
+    ///
+    ///     let outer = Matrix::from_flat(3, 1, u.to_vec()).unwrap().mul(Matrix::from_flat(1, 4, v.to_vec()).unwrap()).unwrap();
+    ///     let expected = a.add(outer.scale(0.5)).unwrap();
+    ///     a.rank1_update(0.5, &u, &v).unwrap();
+    ///     assert!(a.diff(&expected, 1e-12).is_equal());
+    ///
+    ///     assert_eq!(
+    ///         a.rank1_update(1.0, &v, &u),
+    ///         Err(MatrixError::ShapeMismatch { operation: "rank1_update", left: (3, 4), right: (4, 3) })
+    ///     );
+    ///     assert!(a.diff(&expected, 1e-12).is_equal());
+    /// }
+    /// ```
+    pub fn rank1_update(&mut self, alpha: f64, u: &[f64], v: &[f64]) -> Result<(), MatrixError> {
+        if u.len() != self.rows || v.len() != self.cols {
+            return Err(MatrixError::ShapeMismatch {
+                operation: "rank1_update",
+                left: (self.rows, self.cols),
+                right: (u.len(), v.len()),
+            });
+        }
+
+        for (row, &u) in self.data.iter_mut().zip(u.iter()) {
+            let scaled = alpha * u;
+            for (value, &v) in row.iter_mut().zip(v.iter()) {
+                *value += scaled * v;
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds `alpha * u * u^T` to a symmetric `self` in place (BLAS `syr`).
+    ///
+    /// Only the lower triangle is computed, half the multiplications of `rank1_update`, and it is mirrored into the
+    /// upper one, so the previous upper triangle is overwritten and the result is exactly symmetric.
+    /// The mirrored writes go down a column, so on large matrices this is slower than `rank1_update` with `u` twice;
+    /// use it when exact symmetry matters.
+    /// Returns `NotSquare` for non-square matrices and `ShapeMismatch` if `u` does not have one value per row.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     // Online scatter matrix of centered samples: sum of x x^T.
+    ///     let samples = [[1.0, -0.5, 2.0], [0.0, 1.5, -1.0], [-1.0, -1.0, -1.0], [0.25, 0.0, 4.0]];
+    ///     let mut online = Matrix::zeros(3, 3).unwrap();
+    ///     let mut general = Matrix::zeros(3, 3).unwrap();
+    ///     for x in &samples {
+    ///         online.rank1_update_sym(1.0, x).unwrap();
+    ///         general.rank1_update(1.0, x, x).unwrap();
+    ///     }
+    ///     assert!(online.is_symmetric(0.0));
+    ///     assert!(online.diff(&general, 1e-12).is_equal());
+    ///
+    ///     assert_eq!(online.rank1_update_sym(1.0, &[1.0, 2.0]),
+    ///         Err(MatrixError::ShapeMismatch { operation: "rank1_update_sym", left: (3, 3), right: (2, 2) }));
+    ///     assert!(matches!(matrix![1.0, 2.0].rank1_update_sym(1.0, &[1.0]), Err(MatrixError::NotSquare { .. })));
+    /// }
+    /// ```
+    pub fn rank1_update_sym(&mut self, alpha: f64, u: &[f64]) -> Result<(), MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        if u.len() != self.rows {
+            return Err(MatrixError::ShapeMismatch {
+                operation: "rank1_update_sym",
+                left: (self.rows, self.cols),
+                right: (u.len(), u.len()),
+            });
+        }
+
+        for i in 0..self.rows {
+            let scaled = alpha * u[i];
+            let (above, below) = self.data.split_at_mut(i);
+            let row = &mut below[0];
+            for ((value, &u), above_row) in row.iter_mut().zip(u.iter()).zip(above.iter_mut()) {
+                *value += scaled * u;
+                above_row[i] = *value;
+            }
+            row[i] += scaled * u[i];
+        }
+        Ok(())
+    }
+}
+
+impl Matrix32 {
+    /// Multiplies every element by `k`, see `Matrix::scale`.
+    pub fn scale(&self, k: f32) -> Matrix32 {
+        Matrix32 { rows: self.rows, cols: self.cols, data: map_rows(&self.data, |x| x * k) }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use std::ops::Range;
+
+/// Selects indices along one axis for `Matrix::index2d`, like MATLAB's `:` indexing.
+///
+/// `usize`, `Range<usize>` and `&[usize]` convert into a `Sel`, so they can be passed directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sel<'a> {
+    /// Every index, `:`.
+    All,
+    /// A single index.
+    Idx(usize),
+    /// A half-open range of indices, which must not be empty.
+    Range(Range<usize>),
+    /// Any list of indices, in that order, repetitions allowed. It must not be empty.
+    Slice(&'a [usize]),
+}
+
+impl<'a> Sel<'a> {
+    /// Resolves the selector to indices on an axis of length `bound`.
+    fn indices(&self, axis: &'static str, bound: usize) -> Result<Vec<usize>, MatrixError> {
+        let in_bounds = match self {
+            Sel::All => true,
+            Sel::Idx(index) => *index < bound,
+            Sel::Range(range) => range.end <= bound,
+            Sel::Slice(indices) => indices.iter().all(|&index| index < bound),
+        };
+        if !in_bounds {
+            return Err(MatrixError::SelectorOutOfBounds { axis, selector: format!("{:?}", self), bound });
+        }
+
+        let indices: Vec<usize> = match self {
+            Sel::All => (0..bound).collect(),
+            Sel::Idx(index) => vec![*index],
+            Sel::Range(range) => range.clone().collect(),
+            Sel::Slice(indices) => indices.to_vec(),
+        };
+        if indices.is_empty() {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+        Ok(indices)
+    }
+}
+
+impl From<usize> for Sel<'_> {
+    fn from(index: usize) -> Self {
+        Sel::Idx(index)
+    }
+}
+
+impl From<Range<usize>> for Sel<'_> {
+    fn from(range: Range<usize>) -> Self {
+        Sel::Range(range)
+    }
+}
+
+impl<'a> From<&'a [usize]> for Sel<'a> {
+    fn from(indices: &'a [usize]) -> Self {
+        Sel::Slice(indices)
+    }
+}
+
+impl<'a, const N: usize> From<&'a [usize; N]> for Sel<'a> {
+    fn from(indices: &'a [usize; N]) -> Self {
+        Sel::Slice(indices)
+    }
+}
+
+impl Matrix {
+    /// Returns the submatrix at the selected rows and columns, `m(rows, cols)` in MATLAB terms.
+    ///
+    /// An empty range or slice returns `InvalidMatrixSize`, and a selector reaching past the matrix returns
+    /// `SelectorOutOfBounds` with the axis and the selector.
+    /// # Examples
+    /// ```
+    /// # use matrix::{matrix, Matrix, select::Sel};
+    /// fn main() {
+    ///     let m = matrix![
+    ///         0.0, 1.0, 2.0, 3.0;
+    ///         10.0, 11.0, 12.0, 13.0;
+    ///         20.0, 21.0, 22.0, 23.0
+    ///     ];
+    ///
+    ///     // A column, a row, an element.
+    ///     assert_eq!(m.index2d(Sel::All, Sel::Idx(2)).unwrap(), matrix![2.0; 12.0; 22.0]);
+    ///     assert_eq!(m.index2d(1, Sel::All).unwrap(), matrix![10.0, 11.0, 12.0, 13.0]);
+    ///     assert_eq!(m.index2d(2, 3).unwrap(), matrix![23.0]);
+    ///     assert_eq!(m.index2d(Sel::All, Sel::All).unwrap(), m);
+    ///
+    ///     // Ranges and lists of indices, in any combination.
+    ///     assert_eq!(m.index2d(Sel::Slice(&[0, 2]), Sel::Range(1..3)).unwrap(), matrix![1.0, 2.0; 21.0, 22.0]);
+    ///     assert_eq!(m.index2d(0..2, &[3, 0, 3]).unwrap(), matrix![3.0, 0.0, 3.0; 13.0, 10.0, 13.0]);
+    ///     assert_eq!(m.index2d(&[2, 2], 1).unwrap(), matrix![21.0; 21.0]);
+    ///     assert_eq!(m.index2d(1..3, Sel::All).unwrap(), matrix![10.0, 11.0, 12.0, 13.0; 20.0, 21.0, 22.0, 23.0]);
+    ///     assert_eq!(m.index2d(Sel::All, 1..2).unwrap(), matrix![1.0; 11.0; 21.0]);
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError, select::Sel};
+    /// fn main() {
+    ///     let m = matrix![1.0, 2.0; 3.0, 4.0];
+    ///
+    ///     let error = m.index2d(Sel::Range(1..3), Sel::All).unwrap_err();
+    ///     assert_eq!(error.to_string(), "SelectorOutOfBounds: row selector Range(1..3) is out of bounds for length 2");
+    ///     assert_eq!(
+    ///         m.index2d(Sel::All, &[0, 5]),
+    ///         Err(MatrixError::SelectorOutOfBounds { axis: "column", selector: "Slice([0, 5])".into(), bound: 2 })
+    ///     );
+    ///     assert!(matches!(m.index2d(2, 0), Err(MatrixError::SelectorOutOfBounds { axis: "row", .. })));
+    ///
+    ///     assert_eq!(m.index2d(1..1, Sel::All), Err(MatrixError::InvalidMatrixSize));
+    ///     assert_eq!(m.index2d(Sel::All, Sel::Slice(&[])), Err(MatrixError::InvalidMatrixSize));
+    /// }
+    /// ```
+    pub fn index2d<'a>(&self, rows: impl Into<Sel<'a>>, cols: impl Into<Sel<'a>>) -> Result<Matrix, MatrixError> {
+        let rows = rows.into().indices("row", self.rows)?;
+        let cols = cols.into().indices("column", self.cols)?;
+
+        let data = rows
+            .iter()
+            .map(|&i| cols.iter().map(|&j| self.data[i][j]).collect())
+            .collect();
+        Ok(Matrix { rows: rows.len(), cols: cols.len(), data })
+    }
+}
@@ -0,0 +1,252 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+impl Matrix {
+    /// Creates a `rows x cols` matrix from a flat, row-major `Vec`.
+    ///
+    /// Returns `InvalidMatrixSize` if `rows * cols` overflows and `DataMismatch` if the length of `data` is not `rows * cols`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let matrix = Matrix::from_flat(2, 2, vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+    ///     assert_eq!(matrix, matrix![1.0, 2.0; 3.0, 4.0]);
+    ///
+    ///     assert_eq!(Matrix::from_flat(2, 2, vec![1.0]), Err(MatrixError::DataMismatch));
+    ///     assert_eq!(Matrix::from_flat(usize::MAX / 2, 3, vec![]), Err(MatrixError::InvalidMatrixSize));
+    /// }
+    /// ```
+    pub fn from_flat(rows: usize, cols: usize, data: Vec<f64>) -> Result<Matrix, MatrixError> {
+        if rows == 0 || cols == 0 {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+        if dim_checked_mul(rows, cols)? != data.len() {
+            return Err(MatrixError::DataMismatch);
+        }
+
+        let data = data.chunks(cols).map(|row| row.to_vec()).collect();
+        Ok(Matrix { rows, cols, data })
+    }
+
+    /// Returns a `rows x cols` matrix with the same elements, in row-major order.
+    ///
+    /// The number of elements must not change, otherwise it returns `DataMismatch`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let matrix = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    ///
+    ///     assert_eq!(matrix.reshape(3, 2).unwrap(), matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0]);
+    ///     assert_eq!(matrix.reshape(4, 2), Err(MatrixError::DataMismatch));
+    ///     assert_eq!(matrix.reshape(usize::MAX / 2, 3), Err(MatrixError::InvalidMatrixSize));
+    /// }
+    /// ```
+    pub fn reshape(&self, rows: usize, cols: usize) -> Result<Matrix, MatrixError> {
+        if rows == 0 || cols == 0 {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+        if dim_checked_mul(rows, cols)? != self.rows * self.cols {
+            return Err(MatrixError::DataMismatch);
+        }
+
+        Matrix::from_flat(rows, cols, self.data.iter().flatten().copied().collect())
+    }
+
+    /// Computes the Kronecker product, a `(self.rows * other.rows) x (self.cols * other.cols)` block matrix
+    /// where block `(i, j)` is `self[i][j] * other`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0];
+    ///     let b = matrix![1.0; 10.0];
+    ///
+    ///     assert_eq!(a.kronecker(&b).unwrap(), matrix![1.0, 2.0; 10.0, 20.0]);
+    /// }
+    /// ```
+    pub fn kronecker(&self, other: &Matrix) -> Result<Matrix, MatrixError> {
+        let rows = dim_checked_mul(self.rows, other.rows)?;
+        let cols = dim_checked_mul(self.cols, other.cols)?;
+        dim_checked_mul(rows, cols)?;
+
+        let mut result = Matrix::builder().rows(rows).cols(cols).done()?;
+        for (i, row) in self.data.iter().enumerate() {
+            for (j, a) in row.iter().enumerate() {
+                for (k, other_row) in other.data.iter().enumerate() {
+                    let target = &mut result.data[i * other.rows + k][j * other.cols..(j + 1) * other.cols];
+                    for (value, b) in target.iter_mut().zip(other_row.iter()) {
+                        *value = a * b;
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Repeats the matrix `reps_rows` times vertically and `reps_cols` times horizontally.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0];
+    ///
+    ///     assert_eq!(a.tile(2, 2).unwrap(), matrix![1.0, 2.0, 1.0, 2.0; 1.0, 2.0, 1.0, 2.0]);
+    ///     assert_eq!(a.tile(usize::MAX / 2, 3), Err(MatrixError::InvalidMatrixSize));
+    /// }
+    /// ```
+    pub fn tile(&self, reps_rows: usize, reps_cols: usize) -> Result<Matrix, MatrixError> {
+        if reps_rows == 0 || reps_cols == 0 {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+        let rows = dim_checked_mul(self.rows, reps_rows)?;
+        let cols = dim_checked_mul(self.cols, reps_cols)?;
+        dim_checked_mul(rows, cols)?;
+
+        let data = (0..rows)
+            .map(|i| {
+                let row = &self.data[i % self.rows];
+                row.iter().cycle().take(cols).copied().collect()
+            })
+            .collect();
+        Ok(Matrix { rows, cols, data })
+    }
+
+    /// Keeps only the rows for which `predicate(index, row)` returns true, in place.
+    ///
+    /// If no row would be left it returns `InvalidMatrixSize` and leaves the matrix unchanged.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     // Drop the rows containing NaN.
+    ///     let mut data = matrix![1.0, 2.0; f64::NAN, 3.0; 4.0, 5.0];
+    ///     data.retain_rows(|_, row| row.iter().all(|x| !x.is_nan())).unwrap();
+    ///
+    ///     assert_eq!(data, matrix![1.0, 2.0; 4.0, 5.0]);
+    ///     assert_eq!((data.rows, data.cols), (2, 2));
+    ///
+    ///     assert_eq!(data.retain_rows(|_, _| false), Err(MatrixError::InvalidMatrixSize));
+    ///     assert_eq!(data, matrix![1.0, 2.0; 4.0, 5.0]);
+    /// }
+    /// ```
+    pub fn retain_rows(&mut self, mut predicate: impl FnMut(usize, &[f64]) -> bool) -> Result<(), MatrixError> {
+        let keep: Vec<bool> = self.data.iter().enumerate().map(|(i, row)| predicate(i, row)).collect();
+        if !keep.contains(&true) {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+
+        let mut flags = keep.iter();
+        self.data.retain(|_| *flags.next().unwrap_or(&false));
+        self.rows = self.data.len();
+        Ok(())
+    }
+
+    /// Keeps only the columns for which `predicate(index, column)` returns true, in place.
+    ///
+    /// If no column would be left it returns `InvalidMatrixSize` and leaves the matrix unchanged.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     // Drop the columns that are all zeros.
+    ///     let mut data = matrix![1.0, 0.0, 2.0; 3.0, 0.0, 0.0];
+    ///     data.retain_cols(|_, col| col.iter().any(|&x| x != 0.0)).unwrap();
+    ///
+    ///     assert_eq!(data, matrix![1.0, 2.0; 3.0, 0.0]);
+    ///     assert_eq!((data.rows, data.cols), (2, 2));
+    ///
+    ///     // The index can be used too: keep the even columns.
+    ///     let mut wide = matrix![0.0, 1.0, 2.0, 3.0, 4.0];
+    ///     wide.retain_cols(|j, _| j % 2 == 0).unwrap();
+    ///     assert_eq!(wide, matrix![0.0, 2.0, 4.0]);
+    ///
+    ///     assert_eq!(wide.retain_cols(|_, _| false), Err(MatrixError::InvalidMatrixSize));
+    /// }
+    /// ```
+    pub fn retain_cols(&mut self, mut predicate: impl FnMut(usize, &[f64]) -> bool) -> Result<(), MatrixError> {
+        let keep: Vec<bool> = (0..self.cols).map(|j| predicate(j, &self.column_vec(j))).collect();
+        if !keep.contains(&true) {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+
+        for row in self.data.iter_mut() {
+            let mut flags = keep.iter();
+            row.retain(|_| *flags.next().unwrap_or(&false));
+        }
+        self.cols = keep.iter().filter(|&&k| k).count();
+        Ok(())
+    }
+
+    /// Creates an `indices.len() x num_classes` matrix with a 1.0 at (`i`, `indices[i]`) and zeros elsewhere.
+    ///
+    /// Fails with `InvalidMatrixSize` if `indices` is empty or `num_classes` is 0,
+    /// and with `IndexOutOfBounds` if an index is not below `num_classes`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let labels = Matrix::one_hot(&[2, 0, 2], 3).unwrap();
+    ///     assert_eq!(labels, matrix![0.0, 0.0, 1.0; 1.0, 0.0, 0.0; 0.0, 0.0, 1.0]);
+    ///
+    ///     assert_eq!(Matrix::one_hot(&[1, 3], 3), Err(MatrixError::IndexOutOfBounds { index: 3, bound: 3 }));
+    ///     assert_eq!(Matrix::one_hot(&[0], 0), Err(MatrixError::InvalidMatrixSize));
+    /// }
+    /// ```
+    pub fn one_hot(indices: &[usize], num_classes: usize) -> Result<Matrix, MatrixError> {
+        if indices.is_empty() || num_classes == 0 {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+        dim_checked_mul(indices.len(), num_classes)?;
+
+        let data = indices
+            .iter()
+            .map(|&index| {
+                if index >= num_classes {
+                    return Err(MatrixError::IndexOutOfBounds { index, bound: num_classes });
+                }
+                let mut row = vec![0.0; num_classes];
+                row[index] = 1.0;
+                Ok(row)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Matrix { rows: indices.len(), cols: num_classes, data })
+    }
+
+    /// Gathers the rows at `indices`, in that order. An index can appear several times.
+    ///
+    /// With an embedding table, `table.select_rows(&token_ids)` is the lookup of every token.
+    /// Fails with `InvalidMatrixSize` if `indices` is empty and with `IndexOutOfBounds` if an index is not below `rows`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let embeddings = matrix![0.1, 0.2; 0.3, 0.4; 0.5, 0.6];
+    ///
+    ///     assert_eq!(embeddings.select_rows(&[2, 0, 2]).unwrap(), matrix![0.5, 0.6; 0.1, 0.2; 0.5, 0.6]);
+    ///     assert_eq!(embeddings.select_rows(&[1, 5]), Err(MatrixError::IndexOutOfBounds { index: 5, bound: 3 }));
+    ///     assert_eq!(embeddings.select_rows(&[]), Err(MatrixError::InvalidMatrixSize));
+    ///
+    ///     // Same result as multiplying the one-hot encoding by the table.
+    ///     use matrix::mul::Mul;
+    ///     let one_hot = Matrix::one_hot(&[2, 0, 2], 3).unwrap();
+    ///     assert_eq!(one_hot.mul(&embeddings).unwrap(), embeddings.select_rows(&[2, 0, 2]).unwrap());
+    /// }
+    /// ```
+    pub fn select_rows(&self, indices: &[usize]) -> Result<Matrix, MatrixError> {
+        if indices.is_empty() {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+
+        let data = indices
+            .iter()
+            .map(|&index| {
+                self.data
+                    .get(index)
+                    .cloned()
+                    .ok_or(MatrixError::IndexOutOfBounds { index, bound: self.rows })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Matrix { rows: indices.len(), cols: self.cols, data })
+    }
+}
@@ -0,0 +1,75 @@
+use crate::matrix::*;
+use crate::matrix32::Matrix32;
+use crate::imatrix::IMatrix;
+use crate::cmatrix::CMatrix;
+use crate::errors::MatrixError;
+use crate::operations::kernels::{try_zip_rows, zip_rows};
+pub trait Sub<Other = Self> {
+    type Output;
+
+    /// Subtracts a matrix from another.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first matrix.
+    /// * `other` - The matrix to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// A new matrix that is the result of `self - other`.
+    fn sub(&self, other: Other) -> Result<Self::Output, MatrixError>;
+}
+
+impl Sub for Matrix {
+    type Output = Matrix;
+
+    fn sub(&self, other: Self) -> Result<Self::Output, MatrixError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let data = zip_rows(&self.data, &other.data, |a, b| a - b);
+        Ok(Matrix { rows: self.rows, cols: self.cols, data })
+    }
+}
+
+impl Sub for Matrix32 {
+    type Output = Matrix32;
+
+    fn sub(&self, other: Self) -> Result<Self::Output, MatrixError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let data = zip_rows(&self.data, &other.data, |a, b| a - b);
+        Ok(Matrix32 { rows: self.rows, cols: self.cols, data })
+    }
+}
+
+impl Sub for IMatrix {
+    type Output = IMatrix;
+
+    /// Subtracts two integer matrices, returning `Overflow` if any difference does not fit in an `i64`.
+    fn sub(&self, other: Self) -> Result<Self::Output, MatrixError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let data = try_zip_rows(&self.data, &other.data, i64::checked_sub)
+            .ok_or(MatrixError::Overflow { operation: "sub" })?;
+        Ok(IMatrix { rows: self.rows, cols: self.cols, data })
+    }
+}
+
+impl Sub for CMatrix {
+    type Output = CMatrix;
+
+    fn sub(&self, other: Self) -> Result<Self::Output, MatrixError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let data = zip_rows(&self.data, &other.data, |a, b| a - b);
+        Ok(CMatrix { rows: self.rows, cols: self.cols, data })
+    }
+}
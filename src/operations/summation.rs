@@ -0,0 +1,190 @@
+use crate::matrix::*;
+use crate::operations::ops::MatrixOps;
+
+/// How the `*_with` reductions (`Matrix::sum_with`, `mean_with`, `frobenius_norm_with` and `Vector::dot_with`) add
+/// up their terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReductionMode {
+    /// One compensated pass in row-major order, what `MatrixOps::sum`, `mean`, `frobenius_norm` and `dot` do.
+    #[default]
+    Sequential,
+    /// Pairwise summation over a tree that only depends on the number of terms: the terms are halved recursively
+    /// down to blocks of 8, which are summed from left to right, and every pair of halves is then added.
+    ///
+    /// The grouping facing a given number of terms never changes, so neither does the result, bit for bit: not
+    /// with the shape of the matrix, and not with how the halves would be split between threads. The error grows
+    /// with `log2(n) * EPSILON` instead of `n * EPSILON` for naive summation, without the extra work of the
+    /// compensation.
+    Deterministic,
+}
+
+/// Running sum with Neumaier's compensation: the rounding error of every addition is kept in a second term and
+/// added back at the end.
+///
+/// The error of the result is at most about `2 * EPSILON * |sum|` plus a term in `EPSILON^2` that only grows with
+/// the number of values, instead of `n * EPSILON` times the sum of the magnitudes for naive summation. The values
+/// themselves are taken as given: a product or square that was rounded before being added stays rounded.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct CompensatedSum {
+    sum: f64,
+    compensation: f64,
+}
+
+impl CompensatedSum {
+    pub(crate) fn add(&mut self, x: f64) {
+        let t = self.sum + x;
+        // Whichever operand is larger is exact in `t`, the low-order bits of the other are what got lost.
+        self.compensation += if self.sum.abs() >= x.abs() { (self.sum - t) + x } else { (x - t) + self.sum };
+        self.sum = t;
+    }
+
+    pub(crate) fn value(&self) -> f64 {
+        // Non-finite sums make the compensation NaN, the plain sum is already the right answer then.
+        if self.sum.is_finite() { self.sum + self.compensation } else { self.sum }
+    }
+}
+
+/// Sums `values` with `CompensatedSum`.
+pub(crate) fn compensated_sum(values: impl IntoIterator<Item = f64>) -> f64 {
+    values
+        .into_iter()
+        .fold(CompensatedSum::default(), |mut sum, x| {
+            sum.add(x);
+            sum
+        })
+        .value()
+}
+
+/// Number of terms below which `pairwise_sum` adds them sequentially.
+const PAIRWISE_BLOCK: usize = 8;
+
+/// Sums `term(i)` for `i` in `start..end` over the fixed tree of `ReductionMode::Deterministic`.
+pub(crate) fn pairwise_sum(start: usize, end: usize, term: &impl Fn(usize) -> f64) -> f64 {
+    if end - start <= PAIRWISE_BLOCK {
+        return (start..end).fold(0.0, |sum, i| sum + term(i));
+    }
+    let middle = start + (end - start) / 2;
+    pairwise_sum(start, middle, term) + pairwise_sum(middle, end, term)
+}
+
+impl Matrix {
+    /// Returns the sum of all the elements, added up as `mode` says.
+    ///
+    /// `ReductionMode::Sequential` is `MatrixOps::sum`. Every reduction is single-threaded today, so both modes
+    /// give the same result on every run; `Deterministic` also pins down the grouping of the additions, so that it
+    /// stays reproducible if the work is ever split, and it only depends on the number of elements.
+    /// # Example
+    /// ```
+    /// # use matrix::{Matrix, MatrixOps, summation::ReductionMode};
+    /// fn main() {
+    ///     // 2^20 times 0.1: every naive addition rounds, and the errors pile up.
+    ///     let n = 1 << 20;
+    ///     let a = Matrix::from_flat(1, n, vec![0.1; n]).unwrap();
+    ///     let reference = a.sum_with(ReductionMode::Sequential);
+    ///     assert_eq!(reference, a.sum());
+    ///
+    ///     let naive: f64 = a.data[0].iter().fold(0.0, |sum, x| sum + x);
+    ///     let pairwise = a.sum_with(ReductionMode::Deterministic);
+    ///     assert!((naive - reference).abs() > 1e-6);
+    ///     assert!((pairwise - reference).abs() < 1e-10);
+    ///
+    ///     // Magnitudes from 1e-8 to 1e8.
+    ///     let b = Matrix::from_seed(500, 200, 7).unwrap().map_elements(|x| 10f64.powf(16.0 * x - 8.0));
+    ///     let reference = b.sum();
+    ///     let naive: f64 = b.data.iter().flatten().fold(0.0, |sum, x| sum + x);
+    ///     let pairwise = b.sum_with(ReductionMode::Deterministic);
+    ///     assert!((pairwise - reference).abs() * 10.0 < (naive - reference).abs());
+    ///
+    ///     // The same elements in another shape give the same bits.
+    ///     let flat = Matrix::from_flat(1, 500 * 200, b.data.concat()).unwrap();
+    ///     assert_eq!(flat.sum_with(ReductionMode::Deterministic).to_bits(), pairwise.to_bits());
+    /// }
+    /// ```
+    pub fn sum_with(&self, mode: ReductionMode) -> f64 {
+        match mode {
+            ReductionMode::Sequential => MatrixOps::sum(self),
+            ReductionMode::Deterministic => self.pairwise_elements(|x| x),
+        }
+    }
+
+    /// Returns the mean of all the elements, with the sum of `sum_with`.
+    pub fn mean_with(&self, mode: ReductionMode) -> f64 {
+        self.sum_with(mode) / (self.rows * self.cols) as f64
+    }
+
+    /// Returns the Frobenius norm, with the squares added up as `mode` says, see `sum_with`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, Vector, summation::ReductionMode};
+    /// fn main() {
+    ///     let a = matrix![3.0, 0.0; 0.0, 4.0];
+    ///     assert_eq!(a.frobenius_norm_with(ReductionMode::Deterministic), 5.0);
+    ///     assert_eq!(a.mean_with(ReductionMode::Deterministic), 1.75);
+    ///
+    ///     let b = Matrix::from_seed(40, 30, 3).unwrap();
+    ///     assert_eq!(b.frobenius_norm_with(ReductionMode::Sequential), b.frobenius_norm());
+    ///     let v = Vector(b.data.concat());
+    ///     assert_eq!(
+    ///         b.frobenius_norm_with(ReductionMode::Deterministic),
+    ///         v.dot_with(&v, ReductionMode::Deterministic).unwrap().sqrt()
+    ///     );
+    /// }
+    /// ```
+    pub fn frobenius_norm_with(&self, mode: ReductionMode) -> f64 {
+        match mode {
+            ReductionMode::Sequential => self.frobenius_norm(),
+            ReductionMode::Deterministic => self.pairwise_elements(|x| x * x).sqrt(),
+        }
+    }
+
+    /// Sums `f` of every element with `pairwise_sum`, indexing them in row-major order.
+    fn pairwise_elements(&self, f: impl Fn(f64) -> f64) -> f64 {
+        let cols = self.cols;
+        pairwise_sum(0, self.rows * cols, &|i| f(self.data[i / cols][i % cols]))
+    }
+
+    /// Returns the mean of all the elements, summed with compensation like `MatrixOps::sum`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixOps};
+    /// fn main() {
+    ///     assert_eq!(matrix![1.0, 2.0; 3.0, 6.0].mean(), 3.0);
+    ///
+    ///     // 1e16 then 10000 ones: naive summation loses every 1.0, since the spacing of f64 around 1e16 is 2.
+    ///     let mut values = vec![1.0; 10_001];
+    ///     values[0] = 1e16;
+    ///     let a = Matrix::from_flat(1, values.len(), values.clone()).unwrap();
+    ///     assert_eq!(values.iter().sum::<f64>(), 1e16);
+    ///     assert_eq!(a.sum(), 1e16 + 10_000.0);
+    ///     assert_eq!(a.mean(), (1e16 + 10_000.0) / 10_001.0);
+    ///
+    ///     // Cancellation: the large terms cancel out exactly and the small ones survive.
+    ///     let b = matrix![1e100, 1.0, -1e100, 1e-3];
+    ///     assert_eq!(b.sum(), 1.001);
+    /// }
+    /// ```
+    pub fn mean(&self) -> f64 {
+        compensated_sum(self.data.iter().flatten().copied()) / (self.rows * self.cols) as f64
+    }
+
+    /// Returns the Frobenius norm, the square root of the sum of the squared elements, summed with compensation.
+    ///
+    /// It is the `Vector::norm` of the elements, bit for bit.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, Vector};
+    /// fn main() {
+    ///     assert_eq!(matrix![3.0, 0.0; 0.0, 4.0].frobenius_norm(), 5.0);
+    ///
+    ///     for seed in 0..10 {
+    ///         let a = Matrix::from_seed(30, 20, seed).unwrap().scale(1e3);
+    ///         let v = Vector(a.data.concat());
+    ///         assert_eq!(a.frobenius_norm(), v.norm());
+    ///         assert_eq!(v.norm(), v.dot(&v).unwrap().sqrt());
+    ///     }
+    /// }
+    /// ```
+    pub fn frobenius_norm(&self) -> f64 {
+        compensated_sum(self.data.iter().flatten().map(|x| x * x)).sqrt()
+    }
+}
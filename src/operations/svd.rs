@@ -0,0 +1,218 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use crate::operations::mul::Mul;
+use crate::tolerance::DEFAULT_TOLERANCE;
+
+impl Matrix {
+    /// Computes the thin singular value decomposition `A = U * diag(s) * V^T`.
+    ///
+    /// For an `m x n` matrix with `k = min(m, n)` it returns:
+    /// - `U`, an `m x k` matrix with orthonormal columns,
+    /// - the `k` singular values, sorted in descending order,
+    /// - `V^T`, a `k x n` matrix with orthonormal rows.
+    ///
+    /// This first version diagonalizes `A^T A` (or `A A^T` for wide matrices) with `eigen_symmetric`.
+    /// Forming that product squares the condition number, so singular values much smaller than
+    /// `sqrt(f64::EPSILON)` times the largest one lose most of their relative accuracy.
+    /// It is fine for small, reasonably conditioned matrices.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// # use matrix::mul::Mul;
+    /// fn main() {
+    ///     let a = Matrix::builder()
+    ///         .rows(4)
+    ///         .cols(3)
+    ///         .data(vec![
+    ///             vec![1.0, 2.0, 3.0],
+    ///             vec![4.0, 5.0, 6.0],
+    ///             vec![7.0, 8.0, 10.0],
+    ///             vec![1.0, 0.0, 1.0],
+    ///         ])
+    ///         .done()
+    ///         .unwrap();
+    ///
+    ///     let (u, s, vt) = a.svd().unwrap();
+    ///     let reconstructed = u.mul(Matrix::from_diagonal(&s).unwrap()).unwrap().mul(vt).unwrap();
+    ///
+    ///     assert!(s.windows(2).all(|w| w[0] >= w[1]));
+    ///     assert!(reconstructed.diff(&a, 1e-6).is_equal());
+    /// }
+    /// ```
+    /// The Jacobi tolerance follows the scale of the matrix, so small entries are decomposed as accurately:
+    /// ```
+    /// # use matrix::{matrix, Matrix, mul::Mul};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 10.0; 1.0, 0.0, 1.0];
+    ///     let (_, expected, _) = a.svd().unwrap();
+    ///
+    ///     let small = a.scale(1e-7);
+    ///     let (u, s, vt) = small.svd().unwrap();
+    ///     assert!(s.iter().zip(&expected).all(|(x, y)| (x - 1e-7 * y).abs() <= 1e-9 * x));
+    ///
+    ///     // U has orthonormal columns, V^T orthonormal rows.
+    ///     let identity = Matrix::identity(3).unwrap();
+    ///     assert!(u.transpose().mul(&u).unwrap().diff(&identity, 1e-9).is_equal());
+    ///     assert!(vt.mul(&vt.transpose()).unwrap().diff(&identity, 1e-9).is_equal());
+    ///     let reconstructed = u.mul(Matrix::from_diagonal(&s).unwrap()).unwrap().mul(vt).unwrap();
+    ///     assert!(reconstructed.diff(&small, 1e-15).is_equal());
+    /// }
+    /// ```
+    pub fn svd(&self) -> Result<(Matrix, Vec<f64>, Matrix), MatrixError> {
+        if self.rows < self.cols {
+            let (u, s, vt) = self.transpose().svd()?;
+            return Ok((vt.transpose(), s, u.transpose()));
+        }
+
+        let at = self.transpose();
+        let gram = at.mul(self)?;
+        let (values, v) = gram.eigen_symmetric(DEFAULT_TOLERANCE.threshold(gram.frobenius_norm()), 100)?;
+        let singular: Vec<f64> = values.iter().map(|l| l.max(0.0).sqrt()).collect();
+
+        let av = self.mul(&v)?;
+        let (m, k) = (self.rows, self.cols);
+        let cutoff = singular[0] * f64::EPSILON * m as f64;
+        let mut u_cols: Vec<Vec<f64>> = Vec::with_capacity(k);
+
+        for (j, &sigma) in singular.iter().enumerate() {
+            let col = if sigma > cutoff {
+                (0..m).map(|i| av.data[i][j] / sigma).collect()
+            } else {
+                orthonormal_complement(&u_cols, m)
+            };
+            u_cols.push(col);
+        }
+
+        let u = Matrix {
+            rows: m,
+            cols: k,
+            data: (0..m).map(|i| u_cols.iter().map(|c| c[i]).collect()).collect(),
+        };
+
+        Ok((u, singular, v.transpose()))
+    }
+
+    /// Computes the Moore-Penrose pseudo-inverse `A+ = V * diag(1 / s) * U^T` from the SVD.
+    ///
+    /// Singular values at or below `tol` are treated as zero, so this works for rectangular and
+    /// rank-deficient matrices. `tol` is an absolute threshold: it is compared directly to the singular values,
+    /// so it has to follow the scale of the matrix. For a relative threshold, scale it by the largest singular value,
+    /// e.g. `max(rows, cols) * f64::EPSILON * s[0]` (the usual default).
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// # use matrix::mul::Mul;
+    /// fn main() {
+    ///     // Rank 1: the second column is twice the first one.
+    ///     let a = Matrix::builder()
+    ///         .rows(3)
+    ///         .cols(2)
+    ///         .data(vec![
+    ///             vec![1.0, 2.0],
+    ///             vec![2.0, 4.0],
+    ///             vec![3.0, 6.0],
+    ///         ])
+    ///         .done()
+    ///         .unwrap();
+    ///
+    ///     let pinv = a.pinv(1e-10).unwrap();
+    ///     assert_eq!((pinv.rows, pinv.cols), (2, 3));
+    ///
+    ///     // Penrose conditions: A A+ A = A and A+ A A+ = A+.
+    ///     let a_pinv_a = a.mul(&pinv).unwrap().mul(&a).unwrap();
+    ///     let pinv_a_pinv = pinv.mul(&a).unwrap().mul(&pinv).unwrap();
+    ///
+    ///     assert!(a_pinv_a.diff(&a, 1e-9).is_equal());
+    ///     assert!(pinv_a_pinv.diff(&pinv, 1e-9).is_equal());
+    /// }
+    /// ```
+    pub fn pinv(&self, tol: f64) -> Result<Matrix, MatrixError> {
+        let (u, s, vt) = self.svd()?;
+        let reciprocals: Vec<f64> = s.iter().map(|&x| if x > tol { 1.0 / x } else { 0.0 }).collect();
+
+        vt.transpose().mul(Matrix::from_diagonal(&reciprocals)?)?.mul(u.transpose())
+    }
+
+    /// Returns the best rank-`k` approximation of the matrix (in the Frobenius norm), by truncating the SVD.
+    ///
+    /// `k` must be between 1 and `min(rows, cols)`, otherwise it returns `InvalidOperation`.
+    /// # Example
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     // Every row is a multiple of [1, 2, 3], so the matrix has rank 1.
+    ///     let a = Matrix::builder()
+    ///         .rows(3)
+    ///         .cols(3)
+    ///         .data(vec![
+    ///             vec![1.0, 2.0, 3.0],
+    ///             vec![2.0, 4.0, 6.0],
+    ///             vec![-1.0, -2.0, -3.0],
+    ///         ])
+    ///         .done()
+    ///         .unwrap();
+    ///
+    ///     let approx = a.low_rank_approx(1).unwrap();
+    ///
+    ///     assert!(a.approx_error(&approx).unwrap() < 1e-9);
+    ///     assert!(a.low_rank_approx(0).is_err());
+    ///     assert!(a.low_rank_approx(4).is_err());
+    /// }
+    /// ```
+    pub fn low_rank_approx(&self, k: usize) -> Result<Matrix, MatrixError> {
+        if k == 0 || k > self.rows.min(self.cols) {
+            return Err(MatrixError::InvalidOperation("rank must be between 1 and min(rows, cols)"));
+        }
+
+        let (u, s, vt) = self.svd()?;
+        let mut result = Matrix::builder().rows(self.rows).cols(self.cols).done()?;
+        for (r, sigma) in s.iter().take(k).enumerate() {
+            for i in 0..self.rows {
+                let scaled = sigma * u.data[i][r];
+                for (value, v) in result.data[i].iter_mut().zip(vt.data[r].iter()) {
+                    *value += scaled * v;
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns the Frobenius norm of `self - approx`, erroring with `DimensionMismatch` on different shapes.
+    pub fn approx_error(&self, approx: &Matrix) -> Result<f64, MatrixError> {
+        if self.rows != approx.rows || self.cols != approx.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let sum: f64 = self.data
+            .iter()
+            .flatten()
+            .zip(approx.data.iter().flatten())
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum();
+        Ok(sum.sqrt())
+    }
+}
+
+/// Finds a unit vector of length `m` orthogonal to every vector in `basis` by Gram-Schmidt on the standard basis.
+fn orthonormal_complement(basis: &[Vec<f64>], m: usize) -> Vec<f64> {
+    let mut best = vec![0.0; m];
+    let mut best_norm = 0.0;
+
+    for e in 0..m {
+        let mut candidate = vec![0.0; m];
+        candidate[e] = 1.0;
+        for b in basis {
+            let projection: f64 = b.iter().zip(candidate.iter()).map(|(x, y)| x * y).sum();
+            candidate.iter_mut().zip(b.iter()).for_each(|(c, x)| *c -= projection * x);
+        }
+
+        let norm = candidate.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm > best_norm {
+            best_norm = norm;
+            best = candidate;
+        }
+    }
+
+    best.iter_mut().for_each(|x| *x /= best_norm);
+    best
+}
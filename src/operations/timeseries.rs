@@ -0,0 +1,201 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+
+impl Matrix {
+    /// Builds the design matrix of an autoregressive model from a series with one observation per row.
+    ///
+    /// For every lag in `lags`, in order, the result gets the columns of the series shifted down by that lag:
+    /// row `t` holds `x[t + m - lag]` for each lag, where `m` is the largest lag, so the first `m` rows, which
+    /// lack the history, are dropped. A lag of 0 gives the unshifted series, aligned with the other columns,
+    /// which is the usual way to get the regression target. The result is `(rows - m) x (lags.len() * cols)`.
+    ///
+    /// Returns `InvalidOperation` if `lags` is empty or if the largest lag leaves no row (`m >= rows`).
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let x = matrix![1.0; 2.0; 3.0; 4.0; 5.0];
+    ///
+    ///     assert_eq!(x.lagged(&[0, 1, 2]).unwrap(), matrix![3.0, 2.0, 1.0; 4.0, 3.0, 2.0; 5.0, 4.0, 3.0]);
+    ///     // Two series: one column per lag and series.
+    ///     let xy = matrix![1.0, 10.0; 2.0, 20.0; 3.0, 30.0];
+    ///     assert_eq!(xy.lagged(&[1]).unwrap(), matrix![1.0, 10.0; 2.0, 20.0]);
+    ///
+    ///     assert!(x.lagged(&[5]).is_err());
+    ///     assert!(x.lagged(&[]).is_err());
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{Matrix, mul::Mul};
+    /// fn main() {
+    ///     // x[t] = 0.6 x[t - 1] - 0.3 x[t - 2] + noise
+    ///     let n = 5000;
+    ///     let noise = Matrix::from_seed(n, 1, 4).unwrap();
+    ///     let mut x = vec![0.0, 0.0];
+    ///     for t in 2..n {
+    ///         x.push(0.6 * x[t - 1] - 0.3 * x[t - 2] + noise.data[t][0] - 0.5);
+    ///     }
+    ///     let series = Matrix::from_flat(n, 1, x).unwrap();
+    ///
+    ///     // Least squares with the normal equations: (X^T X) beta = X^T y.
+    ///     let design = series.lagged(&[1, 2]).unwrap();
+    ///     let target = Matrix::from_flat(n - 2, 1, series.data[2..].concat()).unwrap();
+    ///     let xt = design.transpose();
+    ///     let beta = xt.mul(&design).unwrap().solve(&xt.mul(&target).unwrap()).unwrap();
+    ///
+    ///     assert!((beta.data[0][0] - 0.6).abs() < 0.05);
+    ///     assert!((beta.data[1][0] + 0.3).abs() < 0.05);
+    /// }
+    /// ```
+    pub fn lagged(&self, lags: &[usize]) -> Result<Matrix, MatrixError> {
+        let Some(&max_lag) = lags.iter().max() else {
+            return Err(MatrixError::InvalidOperation("lagged requires at least one lag"));
+        };
+        if max_lag >= self.rows {
+            return Err(MatrixError::InvalidOperation("lagged requires every lag to be smaller than the number of rows"));
+        }
+
+        let data = (max_lag..self.rows)
+            .map(|t| lags.iter().flat_map(|&lag| self.data[t - lag].iter().copied()).collect())
+            .collect();
+        Ok(Matrix { rows: self.rows - max_lag, cols: lags.len() * self.cols, data })
+    }
+
+    /// Differences successive rows `order` times: every pass replaces row `i` by `row[i + 1] - row[i]` and removes
+    /// one row, like `numpy.diff(axis=0, n=order)`.
+    ///
+    /// Order 0 returns the matrix unchanged. Returns `InvalidOperation` if `order >= rows`, which would leave no row.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let squares = matrix![0.0, 1.0; 1.0, 2.0; 4.0, 4.0; 9.0, 8.0];
+    ///
+    ///     assert_eq!(squares.diff_rows(1).unwrap(), matrix![1.0, 1.0; 3.0, 2.0; 5.0, 4.0]);
+    ///     // The second difference of n^2 is constant.
+    ///     assert_eq!(squares.diff_rows(2).unwrap(), matrix![2.0, 1.0; 2.0, 2.0]);
+    ///     assert_eq!(squares.diff_rows(0).unwrap(), squares);
+    ///     assert!(squares.diff_rows(4).is_err());
+    /// }
+    /// ```
+    pub fn diff_rows(&self, order: usize) -> Result<Matrix, MatrixError> {
+        if order >= self.rows {
+            return Err(MatrixError::InvalidOperation("diff_rows requires an order smaller than the number of rows"));
+        }
+
+        let mut data = self.data.clone();
+        for _ in 0..order {
+            data = data
+                .windows(2)
+                .map(|pair| pair[1].iter().zip(pair[0].iter()).map(|(next, previous)| next - previous).collect())
+                .collect();
+        }
+        Ok(Matrix { rows: self.rows - order, cols: self.cols, data })
+    }
+
+    /// Computes the mean of every window of `window` consecutive elements along each row: element `(i, j)` of
+    /// the result is the mean of `self[i][j..j + window]`, so the result has `cols - window + 1` columns.
+    ///
+    /// Each row is scanned with a running update, `O(cols)` whatever the window. Returns
+    /// `InvalidOperation` if `window` is 0 or larger than `cols`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0, 3.0, 4.0, 5.0; 10.0, 0.0, 10.0, 0.0, 10.0];
+    ///
+    ///     assert_eq!(a.rolling_mean_rows(2).unwrap(), matrix![1.5, 2.5, 3.5, 4.5; 5.0, 5.0, 5.0, 5.0]);
+    ///     assert_eq!(a.rolling_mean_rows(5).unwrap(), matrix![3.0; 6.0]);
+    ///     assert_eq!(a.rolling_mean_rows(1).unwrap(), a);
+    ///     assert!(a.rolling_mean_rows(0).is_err());
+    ///     assert!(a.rolling_mean_rows(6).is_err());
+    /// }
+    /// ```
+    pub fn rolling_mean_rows(&self, window: usize) -> Result<Matrix, MatrixError> {
+        self.rolling_rows(window, |mean, _| mean)
+    }
+
+    /// Computes the population standard deviation (dividing by `window`) of every window along each row, with
+    /// the shape and errors of `rolling_mean_rows`.
+    ///
+    /// The running update follows Welford: it tracks the mean and the sum of squared deviations from it, instead of
+    /// the sum of squares, so a large common offset does not cancel the variance out.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    ///     assert_eq!(a.rolling_std_rows(8).unwrap(), matrix![2.0]);
+    ///     assert_eq!(a.rolling_std_rows(1).unwrap(), Matrix::zeros(1, 8).unwrap());
+    ///
+    ///     // With an offset of 1e9, the sum of squares approach returns garbage (or a negative variance).
+    ///     let offset = matrix![1e9 + 1.0, 1e9 + 2.0, 1e9 + 3.0, 1e9 + 4.0, 1e9 + 5.0];
+    ///     let std = offset.rolling_std_rows(3).unwrap();
+    ///     assert!(std.diff(&Matrix::from_flat(1, 3, vec![(2.0f64 / 3.0).sqrt(); 3]).unwrap(), 1e-6).is_equal());
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     // Against brute force recomputation of every window.
+    ///     let a = Matrix::from_seed(4, 300, 5).unwrap().scale(100.0);
+    ///     for window in [1, 2, 7, 50, 300] {
+    ///         let means = a.rolling_mean_rows(window).unwrap();
+    ///         let stds = a.rolling_std_rows(window).unwrap();
+    ///         assert_eq!((means.cols, stds.cols), (300 - window + 1, 300 - window + 1));
+    ///         for (i, row) in a.data.iter().enumerate() {
+    ///             for (j, values) in row.windows(window).enumerate() {
+    ///                 let mean = values.iter().sum::<f64>() / window as f64;
+    ///                 let variance = values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / window as f64;
+    ///                 assert!((means.data[i][j] - mean).abs() < 1e-10);
+    ///                 assert!((stds.data[i][j] - variance.sqrt()).abs() < 1e-10);
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn rolling_std_rows(&self, window: usize) -> Result<Matrix, MatrixError> {
+        self.rolling_rows(window, |_, squared_deviations| (squared_deviations / window as f64).sqrt())
+    }
+
+    /// Slides a window along every row, calling `f(mean, sum of squared deviations)` for each position.
+    fn rolling_rows(&self, window: usize, f: impl Fn(f64, f64) -> f64) -> Result<Matrix, MatrixError> {
+        if window == 0 || window > self.cols {
+            return Err(MatrixError::InvalidOperation("rolling window must be between 1 and the number of columns"));
+        }
+
+        let w = window as f64;
+        let data = self.data
+            .iter()
+            .map(|row| {
+                let (mut mean, mut m2) = welford(&row[..window]);
+                let mut result = Vec::with_capacity(self.cols - window + 1);
+                result.push(f(mean, m2));
+                for start in 1..=self.cols - window {
+                    let (old, new) = (row[start - 1], row[start + window - 1]);
+                    if start % window == 0 {
+                        // Restarting from scratch once per window length keeps rounding from drifting
+                        // along long rows, and costs `O(cols)` in total.
+                        (mean, m2) = welford(&row[start..start + window]);
+                    } else {
+                        let previous_mean = mean;
+                        mean += (new - old) / w;
+                        m2 = (m2 + (new - old) * (new - mean + old - previous_mean)).max(0.0);
+                    }
+                    result.push(f(mean, m2));
+                }
+                result
+            })
+            .collect();
+        Ok(Matrix { rows: self.rows, cols: self.cols - window + 1, data })
+    }
+}
+
+/// Mean and sum of squared deviations of `values`, with Welford's update.
+fn welford(values: &[f64]) -> (f64, f64) {
+    values.iter().enumerate().fold((0.0, 0.0), |(mean, m2), (k, &x)| {
+        let delta = x - mean;
+        let mean = mean + delta / (k + 1) as f64;
+        (mean, m2 + delta * (x - mean))
+    })
+}
@@ -0,0 +1,77 @@
+use crate::matrix::*;
+use crate::errors::MatrixError;
+use crate::operations::{add::Add, mul::Mul, sub::Sub};
+
+impl Matrix {
+    /// Panics with the error message, reporting `DimensionMismatch` as a `ShapeMismatch` with both shapes.
+    fn expect_shapes<T>(&self, other: &Matrix, operation: &'static str, result: Result<T, MatrixError>) -> T {
+        result.unwrap_or_else(|error| match error {
+            MatrixError::DimensionMismatch => panic!(
+                "{}",
+                MatrixError::ShapeMismatch { operation, left: (self.rows, self.cols), right: (other.rows, other.cols) }
+            ),
+            error => panic!("{}", error),
+        })
+    }
+
+    /// Adds two matrices like `Add::add`, but panics instead of returning an error, for quick scripts.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes differ, with a message naming the operation and both shapes.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0; 3.0, 4.0];
+    ///
+    ///     assert_eq!(a.add_unchecked(&a), matrix![2.0, 4.0; 6.0, 8.0]);
+    ///     assert_eq!(a.sub_unchecked(&a), matrix![0.0, 0.0; 0.0, 0.0]);
+    ///     assert_eq!(a.mul_unchecked(&a), matrix![7.0, 10.0; 15.0, 22.0]);
+    /// }
+    /// ```
+    /// ```should_panic
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     matrix![1.0, 2.0; 3.0, 4.0].add_unchecked(&matrix![1.0; 2.0; 3.0]);
+    /// }
+    /// ```
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// use std::panic::catch_unwind;
+    ///
+    /// fn message(result: std::thread::Result<Matrix>) -> String {
+    ///     *result.unwrap_err().downcast::<String>().unwrap()
+    /// }
+    ///
+    /// fn main() {
+    ///     let (a, b) = (matrix![1.0, 2.0; 3.0, 4.0], matrix![1.0; 2.0; 3.0]);
+    ///
+    ///     assert_eq!(
+    ///         message(catch_unwind(|| a.add_unchecked(&b))),
+    ///         "ShapeMismatch: `add` got incompatible shapes 2x2 and 3x1"
+    ///     );
+    ///     assert_eq!(
+    ///         message(catch_unwind(|| a.sub_unchecked(&b))),
+    ///         "ShapeMismatch: `sub` got incompatible shapes 2x2 and 3x1"
+    ///     );
+    ///     assert_eq!(
+    ///         message(catch_unwind(|| b.mul_unchecked(&b))),
+    ///         "ShapeMismatch: `mul` got incompatible shapes 3x1 and 3x1"
+    ///     );
+    /// }
+    /// ```
+    pub fn add_unchecked(&self, other: &Matrix) -> Matrix {
+        self.expect_shapes(other, "add", self.add(other.clone()))
+    }
+
+    /// Subtracts `other` like `Sub::sub`, but panics instead of returning an error, see `add_unchecked`.
+    pub fn sub_unchecked(&self, other: &Matrix) -> Matrix {
+        self.expect_shapes(other, "sub", self.sub(other.clone()))
+    }
+
+    /// Multiplies by `other` like `Mul::mul`, but panics instead of returning an error, see `add_unchecked`.
+    pub fn mul_unchecked(&self, other: &Matrix) -> Matrix {
+        self.expect_shapes(other, "mul", self.mul(other))
+    }
+}
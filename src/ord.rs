@@ -0,0 +1,115 @@
+use crate::matrix::Matrix;
+use std::cmp::Ordering;
+
+impl Matrix {
+    /// Total order on matrices: by number of rows, then columns, then elements in row-major order
+    /// compared with `f64::total_cmp`.
+    ///
+    /// Unlike `partial_cmp` on `f64`, every pair of matrices is ordered:
+    /// - `-0.0` sorts before `0.0`, they are not equal.
+    /// - Positive `NaN` sorts after `+inf` and negative `NaN` before `-inf`, and a `NaN` is equal to itself when
+    ///   the bit patterns match.
+    ///
+    /// Two matrices compare `Equal` exactly when they have the same shape and the same bit patterns,
+    /// consistent with `HashableMatrix`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// use std::cmp::Ordering;
+    ///
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0; 3.0, 4.0];
+    ///
+    ///     assert_eq!(a.total_cmp(&a.clone()), Ordering::Equal);
+    ///     assert_eq!(a.total_cmp(&matrix![1.0, 2.0; 3.0, 5.0]), Ordering::Less);
+    ///     // The shape comes first: 1 row sorts before 2 rows whatever the elements.
+    ///     assert_eq!(matrix![9.0, 9.0, 9.0].total_cmp(&a), Ordering::Less);
+    ///     assert_eq!(matrix![9.0; 9.0].total_cmp(&a), Ordering::Less);
+    ///
+    ///     assert_eq!(matrix![-0.0].total_cmp(&matrix![0.0]), Ordering::Less);
+    ///     assert_eq!(matrix![f64::NAN].total_cmp(&matrix![f64::INFINITY]), Ordering::Greater);
+    ///     assert_eq!(matrix![f64::NAN].total_cmp(&matrix![f64::NAN]), Ordering::Equal);
+    /// }
+    /// ```
+    pub fn total_cmp(&self, other: &Matrix) -> Ordering {
+        self.rows
+            .cmp(&other.rows)
+            .then(self.cols.cmp(&other.cols))
+            .then_with(|| {
+                self.data
+                    .iter()
+                    .flatten()
+                    .zip(other.data.iter().flatten())
+                    .map(|(x, y)| x.total_cmp(y))
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            })
+    }
+}
+
+/// Wrapper around `Matrix` that implements `Ord` and `Eq` with `Matrix::total_cmp`, so matrices can be
+/// `BTreeMap` keys or sorted.
+///
+/// The `NaN` and `-0.0` caveats of `total_cmp` apply: `0.0` and `-0.0` are different keys.
+/// # Example
+/// ```
+/// # use matrix::{matrix, Matrix, OrdMatrix};
+/// use std::collections::BTreeMap;
+///
+/// fn main() {
+///     let mut cache = BTreeMap::new();
+///     cache.insert(OrdMatrix(matrix![2.0, 0.0; 0.0, 2.0]), "scaled identity");
+///     cache.insert(OrdMatrix(matrix![1.0; 2.0]), "column");
+///     cache.insert(OrdMatrix(matrix![1.0, 2.0]), "row");
+///     cache.insert(OrdMatrix(matrix![-1.0, 5.0]), "another row");
+///     cache.insert(OrdMatrix(matrix![0.0]), "zero");
+///     cache.insert(OrdMatrix(matrix![-0.0]), "negative zero");
+///
+///     assert_eq!(cache.get(&OrdMatrix(matrix![1.0, 2.0])), Some(&"row"));
+///     assert_eq!(cache.get(&OrdMatrix(matrix![2.0, 0.0; 0.0, 2.0])), Some(&"scaled identity"));
+///     assert_eq!(cache.get(&OrdMatrix(matrix![2.0, 1.0])), None);
+///
+///     let order: Vec<_> = cache.values().copied().collect();
+///     assert_eq!(order, ["negative zero", "zero", "another row", "row", "column", "scaled identity"]);
+///
+///     let mut sorted = vec![OrdMatrix(matrix![3.0]), OrdMatrix(matrix![f64::NAN]), OrdMatrix(matrix![-3.0])];
+///     sorted.sort();
+///     assert_eq!(sorted[0].0, matrix![-3.0]);
+///     assert!(sorted[2].0.data[0][0].is_nan());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OrdMatrix(pub Matrix);
+
+impl OrdMatrix {
+    /// Returns the wrapped matrix.
+    pub fn into_inner(self) -> Matrix {
+        self.0
+    }
+}
+
+impl From<Matrix> for OrdMatrix {
+    fn from(matrix: Matrix) -> Self {
+        Self(matrix)
+    }
+}
+
+impl PartialEq for OrdMatrix {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(&other.0).is_eq()
+    }
+}
+
+impl Eq for OrdMatrix {}
+
+impl PartialOrd for OrdMatrix {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrdMatrix {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
@@ -0,0 +1,136 @@
+use crate::matrix::{dim_checked_mul, Matrix};
+use crate::errors::MatrixError;
+use crate::operations::raster::ScalePolicy;
+use std::path::Path;
+
+impl Matrix {
+    /// Writes the matrix as a binary (P5) grayscale PGM image, one pixel per element, normalized with
+    /// `ScalePolicy::MinMaxNormalize`; see `to_pgm_bytes`. Failures return `Io` with the path.
+    pub fn to_pgm(&self, path: impl AsRef<Path>) -> Result<(), MatrixError> {
+        let bytes = self.to_pgm_bytes(ScalePolicy::MinMaxNormalize)?;
+        let path = path.as_ref();
+        std::fs::write(path, bytes).map_err(|error| MatrixError::Io { path: path.to_path_buf(), kind: error.kind() })
+    }
+
+    /// Reads a binary (P5) grayscale PGM image, see `from_pgm_bytes`. A file that can't be read returns `Io` with
+    /// the path.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let path = std::env::temp_dir().join(format!("matrix-pgm-{}.pgm", std::process::id()));
+    ///     let heatmap = matrix![-1.0, 0.0, 1.0; 0.5, 0.25, -0.5];
+    ///
+    ///     heatmap.to_pgm(&path).unwrap();
+    ///     assert_eq!(Matrix::from_pgm(&path).unwrap(), matrix![0.0, 128.0, 255.0; 191.0, 159.0, 64.0]);
+    ///     std::fs::remove_file(&path).unwrap();
+    ///
+    ///     let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/gradient_4x3.pgm");
+    ///     let gradient = Matrix::from_pgm(fixture).unwrap();
+    ///     assert_eq!(gradient, matrix![0.0, 85.0, 170.0, 255.0; 10.0, 20.0, 30.0, 40.0; 255.0, 128.0, 64.0, 0.0]);
+    ///     assert!(Matrix::from_pgm(path).is_err());
+    /// }
+    /// ```
+    pub fn from_pgm(path: impl AsRef<Path>) -> Result<Matrix, MatrixError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(|error| MatrixError::Io { path: path.to_path_buf(), kind: error.kind() })?;
+        Matrix::from_pgm_bytes(&bytes)
+    }
+
+    /// Encodes the matrix as a binary (P5) PGM image with a maxval of 255: `cols` pixels wide and `rows` high,
+    /// with the bytes of `to_u8_grid(policy)`, whose errors it returns.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, raster::ScalePolicy};
+    /// fn main() {
+    ///     let a = matrix![0.0, 100.0, 255.0; 300.0, -4.0, 1.0];
+    ///     let bytes = a.to_pgm_bytes(ScalePolicy::Clamp0To255).unwrap();
+    ///
+    ///     assert_eq!(bytes, b"P5\n3 2\n255\n\x00\x64\xff\xff\x00\x01");
+    ///     assert_eq!(Matrix::from_pgm_bytes(&bytes).unwrap(), matrix![0.0, 100.0, 255.0; 255.0, 0.0, 1.0]);
+    /// }
+    /// ```
+    pub fn to_pgm_bytes(&self, policy: ScalePolicy) -> Result<Vec<u8>, MatrixError> {
+        let grid = self.to_u8_grid(policy)?;
+        let mut bytes = format!("P5\n{} {}\n255\n", self.cols, self.rows).into_bytes();
+        grid.iter().for_each(|row| bytes.extend_from_slice(row));
+        Ok(bytes)
+    }
+
+    /// Parses a binary (P5) PGM image with a maxval up to 255, every pixel becoming an element equal to its value.
+    ///
+    /// `#` comments are accepted in the header. Another magic number, a malformed header, a maxval outside
+    /// `1..=255` (16-bit images) and missing pixels return `UnsupportedFormat` with the reason; a zero width or
+    /// height returns `InvalidMatrixSize`. Bytes after the last pixel are ignored, as the format allows.
+    /// # Example
+    /// ```
+    /// # use matrix::{Matrix, MatrixError};
+    /// fn reason(bytes: &[u8]) -> String {
+    ///     match Matrix::from_pgm_bytes(bytes) {
+    ///         Err(MatrixError::UnsupportedFormat { format: "pgm", reason }) => reason,
+    ///         other => panic!("unexpected {:?}", other),
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     assert_eq!(Matrix::from_pgm_bytes(b"P5 2 1 # size\n15\n\x00\x0f").unwrap().data, vec![vec![0.0, 15.0]]);
+    ///
+    ///     assert_eq!(reason(b"P2\n2 1\n255\n0 1\n"), "missing the P5 magic number (only binary grayscale is supported)");
+    ///     assert_eq!(reason(b"P5\n2 x\n255\n\x00\x00"), "invalid height in the header");
+    ///     assert_eq!(reason(b"P5\n2 1\n"), "missing maxval in the header");
+    ///     assert_eq!(reason(b"P5\n2 1\n65535\n\x00\x00\x00\x00"), "maxval 65535 is not supported, only 1 to 255");
+    ///     assert_eq!(reason(b"P5\n2 2\n255\n\x00\x00\x00"), "expected 4 bytes of pixel data, got 3");
+    ///     assert_eq!(Matrix::from_pgm_bytes(b"P5\n0 2\n255\n"), Err(MatrixError::InvalidMatrixSize));
+    /// }
+    /// ```
+    pub fn from_pgm_bytes(bytes: &[u8]) -> Result<Matrix, MatrixError> {
+        let unsupported = |reason: String| MatrixError::UnsupportedFormat { format: "pgm", reason };
+        if !bytes.starts_with(b"P5") {
+            return Err(unsupported("missing the P5 magic number (only binary grayscale is supported)".into()));
+        }
+
+        let mut position = 2;
+        let mut field = |name: &str| -> Result<usize, MatrixError> {
+            let missing = || unsupported(format!("missing {} in the header", name));
+            // Whitespace and comments, which run to the end of the line.
+            loop {
+                match bytes.get(position) {
+                    Some(b) if b.is_ascii_whitespace() => position += 1,
+                    Some(b'#') => {
+                        while bytes.get(position).is_some_and(|&b| b != b'\n') {
+                            position += 1;
+                        }
+                    }
+                    Some(_) => break,
+                    None => return Err(missing()),
+                }
+            }
+            let start = position;
+            while bytes.get(position).is_some_and(|b| !b.is_ascii_whitespace()) {
+                position += 1;
+            }
+            std::str::from_utf8(&bytes[start..position])
+                .ok()
+                .and_then(|text| text.parse().ok())
+                .ok_or_else(|| unsupported(format!("invalid {} in the header", name)))
+        };
+        let width = field("width")?;
+        let height = field("height")?;
+        let maxval = field("maxval")?;
+        if !(1..=255).contains(&maxval) {
+            return Err(unsupported(format!("maxval {} is not supported, only 1 to 255", maxval)));
+        }
+        if width == 0 || height == 0 {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+
+        // A single whitespace byte separates the header from the pixels.
+        let pixels = bytes.get(position + 1..).unwrap_or(&[]);
+        let len = dim_checked_mul(height, width)?;
+        if pixels.len() < len {
+            return Err(unsupported(format!("expected {} bytes of pixel data, got {}", len, pixels.len())));
+        }
+        let values = pixels[..len].iter().map(|&b| f64::from(b)).collect();
+        Matrix::from_flat(height, width, values)
+    }
+}
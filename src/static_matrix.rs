@@ -0,0 +1,232 @@
+use crate::matrix::Matrix;
+use crate::errors::MatrixError;
+use crate::view::MatrixLike;
+use std::ops::{Add, Mul};
+
+/// Matrix whose dimensions are part of its type, backed by `[[f64; C]; R]`.
+///
+/// Dimension rules are enforced by the compiler, so `add` and `mul` cannot fail:
+/// `StaticMatrix<R, K> * StaticMatrix<K, C>` is a `StaticMatrix<R, C>` and any other combination doesn't compile.
+/// # Example
+/// ```
+/// # use matrix::StaticMatrix;
+/// fn main() {
+///     let a = StaticMatrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+///     let b = StaticMatrix::new([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+///
+///     let c: StaticMatrix<2, 2> = a * b;
+///     assert_eq!(c, StaticMatrix::new([[4.0, 5.0], [10.0, 11.0]]));
+///     assert_eq!(c + StaticMatrix::identity(), StaticMatrix::new([[5.0, 5.0], [10.0, 12.0]]));
+/// }
+/// ```
+/// ```compile_fail
+/// # use matrix::StaticMatrix;
+/// fn main() {
+///     let a = StaticMatrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+///     // 2x3 * 2x3 is rejected at compile time.
+///     let c = a * a;
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StaticMatrix<const R: usize, const C: usize> {
+    pub data: [[f64; C]; R],
+}
+
+impl<const R: usize, const C: usize> StaticMatrix<R, C> {
+    /// Creates a matrix from its rows.
+    pub fn new(data: [[f64; C]; R]) -> Self {
+        Self { data }
+    }
+
+    /// Creates a matrix filled with zeros.
+    pub fn zeros() -> Self {
+        Self { data: [[0.0; C]; R] }
+    }
+
+    /// Returns (rows, cols), which are always (`R`, `C`).
+    pub fn shape(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    /// Gets the element at (`row`, `col`), `None` if it is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<f64> {
+        self.data.get(row).and_then(|r| r.get(col)).copied()
+    }
+
+    /// Returns the transpose, a `StaticMatrix<C, R>`.
+    /// # Example
+    /// ```
+    /// # use matrix::StaticMatrix;
+    /// fn main() {
+    ///     let a = StaticMatrix::new([[1.0, 2.0, 3.0]]);
+    ///     let t: StaticMatrix<3, 1> = a.transpose();
+    ///
+    ///     assert_eq!(t, StaticMatrix::new([[1.0], [2.0], [3.0]]));
+    /// }
+    /// ```
+    pub fn transpose(&self) -> StaticMatrix<C, R> {
+        let mut result = StaticMatrix::<C, R>::zeros();
+        for (i, row) in self.data.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                result.data[j][i] = *value;
+            }
+        }
+        result
+    }
+}
+
+impl<const N: usize> StaticMatrix<N, N> {
+    /// Creates the `N x N` identity matrix.
+    pub fn identity() -> Self {
+        let mut result = Self::zeros();
+        for i in 0..N {
+            result.data[i][i] = 1.0;
+        }
+        result
+    }
+
+    /// Computes the determinant with Gaussian elimination and partial pivoting, on a copy of the matrix.
+    /// # Example
+    /// ```
+    /// # use matrix::StaticMatrix;
+    /// fn main() {
+    ///     let a = StaticMatrix::new([[1.0, 2.0], [3.0, 4.0]]);
+    ///     let b = StaticMatrix::new([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+    ///
+    ///     assert!((a.determinant() + 2.0).abs() < 1e-12);
+    ///     assert!((b.determinant() - 6.0).abs() < 1e-12);
+    ///     assert_eq!(StaticMatrix::<4, 4>::identity().determinant(), 1.0);
+    /// }
+    /// ```
+    pub fn determinant(&self) -> f64 {
+        let mut a = self.data;
+        let mut det = 1.0;
+
+        for k in 0..N {
+            let pivot_row = (k..N)
+                .max_by(|&x, &y| a[x][k].abs().total_cmp(&a[y][k].abs()))
+                .unwrap_or(k);
+            if a[pivot_row][k] == 0.0 {
+                return 0.0;
+            }
+            if pivot_row != k {
+                a.swap(pivot_row, k);
+                det = -det;
+            }
+
+            let pivot = a[k];
+            det *= pivot[k];
+            for row in a.iter_mut().skip(k + 1) {
+                let factor = row[k] / pivot[k];
+                for (value, p) in row.iter_mut().zip(pivot.iter()).skip(k) {
+                    *value -= factor * p;
+                }
+            }
+        }
+        det
+    }
+}
+
+impl<const R: usize, const C: usize> Default for StaticMatrix<R, C> {
+    fn default() -> Self {
+        Self::zeros()
+    }
+}
+
+impl<const R: usize, const C: usize> Add for StaticMatrix<R, C> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self::Output {
+        for (row, other_row) in self.data.iter_mut().zip(other.data.iter()) {
+            for (value, other_value) in row.iter_mut().zip(other_row.iter()) {
+                *value += other_value;
+            }
+        }
+        self
+    }
+}
+
+impl<const R: usize, const K: usize, const C: usize> Mul<StaticMatrix<K, C>> for StaticMatrix<R, K> {
+    type Output = StaticMatrix<R, C>;
+
+    fn mul(self, other: StaticMatrix<K, C>) -> Self::Output {
+        let mut result = StaticMatrix::<R, C>::zeros();
+        for (i, row) in self.data.iter().enumerate() {
+            for (k, a) in row.iter().enumerate() {
+                for (value, b) in result.data[i].iter_mut().zip(other.data[k].iter()) {
+                    *value += a * b;
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<const R: usize, const C: usize> TryFrom<&Matrix> for StaticMatrix<R, C> {
+    type Error = MatrixError;
+
+    /// Converts a dynamic matrix, failing with `ShapeMismatch` unless it is exactly `R x C`.
+    fn try_from(matrix: &Matrix) -> Result<Self, Self::Error> {
+        if matrix.rows != R || matrix.cols != C {
+            return Err(MatrixError::ShapeMismatch {
+                operation: "StaticMatrix::try_from",
+                left: (R, C),
+                right: (matrix.rows, matrix.cols),
+            });
+        }
+
+        let mut result = Self::zeros();
+        for (row, source) in result.data.iter_mut().zip(matrix.data.iter()) {
+            row.copy_from_slice(source);
+        }
+        Ok(result)
+    }
+}
+
+impl<const R: usize, const C: usize> TryFrom<Matrix> for StaticMatrix<R, C> {
+    type Error = MatrixError;
+
+    /// Converts a dynamic matrix, failing with `ShapeMismatch` unless it is exactly `R x C`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, StaticMatrix};
+    /// fn main() {
+    ///     let dynamic = matrix![1.0, 2.0; 3.0, 4.0];
+    ///
+    ///     let fixed = StaticMatrix::<2, 2>::try_from(dynamic.clone()).unwrap();
+    ///     assert_eq!(Matrix::from(fixed), dynamic);
+    ///
+    ///     assert!(StaticMatrix::<3, 2>::try_from(dynamic).is_err());
+    /// }
+    /// ```
+    fn try_from(matrix: Matrix) -> Result<Self, Self::Error> {
+        Self::try_from(&matrix)
+    }
+}
+
+impl<const R: usize, const C: usize> From<StaticMatrix<R, C>> for Matrix {
+    /// Converts to a dynamic matrix. `R` and `C` must both be non-zero, like every `Matrix`.
+    fn from(matrix: StaticMatrix<R, C>) -> Self {
+        const { assert!(R > 0 && C > 0, "a Matrix cannot have zero rows or columns") };
+
+        Matrix {
+            rows: R,
+            cols: C,
+            data: matrix.data.iter().map(|row| row.to_vec()).collect(),
+        }
+    }
+}
+
+impl<const R: usize, const C: usize> MatrixLike for StaticMatrix<R, C> {
+    fn shape(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<f64> {
+        StaticMatrix::get(self, row, col)
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[f64]> {
+        self.data.get(row).map(|values| values.as_slice())
+    }
+}
@@ -0,0 +1,64 @@
+/// Absolute and relative tolerance for the approximate comparisons of the crate.
+///
+/// Two values `a` and `b` are considered equal when `|a - b| <= max(absolute, relative * max(|a|, |b|))`,
+/// and a pivot is considered zero when it is at most `max(absolute, relative * scale)`, where `scale` is the
+/// largest absolute entry of the matrix. The relative part follows the scale of the data, the absolute part is
+/// a floor for values near 0.
+///
+/// The methods that need a tolerance have a `_with_tol` variant taking a `Tolerance`, and the plain methods
+/// use `DEFAULT_TOLERANCE`.
+/// # Example
+/// ```
+/// # use matrix::{matrix, Matrix, Tolerance, DEFAULT_TOLERANCE};
+/// fn main() {
+///     // The second row is the first one times 2, up to 1e-9.
+///     let nearly_singular = matrix![1.0, 2.0; 2.0, 4.0 + 1e-9];
+///
+///     assert_eq!(nearly_singular.is_singular_with_tol(DEFAULT_TOLERANCE), Ok(false));
+///     assert_eq!(nearly_singular.is_singular_with_tol(Tolerance::relative(1e-6)), Ok(true));
+///     assert!(nearly_singular.solve(&matrix![1.0; 1.0]).is_ok());
+///     assert!(nearly_singular.solve_with_tol(&matrix![1.0; 1.0], Tolerance::absolute(1e-6)).is_err());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    pub absolute: f64,
+    pub relative: f64,
+}
+
+/// Tolerance used by the methods that don't take one: relative `1e-12`, no absolute floor,
+/// so the results don't depend on the scale of the matrix.
+pub const DEFAULT_TOLERANCE: Tolerance = Tolerance { absolute: 0.0, relative: 1e-12 };
+
+impl Tolerance {
+    /// Creates a tolerance with both parts.
+    pub fn new(absolute: f64, relative: f64) -> Self {
+        Self { absolute, relative }
+    }
+
+    /// Creates a purely absolute tolerance.
+    pub fn absolute(absolute: f64) -> Self {
+        Self { absolute, relative: 0.0 }
+    }
+
+    /// Creates a purely relative tolerance.
+    pub fn relative(relative: f64) -> Self {
+        Self { absolute: 0.0, relative }
+    }
+
+    /// Largest difference accepted for values of magnitude `scale`.
+    pub fn threshold(&self, scale: f64) -> f64 {
+        self.absolute.max(self.relative * scale)
+    }
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        DEFAULT_TOLERANCE
+    }
+}
+
+/// Returns true if `a` and `b` are equal up to `tol`, see `Tolerance`. Equal infinities compare equal, `NaN` never does.
+pub(crate) fn approx_eq_scalar(a: f64, b: f64, tol: Tolerance) -> bool {
+    a == b || (a - b).abs() <= tol.threshold(a.abs().max(b.abs()))
+}
@@ -0,0 +1,142 @@
+use crate::errors::MatrixError;
+use crate::matrix::{dim_checked_mul, Matrix};
+use crate::operations::random::SplitMix64;
+
+/// First state of the builder returned by `Matrix::builder_typed`: nothing is set yet.
+///
+/// The shape comes either from `shape`, or from the data itself with `data`.
+#[must_use = "the matrix is only built by `done`"]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuilderNeedsShape {
+    _private: (),
+}
+
+/// Second state of the typed builder: the shape is set and valid, the content is not.
+#[must_use = "the matrix is only built by `done`"]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuilderShaped {
+    rows: usize,
+    cols: usize,
+}
+
+/// Last state of the typed builder: shape and content are set and agree, so `done` cannot fail.
+#[must_use = "the matrix is only built by `done`"]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuilderReady {
+    matrix: Matrix,
+}
+
+impl Matrix {
+    /// Starts a builder whose steps are encoded in its type: `BuilderNeedsShape`, then `BuilderShaped`, then
+    /// `BuilderReady`, the only one with a `done` method.
+    ///
+    /// Every check happens on the transition that introduces the value it is about, so a shape is never set
+    /// twice, data never comes before its shape, and `done` returns a `Matrix` rather than a `Result`. Use
+    /// `Matrix::builder` to set the fields in any order.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() -> Result<(), MatrixError> {
+    ///     let a = Matrix::builder_typed().shape(2, 3)?.data(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]])?.done();
+    ///     assert_eq!(a, matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0]);
+    ///
+    ///     // The shape is taken from the data, so the two cannot disagree.
+    ///     let b = Matrix::builder_typed().data(vec![vec![1.0], vec![2.0]])?.done();
+    ///     assert_eq!(b, matrix![1.0; 2.0]);
+    ///
+    ///     assert_eq!(Matrix::builder_typed().shape(2, 2)?.zeros().done(), Matrix::zeros(2, 2)?);
+    ///     assert_eq!(Matrix::builder_typed().shape(3, 4)?.pseudo_random(42).done(), Matrix::from_seed(3, 4, 42)?);
+    ///
+    ///     // Each step reports the errors of what it sets.
+    ///     assert_eq!(Matrix::builder_typed().shape(0, 3), Err(MatrixError::InvalidMatrixSize));
+    ///     assert_eq!(
+    ///         Matrix::builder_typed().shape(usize::MAX / 2, 3),
+    ///         Err(MatrixError::InvalidMatrixSize)
+    ///     );
+    ///     assert_eq!(Matrix::builder_typed().shape(2, 2)?.data(vec![vec![1.0, 2.0]]), Err(MatrixError::DataMismatch));
+    ///     assert_eq!(Matrix::builder_typed().data(vec![vec![1.0, 2.0], vec![3.0]]), Err(MatrixError::DataMismatch));
+    ///     Ok(())
+    /// }
+    /// ```
+    /// `done` only exists once the content is set:
+    /// ```compile_fail
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let a = Matrix::builder_typed().shape(2, 2).unwrap().done();
+    /// }
+    /// ```
+    /// ```compile_fail
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let a = Matrix::builder_typed().done();
+    /// }
+    /// ```
+    /// and the shape can't be set again, or after the data:
+    /// ```compile_fail
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let a = Matrix::builder_typed().shape(2, 2).unwrap().shape(3, 3);
+    /// }
+    /// ```
+    /// ```compile_fail
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let a = Matrix::builder_typed().data(vec![vec![1.0]]).unwrap().shape(2, 2);
+    /// }
+    /// ```
+    /// ```compile_fail
+    /// # use matrix::Matrix;
+    /// fn main() {
+    ///     let a = Matrix::builder_typed().shape(1, 1).unwrap().zeros().data(vec![vec![1.0]]);
+    /// }
+    /// ```
+    pub fn builder_typed() -> BuilderNeedsShape {
+        BuilderNeedsShape { _private: () }
+    }
+}
+
+impl BuilderNeedsShape {
+    /// Sets the shape. Returns `InvalidMatrixSize` if either dimension is 0 or if the number of elements does not
+    /// fit in a `usize`.
+    pub fn shape(self, rows: usize, cols: usize) -> Result<BuilderShaped, MatrixError> {
+        if rows == 0 || cols == 0 {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+        dim_checked_mul(rows, cols)?;
+        Ok(BuilderShaped { rows, cols })
+    }
+
+    /// Sets the content, and the shape from it, with the errors of `Matrix::from_rows`.
+    pub fn data(self, data: Vec<Vec<f64>>) -> Result<BuilderReady, MatrixError> {
+        Ok(BuilderReady { matrix: Matrix::from_rows(data)? })
+    }
+}
+
+impl BuilderShaped {
+    /// Sets the content. Returns `DataMismatch` unless `data` has exactly the shape set before.
+    pub fn data(self, data: Vec<Vec<f64>>) -> Result<BuilderReady, MatrixError> {
+        if data.len() != self.rows || data.iter().any(|row| row.len() != self.cols) {
+            return Err(MatrixError::DataMismatch);
+        }
+        Ok(BuilderReady { matrix: Matrix { rows: self.rows, cols: self.cols, data } })
+    }
+
+    /// Fills the matrix with zeros.
+    pub fn zeros(self) -> BuilderReady {
+        let data = (0..self.rows).map(|_| vec![0.0; self.cols]).collect();
+        BuilderReady { matrix: Matrix { rows: self.rows, cols: self.cols, data } }
+    }
+
+    /// Fills the matrix with pseudo-random values in `[0, 1)`, the same as `Matrix::from_seed`.
+    pub fn pseudo_random(self, seed: u64) -> BuilderReady {
+        let data = SplitMix64::new(seed).fill(self.rows, self.cols);
+        BuilderReady { matrix: Matrix { rows: self.rows, cols: self.cols, data } }
+    }
+}
+
+impl BuilderReady {
+    /// Returns the matrix. Every check already happened on the way here.
+    pub fn done(self) -> Matrix {
+        self.matrix
+    }
+}
@@ -0,0 +1,211 @@
+use crate::matrix::Matrix;
+use crate::errors::MatrixError;
+use crate::operations::summation::{compensated_sum, pairwise_sum, ReductionMode};
+
+/// A vector of `f64`, so vectors don't have to be spelled as `n x 1` (or `1 x n`) matrices.
+///
+/// Converting to a matrix is always explicit, with `to_col_matrix` or `to_row_matrix`.
+/// Like matrices, vectors are never empty.
+/// # Example
+/// ```
+/// # use matrix::{matrix, Matrix, Vector};
+/// fn main() {
+///     let v = Vector::from_slice(&[3.0, 4.0]).unwrap();
+///
+///     assert_eq!(v.len(), 2);
+///     assert_eq!(v.norm(), 5.0);
+///     assert_eq!(v.dot(&v).unwrap(), 25.0);
+///     assert_eq!(v.normalize().unwrap(), Vector::from_slice(&[0.6, 0.8]).unwrap());
+///     assert_eq!(v.to_col_matrix(), matrix![3.0; 4.0]);
+///     assert_eq!(v.to_row_matrix(), matrix![3.0, 4.0]);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector(pub Vec<f64>);
+
+impl Vector {
+    /// Creates a vector from its elements, returning `InvalidMatrixSize` for an empty slice.
+    pub fn from_slice(values: &[f64]) -> Result<Self, MatrixError> {
+        if values.is_empty() {
+            return Err(MatrixError::InvalidMatrixSize);
+        }
+        Ok(Self(values.to_vec()))
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the vector has no elements, which only happens for a vector built by hand.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Gets the element at `index`, `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<f64> {
+        self.0.get(index).copied()
+    }
+
+    fn check_same_len(&self, other: &Vector, operation: &'static str) -> Result<(), MatrixError> {
+        if self.len() != other.len() {
+            return Err(MatrixError::ShapeMismatch {
+                operation,
+                left: (self.len(), 1),
+                right: (other.len(), 1),
+            });
+        }
+        Ok(())
+    }
+
+    /// Computes the dot product, returning `ShapeMismatch` if the lengths differ.
+    ///
+    /// The products are summed with compensation, see `MatrixOps::sum`; each product is still rounded once.
+    /// # Example
+    /// ```
+    /// # use matrix::Vector;
+    /// fn main() {
+    ///     let mut x = vec![1.0; 1001];
+    ///     x[0] = 1e8;
+    ///     let v = Vector(x);
+    ///     // 1e16 + 1000 * 1.0, which naive summation rounds to 1e16.
+    ///     assert_eq!(v.dot(&v).unwrap(), 1e16 + 1000.0);
+    ///     assert!(v.dot(&Vector(vec![1.0])).is_err());
+    /// }
+    /// ```
+    pub fn dot(&self, other: &Vector) -> Result<f64, MatrixError> {
+        self.check_same_len(other, "dot")?;
+        Ok(compensated_sum(self.0.iter().zip(other.0.iter()).map(|(a, b)| a * b)))
+    }
+
+    /// Computes the dot product with the products added up as `mode` says, see `Matrix::sum_with`.
+    ///
+    /// `ReductionMode::Sequential` is `dot`. Returns `ShapeMismatch` if the lengths differ.
+    /// # Example
+    /// ```
+    /// # use matrix::{Vector, summation::ReductionMode};
+    /// fn main() {
+    ///     let v = Vector(vec![1.0, 2.0, 3.0]);
+    ///     let w = Vector(vec![4.0, -5.0, 6.0]);
+    ///     assert_eq!(v.dot_with(&w, ReductionMode::Deterministic).unwrap(), 12.0);
+    ///     assert_eq!(v.dot_with(&w, ReductionMode::Sequential), v.dot(&w));
+    ///     assert!(v.dot_with(&Vector(vec![1.0]), ReductionMode::Deterministic).is_err());
+    /// }
+    /// ```
+    pub fn dot_with(&self, other: &Vector, mode: ReductionMode) -> Result<f64, MatrixError> {
+        match mode {
+            ReductionMode::Sequential => self.dot(other),
+            ReductionMode::Deterministic => {
+                self.check_same_len(other, "dot")?;
+                Ok(pairwise_sum(0, self.len(), &|i| self.0[i] * other.0[i]))
+            }
+        }
+    }
+
+    /// Computes the Euclidean norm, summing the squares with compensation like `dot`.
+    ///
+    /// `v.norm()` is exactly `v.dot(&v).unwrap().sqrt()`.
+    pub fn norm(&self) -> f64 {
+        compensated_sum(self.0.iter().map(|x| x * x)).sqrt()
+    }
+
+    /// Returns the unit vector with the same direction, or `InvalidOperation` for the zero vector.
+    pub fn normalize(&self) -> Result<Vector, MatrixError> {
+        let norm = self.norm();
+        if norm == 0.0 {
+            return Err(MatrixError::InvalidOperation("cannot normalize the zero vector"));
+        }
+        Ok(Vector(self.0.iter().map(|x| x / norm).collect()))
+    }
+
+    /// Computes the cross product of two 3D vectors, following the right-hand rule.
+    ///
+    /// Returns `InvalidOperation` unless both vectors have 3 elements.
+    /// # Example
+    /// ```
+    /// # use matrix::Vector;
+    /// fn main() {
+    ///     let x = Vector::from_slice(&[1.0, 0.0, 0.0]).unwrap();
+    ///     let y = Vector::from_slice(&[0.0, 1.0, 0.0]).unwrap();
+    ///     let z = Vector::from_slice(&[0.0, 0.0, 1.0]).unwrap();
+    ///
+    ///     assert_eq!(x.cross(&y).unwrap(), z);
+    ///     assert_eq!(y.cross(&z).unwrap(), x);
+    ///     assert_eq!(y.cross(&x).unwrap(), Vector::from_slice(&[0.0, 0.0, -1.0]).unwrap());
+    ///
+    ///     let planar = Vector::from_slice(&[1.0, 0.0]).unwrap();
+    ///     assert!(planar.cross(&planar).is_err());
+    /// }
+    /// ```
+    pub fn cross(&self, other: &Vector) -> Result<Vector, MatrixError> {
+        match (self.0.as_slice(), other.0.as_slice()) {
+            ([a1, a2, a3], [b1, b2, b3]) => Ok(Vector(vec![
+                a2 * b3 - a3 * b2,
+                a3 * b1 - a1 * b3,
+                a1 * b2 - a2 * b1,
+            ])),
+            _ => Err(MatrixError::InvalidOperation("cross product is only defined for 3D vectors")),
+        }
+    }
+
+    /// Returns the angle between two vectors in radians, in `[0, pi]`.
+    ///
+    /// Returns `ShapeMismatch` if the lengths differ and `InvalidOperation` if either vector is zero.
+    /// # Example
+    /// ```
+    /// # use matrix::Vector;
+    /// fn main() {
+    ///     let a = Vector::from_slice(&[1.0, 0.0]).unwrap();
+    ///     let b = Vector::from_slice(&[1.0, 1.0]).unwrap();
+    ///
+    ///     assert!((a.angle_between(&b).unwrap() - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+    ///     assert!(a.angle_between(&Vector::from_slice(&[0.0, 0.0]).unwrap()).is_err());
+    /// }
+    /// ```
+    pub fn angle_between(&self, other: &Vector) -> Result<f64, MatrixError> {
+        let dot = self.dot(other)?;
+        let norms = self.norm() * other.norm();
+        if norms == 0.0 {
+            return Err(MatrixError::InvalidOperation("the angle with the zero vector is undefined"));
+        }
+        Ok((dot / norms).clamp(-1.0, 1.0).acos())
+    }
+
+    /// Returns the vector as an `n x 1` matrix.
+    pub fn to_col_matrix(&self) -> Matrix {
+        Matrix { rows: self.len(), cols: 1, data: self.0.iter().map(|&x| vec![x]).collect() }
+    }
+
+    /// Returns the vector as a `1 x n` matrix.
+    pub fn to_row_matrix(&self) -> Matrix {
+        Matrix { rows: 1, cols: self.len(), data: vec![self.0.clone()] }
+    }
+}
+
+impl Matrix {
+    /// Multiplies the matrix by a column vector, returning `ShapeMismatch` unless `v` has `cols` elements.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, Vector, mul::Mul};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    ///     let v = Vector::from_slice(&[1.0, 0.0, -1.0]).unwrap();
+    ///
+    ///     let product = a.mul_vector(&v).unwrap();
+    ///     assert_eq!(product, Vector::from_slice(&[-2.0, -2.0]).unwrap());
+    ///     assert_eq!(product.to_col_matrix(), a.mul(&v.to_col_matrix()).unwrap());
+    ///
+    ///     assert!(a.mul_vector(&Vector::from_slice(&[1.0, 2.0]).unwrap()).is_err());
+    /// }
+    /// ```
+    pub fn mul_vector(&self, v: &Vector) -> Result<Vector, MatrixError> {
+        if self.cols != v.len() {
+            return Err(MatrixError::ShapeMismatch {
+                operation: "mul_vector",
+                left: (self.rows, self.cols),
+                right: (v.len(), 1),
+            });
+        }
+        Ok(Vector(self.data.iter().map(|row| row.iter().zip(v.0.iter()).map(|(a, b)| a * b).sum()).collect()))
+    }
+}
@@ -0,0 +1,590 @@
+use crate::matrix::Matrix;
+use crate::errors::MatrixError;
+use crate::operations::add::Add;
+use std::ops::Range;
+
+/// Read-only rectangular window into a `Matrix`, created by `Matrix::view`.
+///
+/// Indices passed to the view are relative to its top-left corner.
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a> {
+    matrix: &'a Matrix,
+    top: usize,
+    left: usize,
+    rows: usize,
+    cols: usize,
+}
+
+/// Mutable rectangular window into a `Matrix`, created by `Matrix::view_mut`.
+///
+/// It borrows the whole parent matrix mutably, so two mutable views (overlapping or not)
+/// can never exist at the same time.
+#[derive(Debug)]
+pub struct MatrixViewMut<'a> {
+    matrix: &'a mut Matrix,
+    top: usize,
+    left: usize,
+    rows: usize,
+    cols: usize,
+}
+
+/// Zero-copy transposed access to a `Matrix`, created by `Matrix::t`.
+///
+/// `get(i, j)` reads element `(j, i)` of the parent matrix.
+#[derive(Debug, Clone, Copy)]
+pub struct TransposedView<'a> {
+    matrix: &'a Matrix,
+}
+
+/// Minimal read-only matrix interface, used by operations that accept views as well as matrices.
+///
+/// `shape` and `get` are all an implementation needs; `row_slice` lets generic code read whole rows at once
+/// from the representations that store them contiguously.
+pub trait MatrixLike {
+    /// Returns (rows, cols).
+    fn shape(&self) -> (usize, usize);
+
+    /// Gets the element at (`row`, `col`), `None` if it is out of bounds.
+    fn get(&self, row: usize, col: usize) -> Option<f64>;
+
+    /// Borrows row `row` as a slice of `cols` values, `None` if it is out of bounds or if the representation
+    /// doesn't store its rows contiguously (like `TransposedView` and `BandedMatrix`).
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, BandedMatrix, Matrix, MatrixLike};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    ///
+    ///     assert_eq!(a.row_slice(1), Some(&[4.0, 5.0, 6.0][..]));
+    ///     assert_eq!(a.view(0..2, 1..3).unwrap().row_slice(0), Some(&[2.0, 3.0][..]));
+    ///     assert_eq!(a.row_slice(2), None);
+    ///     assert_eq!(a.t().row_slice(0), None);
+    ///     assert_eq!(BandedMatrix::zeros(2, 1, 1).unwrap().row_slice(0), None);
+    /// }
+    /// ```
+    fn row_slice(&self, row: usize) -> Option<&[f64]> {
+        let _ = row;
+        None
+    }
+}
+
+/// Checks that `range` is non-empty and inside `0..bound`.
+fn check_range(range: &Range<usize>, bound: usize) -> Result<(), MatrixError> {
+    if range.start >= range.end {
+        return Err(MatrixError::InvalidMatrixSize);
+    }
+    if range.end > bound {
+        return Err(MatrixError::IndexOutOfBounds { index: range.end - 1, bound });
+    }
+    Ok(())
+}
+
+impl Matrix {
+    /// Borrows the window `row_range x col_range` of the matrix without copying it.
+    ///
+    /// Empty ranges return `InvalidMatrixSize`, and ranges past the end of the matrix return `IndexOutOfBounds`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// # use matrix::add::Add;
+    /// fn main() {
+    ///     let a = matrix![
+    ///         1.0, 2.0, 3.0;
+    ///         4.0, 5.0, 6.0;
+    ///         7.0, 8.0, 9.0;
+    ///     ];
+    ///
+    ///     let block = a.view(1..3, 0..2).unwrap();
+    ///     assert_eq!(block.shape(), (2, 2));
+    ///     assert_eq!(block.get(0, 1), Some(5.0));
+    ///     assert_eq!(block.iter().collect::<Vec<_>>(), vec![4.0, 5.0, 7.0, 8.0]);
+    ///
+    ///     let sum = a.view(0..2, 0..2).unwrap().add(a.view(1..3, 1..3).unwrap()).unwrap();
+    ///     assert_eq!(sum, matrix![6.0, 8.0; 12.0, 14.0]);
+    ///
+    ///     assert!(a.view(0..4, 0..1).is_err());
+    /// }
+    /// ```
+    pub fn view(&self, row_range: Range<usize>, col_range: Range<usize>) -> Result<MatrixView<'_>, MatrixError> {
+        check_range(&row_range, self.rows)?;
+        check_range(&col_range, self.cols)?;
+
+        Ok(MatrixView {
+            matrix: self,
+            top: row_range.start,
+            left: col_range.start,
+            rows: row_range.len(),
+            cols: col_range.len(),
+        })
+    }
+
+    /// Mutably borrows the window `row_range x col_range` of the matrix, for in-place block updates.
+    ///
+    /// Changes made through the view are written directly into the matrix.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let mut a = matrix![
+    ///         1.0, 2.0;
+    ///         3.0, 4.0;
+    ///     ];
+    ///
+    ///     let mut block = a.view_mut(1..2, 0..2).unwrap();
+    ///     block.fill(0.0);
+    ///     block.set(0, 1, 9.0).unwrap();
+    ///
+    ///     assert_eq!(a, matrix![1.0, 2.0; 0.0, 9.0]);
+    /// }
+    /// ```
+    /// Two mutable views of the same matrix are rejected by the borrow checker:
+    /// ```compile_fail
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let mut a = matrix![1.0, 2.0; 3.0, 4.0];
+    ///
+    ///     let mut top = a.view_mut(0..1, 0..2).unwrap();
+    ///     let mut bottom = a.view_mut(0..2, 0..2).unwrap();
+    ///     top.fill(1.0);
+    ///     bottom.fill(2.0);
+    /// }
+    /// ```
+    pub fn view_mut(&mut self, row_range: Range<usize>, col_range: Range<usize>) -> Result<MatrixViewMut<'_>, MatrixError> {
+        check_range(&row_range, self.rows)?;
+        check_range(&col_range, self.cols)?;
+
+        Ok(MatrixViewMut {
+            matrix: self,
+            top: row_range.start,
+            left: col_range.start,
+            rows: row_range.len(),
+            cols: col_range.len(),
+        })
+    }
+
+    /// Copies `block` into the region whose top-left corner is (`top`, `left`).
+    ///
+    /// If the block doesn't fit, it returns `BlockOverflow` with the number of rows and columns that would fall
+    /// past the edges, and the matrix is left unchanged.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     let mut a = Matrix::zeros(3, 4).unwrap();
+    ///     a.set_submatrix(1, 2, &matrix![1.0, 2.0; 3.0, 4.0]).unwrap();
+    ///     assert_eq!(a, matrix![0.0, 0.0, 0.0, 0.0; 0.0, 0.0, 1.0, 2.0; 0.0, 0.0, 3.0, 4.0]);
+    ///
+    ///     a.set_region(0..2, 0..3, 7.0).unwrap();
+    ///     assert_eq!(a, matrix![7.0, 7.0, 7.0, 0.0; 7.0, 7.0, 7.0, 2.0; 0.0, 0.0, 3.0, 4.0]);
+    ///
+    ///     assert_eq!(
+    ///         a.set_submatrix(2, 3, &matrix![1.0, 2.0; 3.0, 4.0]),
+    ///         Err(MatrixError::BlockOverflow { operation: "set_submatrix", rows: 1, cols: 1 })
+    ///     );
+    ///     assert!(a.set_region(0..5, 0..1, 1.0).is_err());
+    /// }
+    /// ```
+    pub fn set_submatrix(&mut self, top: usize, left: usize, block: &Matrix) -> Result<(), MatrixError> {
+        self.block_mut(top, left, block, "set_submatrix")?
+            .rows_iter_mut()
+            .zip(block.data.iter())
+            .for_each(|(row, source)| row.copy_from_slice(source));
+        Ok(())
+    }
+
+    /// Adds `block` to the region whose top-left corner is (`top`, `left`), see `set_submatrix`.
+    ///
+    /// This is the scatter step of finite element assembly, where the blocks of neighbouring elements overlap.
+    /// The bounds are checked first, so a failed call leaves the matrix untouched.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     // 1D bar made of 3 elements between 4 nodes, each with the stiffness block [1 -1; -1 1].
+    ///     let element = matrix![1.0, -1.0; -1.0, 1.0];
+    ///     let mut global = Matrix::zeros(4, 4).unwrap();
+    ///     for e in 0..3 {
+    ///         global.add_submatrix(e, e, &element).unwrap();
+    ///     }
+    ///
+    ///     assert_eq!(global, matrix![
+    ///         1.0, -1.0, 0.0, 0.0;
+    ///         -1.0, 2.0, -1.0, 0.0;
+    ///         0.0, -1.0, 2.0, -1.0;
+    ///         0.0, 0.0, -1.0, 1.0
+    ///     ]);
+    ///
+    ///     // Overwriting instead of adding loses the contribution of the previous element.
+    ///     let mut wrong = Matrix::zeros(4, 4).unwrap();
+    ///     for e in 0..3 {
+    ///         wrong.set_submatrix(e, e, &element).unwrap();
+    ///     }
+    ///     assert_eq!(wrong.data[1][1], 1.0);
+    ///     let before = global.clone();
+    ///     assert!(global.add_submatrix(3, 3, &element).is_err());
+    ///     assert_eq!(global, before);
+    /// }
+    /// ```
+    pub fn add_submatrix(&mut self, top: usize, left: usize, block: &Matrix) -> Result<(), MatrixError> {
+        self.block_mut(top, left, block, "add_submatrix")?
+            .rows_iter_mut()
+            .zip(block.data.iter())
+            .for_each(|(row, source)| row.iter_mut().zip(source.iter()).for_each(|(x, y)| *x += y));
+        Ok(())
+    }
+
+    /// Adds `block[i][j]` to the element (`row_indices[i]`, `col_indices[j]`), the general form of `add_submatrix`
+    /// for blocks whose rows and columns are not contiguous in the matrix.
+    ///
+    /// Repeated indices accumulate. `row_indices` and `col_indices` must match the shape of `block` or it returns
+    /// `ShapeMismatch`, and an index outside the matrix returns `IndexOutOfBounds`. Every index is checked before
+    /// anything is written, so a failed call leaves the matrix untouched.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix, MatrixError};
+    /// fn main() {
+    ///     // Laplacian of the graph 0 - 2 - 1 - 3, one [1 -1; -1 1] block per edge.
+    ///     let edge = matrix![1.0, -1.0; -1.0, 1.0];
+    ///     let mut laplacian = Matrix::zeros(4, 4).unwrap();
+    ///     for (u, v) in [(0, 2), (2, 1), (1, 3)] {
+    ///         laplacian.scatter_add(&[u, v], &[u, v], &edge).unwrap();
+    ///     }
+    ///     assert_eq!(laplacian, matrix![
+    ///         1.0, 0.0, -1.0, 0.0;
+    ///         0.0, 2.0, -1.0, -1.0;
+    ///         -1.0, -1.0, 2.0, 0.0;
+    ///         0.0, -1.0, 0.0, 1.0
+    ///     ]);
+    ///
+    ///     let before = laplacian.clone();
+    ///     assert_eq!(
+    ///         laplacian.scatter_add(&[0, 4], &[0, 1], &edge),
+    ///         Err(MatrixError::IndexOutOfBounds { index: 4, bound: 4 })
+    ///     );
+    ///     assert_eq!(
+    ///         laplacian.scatter_add(&[0, 1], &[0, 1, 2], &edge),
+    ///         Err(MatrixError::ShapeMismatch { operation: "scatter_add", left: (2, 3), right: (2, 2) })
+    ///     );
+    ///     assert_eq!(laplacian, before);
+    ///
+    ///     laplacian.scatter_add(&[3, 3], &[3], &matrix![1.0; 1.0]).unwrap();
+    ///     assert_eq!(laplacian.data[3][3], 3.0);
+    /// }
+    /// ```
+    pub fn scatter_add(&mut self, row_indices: &[usize], col_indices: &[usize], block: &Matrix) -> Result<(), MatrixError> {
+        if (row_indices.len(), col_indices.len()) != (block.rows, block.cols) {
+            return Err(MatrixError::ShapeMismatch {
+                operation: "scatter_add",
+                left: (row_indices.len(), col_indices.len()),
+                right: (block.rows, block.cols),
+            });
+        }
+        let out_of_bounds = |indices: &[usize], bound: usize| {
+            indices
+                .iter()
+                .find(|&&index| index >= bound)
+                .map(|&index| MatrixError::IndexOutOfBounds { index, bound })
+        };
+        if let Some(error) = out_of_bounds(row_indices, self.rows).or_else(|| out_of_bounds(col_indices, self.cols)) {
+            return Err(error);
+        }
+
+        for (&i, source) in row_indices.iter().zip(block.data.iter()) {
+            for (&j, value) in col_indices.iter().zip(source.iter()) {
+                self.data[i][j] += value;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets every element of the window `row_range x col_range` to `value`.
+    ///
+    /// Errors are the same as `view_mut`.
+    pub fn set_region(&mut self, row_range: Range<usize>, col_range: Range<usize>, value: f64) -> Result<(), MatrixError> {
+        self.view_mut(row_range, col_range)?.fill(value);
+        Ok(())
+    }
+
+    fn block_mut(&mut self, top: usize, left: usize, block: &Matrix, operation: &'static str) -> Result<MatrixViewMut<'_>, MatrixError> {
+        let rows = top.saturating_add(block.rows).saturating_sub(self.rows);
+        let cols = left.saturating_add(block.cols).saturating_sub(self.cols);
+        if rows > 0 || cols > 0 {
+            return Err(MatrixError::BlockOverflow { operation, rows, cols });
+        }
+        self.view_mut(top..top + block.rows, left..left + block.cols)
+    }
+
+    /// Returns a lazy transpose of the matrix, without copying any element.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    ///     let t = a.t();
+    ///
+    ///     assert_eq!(t.shape(), (3, 2));
+    ///     assert_eq!(t.get(2, 0), Some(3.0));
+    ///     assert_eq!(t.iter().collect::<Vec<_>>(), vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+    /// }
+    /// ```
+    pub fn t(&self) -> TransposedView<'_> {
+        TransposedView { matrix: self }
+    }
+
+    /// Multiplies the matrix by anything implementing `MatrixLike`, such as a view or a lazy transpose.
+    ///
+    /// `a.mul_view(&b.t())` computes `A * B^T` without materializing `B^T`.
+    /// # Example
+    /// ```
+    /// # use matrix::{matrix, Matrix};
+    /// # use matrix::mul::Mul;
+    /// use std::alloc::{GlobalAlloc, Layout, System};
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// struct Counting;
+    /// static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// unsafe impl GlobalAlloc for Counting {
+    ///     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    ///         ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+    ///         unsafe { System.alloc(layout) }
+    ///     }
+    ///     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    ///         unsafe { System.dealloc(ptr, layout) }
+    ///     }
+    /// }
+    ///
+    /// #[global_allocator]
+    /// static GLOBAL: Counting = Counting;
+    ///
+    /// fn main() {
+    ///     let a = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    ///     let b = matrix![1.0, 0.0, 1.0; 2.0, 1.0, 0.0];
+    ///
+    ///     let before = ALLOCATIONS.load(Ordering::SeqCst);
+    ///     let lazy = a.mul_view(&b.t()).unwrap();
+    ///     let allocations = ALLOCATIONS.load(Ordering::SeqCst) - before;
+    ///
+    ///     // Only the 2x2 result is allocated (one Vec per row plus the outer Vec), B^T never is.
+    ///     assert_eq!(allocations, a.rows + 1);
+    ///     assert_eq!(lazy, a.mul(b.transpose()).unwrap());
+    /// }
+    /// ```
+    pub fn mul_view<M: MatrixLike>(&self, other: &M) -> Result<Matrix, MatrixError> {
+        let (other_rows, other_cols) = other.shape();
+        if self.cols != other_rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let mut result = Matrix::builder().rows(self.rows).cols(other_cols).done()?;
+        for (i, row) in self.data.iter().enumerate() {
+            for j in 0..other_cols {
+                result.data[i][j] = row
+                    .iter()
+                    .enumerate()
+                    .map(|(k, a)| a * other.get(k, j).expect("index is within the shape"))
+                    .sum();
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<'a> TransposedView<'a> {
+    /// Returns (rows, cols) of the transpose, i.e. (cols, rows) of the parent matrix.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.matrix.cols, self.matrix.rows)
+    }
+
+    /// Gets the element at (`row`, `col`) of the transpose.
+    pub fn get(&self, row: usize, col: usize) -> Option<f64> {
+        self.matrix.get(col, row)
+    }
+
+    /// Iterates over the elements of the transpose in row-major order (column-major order of the parent).
+    pub fn iter(&self) -> impl Iterator<Item = f64> + 'a {
+        let matrix = self.matrix;
+        (0..matrix.cols).flat_map(move |j| matrix.data.iter().map(move |row| row[j]))
+    }
+
+    /// Materializes the transpose into a new `Matrix`.
+    pub fn to_owned(&self) -> Matrix {
+        self.matrix.transpose()
+    }
+}
+
+impl<'a> MatrixView<'a> {
+    /// Returns (rows, cols) of the view.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Gets the element at (`row`, `col`) relative to the view, `None` if it is outside of the view.
+    pub fn get(&self, row: usize, col: usize) -> Option<f64> {
+        if row < self.rows && col < self.cols {
+            Some(self.matrix.data[self.top + row][self.left + col])
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over the rows of the view as slices.
+    pub fn rows_iter(&self) -> impl Iterator<Item = &'a [f64]> + 'a {
+        let (left, cols) = (self.left, self.cols);
+        self.matrix.data[self.top..self.top + self.rows]
+            .iter()
+            .map(move |row| &row[left..left + cols])
+    }
+
+    /// Iterates over the elements of the view in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = f64> + 'a {
+        self.rows_iter().flat_map(|row| row.iter().copied())
+    }
+
+    /// Copies the view into a new `Matrix`.
+    pub fn to_owned(&self) -> Matrix {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.rows_iter().map(|row| row.to_vec()).collect(),
+        }
+    }
+}
+
+impl<'a> MatrixViewMut<'a> {
+    /// Returns (rows, cols) of the view.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Gets the element at (`row`, `col`) relative to the view, `None` if it is outside of the view.
+    pub fn get(&self, row: usize, col: usize) -> Option<f64> {
+        if row < self.rows && col < self.cols {
+            Some(self.matrix.data[self.top + row][self.left + col])
+        } else {
+            None
+        }
+    }
+
+    /// Gets a mutable reference to the element at (`row`, `col`) relative to the view.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut f64> {
+        if row < self.rows && col < self.cols {
+            Some(&mut self.matrix.data[self.top + row][self.left + col])
+        } else {
+            None
+        }
+    }
+
+    /// Sets the element at (`row`, `col`) relative to the view.
+    pub fn set(&mut self, row: usize, col: usize, value: f64) -> Result<(), MatrixError> {
+        if row >= self.rows {
+            return Err(MatrixError::IndexOutOfBounds { index: row, bound: self.rows });
+        }
+        if col >= self.cols {
+            return Err(MatrixError::IndexOutOfBounds { index: col, bound: self.cols });
+        }
+
+        self.matrix.data[self.top + row][self.left + col] = value;
+        Ok(())
+    }
+
+    /// Sets every element of the view to `value`.
+    pub fn fill(&mut self, value: f64) {
+        for row in self.rows_iter_mut() {
+            row.fill(value);
+        }
+    }
+
+    /// Iterates over the rows of the view as mutable slices.
+    pub fn rows_iter_mut(&mut self) -> impl Iterator<Item = &mut [f64]> + '_ {
+        let (left, cols) = (self.left, self.cols);
+        self.matrix.data[self.top..self.top + self.rows]
+            .iter_mut()
+            .map(move |row| &mut row[left..left + cols])
+    }
+
+    /// Reborrows the mutable view as a read-only view.
+    pub fn as_view(&self) -> MatrixView<'_> {
+        MatrixView {
+            matrix: self.matrix,
+            top: self.top,
+            left: self.left,
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Copies the view into a new `Matrix`.
+    pub fn to_owned(&self) -> Matrix {
+        self.as_view().to_owned()
+    }
+}
+
+impl Add for MatrixView<'_> {
+    type Output = Matrix;
+
+    fn add(&self, other: Self) -> Result<Self::Output, MatrixError> {
+        if self.shape() != other.shape() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let data = self.rows_iter()
+            .zip(other.rows_iter())
+            .map(|(a, b)| a.iter().zip(b.iter()).map(|(x, y)| x + y).collect())
+            .collect();
+
+        Ok(Matrix { rows: self.rows, cols: self.cols, data })
+    }
+}
+
+impl MatrixLike for Matrix {
+    fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<f64> {
+        Matrix::get(self, row, col)
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[f64]> {
+        self.data.get(row).map(|values| values.as_slice())
+    }
+}
+
+impl MatrixLike for MatrixView<'_> {
+    fn shape(&self) -> (usize, usize) {
+        MatrixView::shape(self)
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<f64> {
+        MatrixView::get(self, row, col)
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[f64]> {
+        (row < self.rows).then(|| &self.matrix.data[self.top + row][self.left..self.left + self.cols])
+    }
+}
+
+impl MatrixLike for MatrixViewMut<'_> {
+    fn shape(&self) -> (usize, usize) {
+        MatrixViewMut::shape(self)
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<f64> {
+        MatrixViewMut::get(self, row, col)
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[f64]> {
+        (row < self.rows).then(|| &self.matrix.data[self.top + row][self.left..self.left + self.cols])
+    }
+}
+
+impl MatrixLike for TransposedView<'_> {
+    fn shape(&self) -> (usize, usize) {
+        TransposedView::shape(self)
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<f64> {
+        TransposedView::get(self, row, col)
+    }
+}
@@ -0,0 +1,88 @@
+//! Flat, JavaScript-friendly wrappers around `Matrix`, enabled by the `wasm` feature.
+//!
+//! Everything here only uses types that `wasm-bindgen` converts on its own: `&[f64]` and `Vec<f64>` become
+//! `Float64Array`, `usize` becomes `number`, and the `String` of an `Err` is thrown as a JavaScript error.
+//! The crate itself has no dependencies, so the `#[wasm_bindgen]` exports belong in a small wrapper crate that
+//! depends on `wasm-bindgen` and forwards to these functions.
+use crate::matrix::Matrix;
+use crate::operations::add::Add;
+use crate::operations::mul::Mul;
+
+/// A matrix built from and read back as a flat row-major array.
+/// # Example
+/// ```
+/// # use matrix::wasm::WasmMatrix;
+/// fn main() {
+///     let a = WasmMatrix::new(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3).unwrap();
+///     let b = WasmMatrix::new(&[1.0, 0.0, 0.0, 1.0, 1.0, 1.0], 3, 2).unwrap();
+///
+///     let product = a.mul(&b).unwrap();
+///     assert_eq!((product.rows(), product.cols()), (2, 2));
+///     assert_eq!(product.data(), vec![4.0, 5.0, 10.0, 11.0]);
+///     assert!((product.determinant().unwrap() + 6.0).abs() < 1e-12);
+///
+///     let t = a.transpose();
+///     assert_eq!((t.rows(), t.cols(), t.data()), (3, 2, vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]));
+///     assert_eq!(a.add(&a).unwrap().data(), vec![2.0, 4.0, 6.0, 8.0, 10.0, 12.0]);
+///
+///     // Errors are the `Display` of the `MatrixError`.
+///     assert_eq!(a.add(&b).unwrap_err(), "DimensionMismatch: Matrix dimensions do not match");
+///     assert_eq!(a.determinant().unwrap_err(), "NotSquare: Operation requires a square matrix, got 2x3");
+///     assert!(WasmMatrix::new(&[1.0, 2.0, 3.0], 2, 2).is_err());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WasmMatrix {
+    matrix: Matrix,
+}
+
+impl WasmMatrix {
+    /// Builds a `rows x cols` matrix from `data` in row-major order, see `Matrix::from_flat`.
+    pub fn new(data: &[f64], rows: usize, cols: usize) -> Result<WasmMatrix, String> {
+        let matrix = Matrix::from_flat(rows, cols, data.to_vec()).map_err(|error| error.to_string())?;
+        Ok(WasmMatrix { matrix })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.matrix.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.matrix.cols
+    }
+
+    /// Returns the elements in row-major order.
+    pub fn data(&self) -> Vec<f64> {
+        self.matrix.data.iter().flatten().copied().collect()
+    }
+
+    pub fn add(&self, other: &WasmMatrix) -> Result<WasmMatrix, String> {
+        let matrix = self.matrix.add(other.matrix.clone()).map_err(|error| error.to_string())?;
+        Ok(WasmMatrix { matrix })
+    }
+
+    pub fn mul(&self, other: &WasmMatrix) -> Result<WasmMatrix, String> {
+        let matrix = self.matrix.mul(&other.matrix).map_err(|error| error.to_string())?;
+        Ok(WasmMatrix { matrix })
+    }
+
+    pub fn transpose(&self) -> WasmMatrix {
+        WasmMatrix { matrix: self.matrix.transpose() }
+    }
+
+    pub fn determinant(&self) -> Result<f64, String> {
+        self.matrix.determinant().map_err(|error| error.to_string())
+    }
+}
+
+impl From<Matrix> for WasmMatrix {
+    fn from(matrix: Matrix) -> Self {
+        WasmMatrix { matrix }
+    }
+}
+
+impl From<WasmMatrix> for Matrix {
+    fn from(wrapper: WasmMatrix) -> Self {
+        wrapper.matrix
+    }
+}
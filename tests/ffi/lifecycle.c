@@ -0,0 +1,46 @@
+/*
+ * Lifecycle of a matrix through the C interface.
+ *
+ *   cargo rustc --release --features ffi --crate-type staticlib
+ *   cc -Iinclude tests/ffi/lifecycle.c target/release/libmatrix.a -lpthread -ldl -lm -o target/lifecycle
+ *   ./target/lifecycle
+ */
+#include <math.h>
+#include <stdio.h>
+#include "matrix.h"
+
+#define CHECK(condition)                                                   \
+    do {                                                                   \
+        if (!(condition)) {                                                \
+            fprintf(stderr, "%s:%d: %s failed\n", __FILE__, __LINE__, #condition); \
+            return 1;                                                      \
+        }                                                                  \
+    } while (0)
+
+int main(void) {
+    const double data[] = {1.0, 2.0, 3.0, 4.0};
+    Matrix *a = NULL, *sum = NULL, *product = NULL;
+    double value = 0.0;
+    size_t rows = 0, cols = 0;
+
+    CHECK(matrix_new(2, 2, data, 4, &a) == MATRIX_OK);
+    CHECK(matrix_add(a, a, &sum) == MATRIX_OK);
+    CHECK(matrix_mul(a, sum, &product) == MATRIX_OK);
+    CHECK(matrix_shape(product, &rows, &cols) == MATRIX_OK && rows == 2 && cols == 2);
+    CHECK(matrix_get(product, 1, 0, &value) == MATRIX_OK && value == 30.0);
+    CHECK(matrix_det(a, &value) == MATRIX_OK && fabs(value + 2.0) < 1e-12);
+
+    value = -1.0;
+    CHECK(matrix_new(2, 2, data, 3, &a) == MATRIX_LENGTH_MISMATCH);
+    CHECK(matrix_new(2, 2, NULL, 4, &a) == MATRIX_NULL_POINTER);
+    CHECK(matrix_get(a, 5, 0, &value) == MATRIX_INDEX_OUT_OF_BOUNDS && value == -1.0);
+    CHECK(matrix_get(NULL, 0, 0, &value) == MATRIX_NULL_POINTER);
+    CHECK(matrix_det(a, NULL) == MATRIX_NULL_POINTER);
+
+    matrix_free(product);
+    matrix_free(sum);
+    matrix_free(a);
+    matrix_free(NULL);
+    puts("ok");
+    return 0;
+}